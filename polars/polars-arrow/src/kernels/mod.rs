@@ -23,7 +23,9 @@ pub mod take_agg;
 mod time;
 
 #[cfg(feature = "timezones")]
-pub use time::replace_timezone;
+pub use time::{
+    dst_transition_flags, replace_timezone, replace_timezone_by_row, Ambiguous, NonExistent,
+};
 
 /// Internal state of [SlicesIterator]
 #[derive(Debug, PartialEq)]
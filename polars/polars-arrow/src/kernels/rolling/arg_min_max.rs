@@ -0,0 +1,75 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// The position of the first largest (`max`) or smallest value of `window`, which yields the
+/// positions and values of the valid values. NaN is never picked over another value.
+pub fn window_arg_min_max<T, I>(window: I, max: bool) -> Option<usize>
+where
+    T: NativeType + PartialOrd + IsFloat,
+    I: IntoIterator<Item = (usize, T)>,
+{
+    let mut best: Option<(usize, T)> = None;
+    for (idx, value) in window {
+        let better = match &best {
+            None => true,
+            Some((_, best)) if max => compare_fn_nan_min(&value, best) == Ordering::Greater,
+            Some((_, best)) => compare_fn_nan_max(&value, best) == Ordering::Less,
+        };
+        if better {
+            best = Some((idx, value));
+        }
+    }
+    best.map(|(idx, _)| idx)
+}
+
+/// Find the offset from the start of every window of its largest (`max`) or smallest value.
+/// On ties the first one is taken.
+///
+/// Null values are skipped. A window is null if it has fewer than `min_periods` non-null
+/// values.
+pub(super) fn rolling_arg_min_max_impl<T, Fo>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    max: bool,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let window = (start..end)
+                .filter(|&i| is_valid(i))
+                .map(|i| (i - start, values[i]));
+            if window.clone().count() < min_periods {
+                out_validity.push(false);
+                return 0;
+            }
+            match window_arg_min_max(window, max) {
+                Some(offset) => {
+                    out_validity.push(true);
+                    offset as u32
+                }
+                None => {
+                    out_validity.push(false);
+                    0
+                }
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::UInt32,
+        out.into(),
+        out_validity.into(),
+    ))
+}
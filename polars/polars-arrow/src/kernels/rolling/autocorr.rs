@@ -0,0 +1,62 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the Pearson correlation between every window and its `lag`-shifted self,
+/// i.e. between the values at `i` and `i + lag` within the window.
+///
+/// Pairs with a null value are skipped. A window is null if it has fewer than
+/// `min_periods` non-null values, fewer than two pairs or no variance.
+pub(super) fn rolling_autocorr_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    lag: usize,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let count = (start..end).filter(|i| is_valid(*i)).count();
+            let pairs = || {
+                (start..end.saturating_sub(lag))
+                    .filter(|i| is_valid(*i) && is_valid(*i + lag))
+                    .map(|i| (values[i], values[i + lag]))
+            };
+
+            let n = pairs().count();
+            if count < min_periods || n < 2 {
+                out_validity.push(false);
+                return 0.0;
+            }
+            let (sum_x, sum_y) = pairs().fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+            let mean_x = sum_x / n as f64;
+            let mean_y = sum_y / n as f64;
+            let (cov, var_x, var_y) = pairs().fold((0.0, 0.0, 0.0), |(c, vx, vy), (x, y)| {
+                let dx = x - mean_x;
+                let dy = y - mean_y;
+                (c + dx * dy, vx + dx * dx, vy + dy * dy)
+            });
+            if var_x == 0.0 || var_y == 0.0 {
+                out_validity.push(false);
+                return 0.0;
+            }
+            out_validity.push(true);
+            cov / (var_x * var_y).sqrt()
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
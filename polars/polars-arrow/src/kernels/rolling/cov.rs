@@ -0,0 +1,144 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// What `rolling_cov_impl` computes of the pairs of every window.
+#[derive(Clone, Copy)]
+pub(super) enum CovKind {
+    /// The covariance with `ddof` delta degrees of freedom.
+    Cov { ddof: u8 },
+    /// The Pearson correlation.
+    Corr,
+}
+
+/// The co-moment and the moments of the pairs in a window, updated with Welford's algorithm as
+/// pairs enter and leave the window.
+#[derive(Default)]
+struct CovWindow {
+    n: usize,
+    mean_x: f64,
+    mean_y: f64,
+    // the sums of the products of the deviations from the means
+    co_moment: f64,
+    m2_x: f64,
+    m2_y: f64,
+}
+
+impl CovWindow {
+    fn add(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / self.n as f64;
+        self.mean_y += dy / self.n as f64;
+        self.co_moment += dx * (y - self.mean_y);
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+    }
+
+    fn remove(&mut self, x: f64, y: f64) {
+        self.n -= 1;
+        if self.n == 0 {
+            *self = Self::default();
+            return;
+        }
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x -= dx / self.n as f64;
+        self.mean_y -= dy / self.n as f64;
+        self.co_moment -= (x - self.mean_x) * dy;
+        self.m2_x -= dx * (x - self.mean_x);
+        self.m2_y -= dy * (y - self.mean_y);
+    }
+}
+
+/// Compute the covariance or correlation between the windows of `x` and `y`, which have the
+/// same length. `validity` is the combined validity of both.
+///
+/// Pairs with a null value are skipped. A window is null if it has fewer than `min_periods`
+/// pairs or no more pairs than `ddof`. A correlation is also null if either side has no
+/// variance in the window.
+///
+/// The moments are updated as pairs enter and leave the window, and recomputed from scratch
+/// every 128 windows to stop the accumulated error from drifting, when the window doesn't
+/// overlap the previous one, or when a non-finite value leaves it, as that can't be removed
+/// from the moments.
+pub(super) fn rolling_cov_impl<Fo>(
+    x: &[f64],
+    y: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    kind: CovKind,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+    let min_pairs = match kind {
+        CovKind::Cov { ddof } => ddof as usize + 1,
+        CovKind::Corr => 2,
+    };
+
+    let mut window = CovWindow::default();
+    let mut last_start = 0;
+    let mut last_end = 0;
+    let mut last_recompute = 0u8;
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+
+            let recompute = start >= last_end
+                || last_recompute > 128
+                || (last_start..start).any(|i| is_valid(i) && !(x[i] + y[i]).is_finite());
+            if recompute {
+                last_recompute = 0;
+                window = CovWindow::default();
+                for i in (start..end).filter(|i| is_valid(*i)) {
+                    window.add(x[i], y[i]);
+                }
+            } else {
+                last_recompute += 1;
+                for i in (last_start..start).filter(|i| is_valid(*i)) {
+                    window.remove(x[i], y[i]);
+                }
+                for i in (last_end..end).filter(|i| is_valid(*i)) {
+                    window.add(x[i], y[i]);
+                }
+            }
+            last_start = start;
+            last_end = end;
+
+            let n = window.n;
+            if n < min_periods || n < min_pairs {
+                out_validity.push(false);
+                return 0.0;
+            }
+            match kind {
+                CovKind::Cov { ddof } => {
+                    out_validity.push(true);
+                    window.co_moment / (n - ddof as usize) as f64
+                }
+                // the moments can't be negative, unless through numeric instability
+                CovKind::Corr if window.m2_x <= 0.0 || window.m2_y <= 0.0 => {
+                    out_validity.push(false);
+                    0.0
+                }
+                CovKind::Corr => {
+                    out_validity.push(true);
+                    window.co_moment / (window.m2_x * window.m2_y).sqrt()
+                }
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
@@ -0,0 +1,48 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the coefficient of variation (`std / mean`) of every window.
+///
+/// `var_window(start, end)` slides an incremental variance window to `start..end` and returns
+/// the variance and the mean of its valid values, and how many there are, or `None` if there
+/// are none. A window is null if it has fewer than `min_periods` valid values, if it has no
+/// more than `ddof` valid values, or if its mean is zero.
+pub(super) fn rolling_cv_impl<Fo, W>(
+    len: usize,
+    window_size: usize,
+    min_periods: usize,
+    ddof: u8,
+    det_offsets_fn: Fo,
+    mut var_window: W,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+    W: FnMut(Start, End) -> Option<(f64, f64, usize)>,
+{
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            match var_window(start, end) {
+                Some((var, mean, count))
+                    if count >= min_periods && count > ddof as usize && mean != 0.0 =>
+                {
+                    out_validity.push(true);
+                    // the variance can be slightly negative due to numeric instability
+                    var.max(0.0).sqrt() / mean
+                }
+                _ => {
+                    out_validity.push(false);
+                    0.0
+                }
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
@@ -0,0 +1,51 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the dot product of every window with `reference`, which has the length
+/// of the window.
+///
+/// The first value of `reference` multiplies the oldest value of a full window. Windows that
+/// are clipped at the edges are multiplied with the matching part of `reference`. Null values
+/// are skipped, and a window is null if it has fewer than `min_periods` non-null values.
+pub(super) fn rolling_dot_impl(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    reference: &[f64],
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef {
+    let len = values.len();
+    let window_size = reference.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+    // the number of values of a full window after the current one
+    let lead = if center { (window_size + 1) / 2 } else { 1 };
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            // the unclipped window is `[full_end - window_size, full_end)`
+            let full_end = idx + lead;
+            let start = full_end.saturating_sub(window_size);
+            let end = std::cmp::min(len, full_end);
+            let offset = start + window_size - full_end;
+
+            let mut count = 0;
+            let mut dot = 0.0;
+            for (i, r) in (start..end).zip(&reference[offset..]) {
+                if is_valid(i) {
+                    count += 1;
+                    dot += values[i] * r;
+                }
+            }
+            out_validity.push(count >= min_periods);
+            dot
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
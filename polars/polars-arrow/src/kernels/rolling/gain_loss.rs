@@ -0,0 +1,58 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the sum of the positive values (gains) and the absolute sum of the negative
+/// values (losses) of every window in a single pass.
+///
+/// Null values are skipped. A window is null if it has fewer than
+/// `min_periods` non-null values.
+pub(super) fn rolling_gain_loss_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+) -> (ArrayRef, ArrayRef)
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let mut gains = Vec::with_capacity(len);
+    let mut losses = Vec::with_capacity(len);
+    for idx in 0..len {
+        let (start, end) = det_offsets_fn(idx, window_size, len);
+        let mut gain = 0.0;
+        let mut loss = 0.0;
+        let mut count = 0;
+        for i in (start..end).filter(|i| is_valid(*i)) {
+            count += 1;
+            let v = values[i];
+            if v > 0.0 {
+                gain += v;
+            } else if v < 0.0 {
+                loss -= v;
+            }
+        }
+        out_validity.push(count >= min_periods);
+        gains.push(gain);
+        losses.push(loss);
+    }
+
+    let out_validity: Option<Bitmap> = out_validity.into();
+    (
+        Box::new(PrimitiveArray::new(
+            DataType::Float64,
+            gains.into(),
+            out_validity.clone(),
+        )),
+        Box::new(PrimitiveArray::new(
+            DataType::Float64,
+            losses.into(),
+            out_validity,
+        )),
+    )
+}
@@ -0,0 +1,96 @@
+use arrow::datatypes::DataType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The parameters of a rolling kurtosis, which mirror those of `scipy.stats.kurtosis`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingKurtosisParams {
+    /// Subtract 3.0 to get the excess kurtosis (Fisher's definition), which is 0.0 for a
+    /// normal distribution. Otherwise, Pearson's definition is used.
+    pub fisher: bool,
+    /// If `false`, correct for the statistical bias of the moment estimators.
+    pub bias: bool,
+}
+
+impl Default for RollingKurtosisParams {
+    fn default() -> Self {
+        Self {
+            fisher: true,
+            bias: true,
+        }
+    }
+}
+
+/// Compute the kurtosis of every window: the fourth central moment divided by the square
+/// of the variance.
+///
+/// Null values are skipped. A window is null if it has fewer than `min_periods` non-null
+/// values, if it has no variance, or if the bias is corrected and it has fewer than four
+/// non-null values.
+pub(super) fn rolling_kurtosis_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    params: RollingKurtosisParams,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let window = || (start..end).filter(|i| is_valid(*i)).map(|i| values[i]);
+
+            let mut count = 0usize;
+            let mut sum = 0.0;
+            for v in window() {
+                count += 1;
+                sum += v;
+            }
+            if count == 0 || count < min_periods || (!params.bias && count < 4) {
+                out_validity.push(false);
+                return 0.0;
+            }
+            let n = count as f64;
+            let mean = sum / n;
+            // second pass over the window to not lose precision on large values
+            let (m2, m4) = window().fold((0.0, 0.0), |(m2, m4), v| {
+                let d2 = (v - mean) * (v - mean);
+                (m2 + d2, m4 + d2 * d2)
+            });
+            let (m2, m4) = (m2 / n, m4 / n);
+            if m2 == 0.0 {
+                out_validity.push(false);
+                return 0.0;
+            }
+
+            let kurtosis = if params.bias {
+                m4 / (m2 * m2)
+            } else {
+                3.0 + 1.0 / (n - 2.0) / (n - 3.0)
+                    * ((n * n - 1.0) * m4 / (m2 * m2) - 3.0 * (n - 1.0) * (n - 1.0))
+            };
+            out_validity.push(true);
+            if params.fisher {
+                kurtosis - 3.0
+            } else {
+                kurtosis
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
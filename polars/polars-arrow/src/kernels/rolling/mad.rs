@@ -0,0 +1,66 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Scales the MAD to be a consistent estimator of the standard deviation of normally
+/// distributed data.
+const NORMAL_CONSISTENCY: f64 = 1.4826;
+
+/// Median of a non-empty buffer; the buffer is sorted in place.
+fn median(buf: &mut [f64]) -> f64 {
+    buf.sort_unstable_by(|a, b| a.total_cmp(b));
+    let mid = buf.len() / 2;
+    if buf.len() % 2 == 0 {
+        (buf[mid - 1] + buf[mid]) / 2.0
+    } else {
+        buf[mid]
+    }
+}
+
+/// Compute the median absolute deviation from the median of every window.
+///
+/// Null values are skipped. A window is null if it has fewer than
+/// `min_periods` non-null values.
+pub(super) fn rolling_mad_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    scale: bool,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut buf = Vec::with_capacity(window_size);
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            buf.clear();
+            buf.extend((start..end).filter(|i| is_valid(*i)).map(|i| values[i]));
+            if buf.is_empty() || buf.len() < min_periods {
+                out_validity.push(false);
+                return 0.0;
+            }
+            let med = median(&mut buf);
+            buf.iter_mut().for_each(|v| *v = (*v - med).abs());
+            let mad = median(&mut buf);
+            out_validity.push(true);
+            if scale {
+                mad * NORMAL_CONSISTENCY
+            } else {
+                mad
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
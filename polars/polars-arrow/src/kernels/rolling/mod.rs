@@ -1,15 +1,50 @@
+mod arg_min_max;
+mod autocorr;
+mod cov;
+mod cv;
+mod dot;
+mod gain_loss;
+mod kurtosis;
+mod mad;
+mod mode;
+mod n_unique;
 pub mod no_nulls;
+mod null_fraction;
 pub mod nulls;
+mod rank;
+mod rescaled_range;
+mod sum_with_reset;
 mod window;
+mod zero_crossings;
 
 use std::cmp::Ordering;
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
+use arg_min_max::rolling_arg_min_max_impl;
+pub use arg_min_max::window_arg_min_max;
 use arrow::array::PrimitiveArray;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::types::NativeType;
+use autocorr::rolling_autocorr_impl;
+use cov::{rolling_cov_impl, CovKind};
+use cv::rolling_cv_impl;
+use dot::rolling_dot_impl;
+use gain_loss::rolling_gain_loss_impl;
+use kurtosis::rolling_kurtosis_impl;
+pub use kurtosis::RollingKurtosisParams;
+use mad::rolling_mad_impl;
+use mode::rolling_mode_impl;
+use n_unique::rolling_n_unique_impl;
+pub use n_unique::NUniqueWindow;
+use null_fraction::rolling_null_fraction_impl;
 use num_traits::{Bounded, Float, NumCast, One, ToPrimitive, Zero};
+use rank::rolling_rank_impl;
+pub use rank::{window_rank, RollingRankMethod, RollingRankParams};
+use rescaled_range::rolling_rs_impl;
+use sum_with_reset::rolling_sum_with_reset_impl;
 use window::*;
+use zero_crossings::rolling_zero_crossings_impl;
+pub use zero_crossings::ZeroCrossingPolicy;
 
 use crate::data_types::IsFloat;
 use crate::prelude::*;
@@ -0,0 +1,68 @@
+use super::*;
+
+/// Take the most frequent value of every window.
+///
+/// Ties are broken by taking the smallest of the most frequent values, where NaN is
+/// larger than every other value. Null values are skipped. A window is null if it
+/// has fewer than `min_periods` non-null values.
+pub(super) fn rolling_mode_impl<T, Fo>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    // the distinct values of the window, sorted, with their counts;
+    // windows are small so this is reset and refilled for every window
+    let mut counts: Vec<(T, usize)> = Vec::with_capacity(window_size);
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            counts.clear();
+            let mut valid_count = 0;
+            for i in start..end {
+                if !is_valid(i) {
+                    continue;
+                }
+                valid_count += 1;
+                let value = values[i];
+                match counts.binary_search_by(|(v, _)| compare_fn_nan_max(v, &value)) {
+                    Ok(pos) => counts[pos].1 += 1,
+                    Err(pos) => counts.insert(pos, (value, 1)),
+                }
+            }
+            // the counts are sorted by value, so the first maximum is the smallest value
+            let mode =
+                counts
+                    .iter()
+                    .fold(None, |mode: Option<&(T, usize)>, candidate| match mode {
+                        Some(mode) if mode.1 >= candidate.1 => Some(mode),
+                        _ => Some(candidate),
+                    });
+            match mode {
+                Some((value, _)) if valid_count >= min_periods => {
+                    out_validity.push(true);
+                    *value
+                }
+                _ => {
+                    out_validity.push(false);
+                    T::default()
+                }
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        T::PRIMITIVE.into(),
+        out.into(),
+        out_validity.into(),
+    ))
+}
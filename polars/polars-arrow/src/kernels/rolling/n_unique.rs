@@ -0,0 +1,138 @@
+use arrow::datatypes::DataType;
+use hashbrown::HashMap;
+
+use super::*;
+
+/// The number of distinct valid values of a sliding window, which is updated incrementally:
+/// only the values that enter or leave the window are looked at.
+///
+/// NaNs are equal to each other and `-0.0` is equal to `0.0`.
+pub struct NUniqueWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    // the number of times every distinct value is in the window
+    counts: HashMap<u128, usize>,
+    n_valid: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> NUniqueWindow<'a, T>
+where
+    T: NativeType + IsFloat,
+{
+    pub fn new(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            counts: HashMap::new(),
+            n_valid: 0,
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// A hashable key for `value`, which fits every numeric type.
+    fn key(value: T) -> u128 {
+        if value.is_nan() {
+            // larger than any padded float
+            return u128::MAX;
+        }
+        let value = if value == T::default() {
+            T::default()
+        } else {
+            value
+        };
+        let bytes = value.to_le_bytes();
+        let bytes = bytes.as_ref();
+        let mut key = [0u8; 16];
+        key[..bytes.len()].copy_from_slice(bytes);
+        u128::from_le_bytes(key)
+    }
+
+    fn is_valid(&self, idx: usize) -> bool {
+        self.validity.map_or(true, |v| v.get_bit(idx))
+    }
+
+    fn insert(&mut self, idx: usize) {
+        if self.is_valid(idx) {
+            self.n_valid += 1;
+            *self.counts.entry(Self::key(self.values[idx])).or_insert(0) += 1;
+        }
+    }
+
+    fn remove(&mut self, idx: usize) {
+        if self.is_valid(idx) {
+            self.n_valid -= 1;
+            let key = Self::key(self.values[idx]);
+            let count = self.counts.get_mut(&key).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(&key);
+            }
+        }
+    }
+
+    /// Slide the window to `start..end`. Both bounds should never decrease.
+    pub fn update(&mut self, start: usize, end: usize) {
+        if start >= self.end {
+            // no overlap with the previous window
+            self.counts.clear();
+            self.n_valid = 0;
+            self.start = start;
+            self.end = start;
+        }
+        for idx in self.start..start {
+            self.remove(idx);
+        }
+        for idx in self.end..end {
+            self.insert(idx);
+        }
+        self.start = start;
+        self.end = end;
+    }
+
+    /// The number of distinct valid values in the window.
+    pub fn n_unique(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The number of valid values in the window.
+    pub fn n_valid(&self) -> usize {
+        self.n_valid
+    }
+}
+
+/// Count the distinct values of every window.
+///
+/// Null values are not counted. A window is null if it has fewer than `min_periods`
+/// non-null values.
+pub(super) fn rolling_n_unique_impl<T, Fo>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let mut window = NUniqueWindow::new(values, validity);
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            window.update(start, end);
+            out_validity.push(window.n_valid() >= min_periods);
+            window.n_unique() as u32
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::UInt32,
+        out.into(),
+        out_validity.into(),
+    ))
+}
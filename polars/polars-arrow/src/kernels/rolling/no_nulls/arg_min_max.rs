@@ -0,0 +1,72 @@
+use super::*;
+
+/// Find the offset from the start of every window of its largest value.
+/// On ties the first one is taken.
+pub fn rolling_argmax<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    if center {
+        rolling_arg_min_max_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            true,
+            det_offsets_center,
+        )
+    } else {
+        rolling_arg_min_max_impl(values, None, window_size, min_periods, true, det_offsets)
+    }
+}
+
+/// Find the offset from the start of every window of its smallest value.
+/// On ties the first one is taken.
+pub fn rolling_argmin<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    if center {
+        rolling_arg_min_max_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            false,
+            det_offsets_center,
+        )
+    } else {
+        rolling_arg_min_max_impl(values, None, window_size, min_periods, false, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_arg_min_max() {
+        let values = &[1.0f64, 2.0, 3.0, 3.0, f64::NAN, 0.0];
+
+        let out = rolling_argmax(values, 3, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the tie of [2.0, 3.0, 3.0] goes to the first 3.0 and NaN is skipped
+        assert_eq!(out, &[None, Some(1), Some(2), Some(1), Some(0), Some(0)]);
+
+        let out = rolling_argmin(values, 3, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(0), Some(0), Some(0), Some(0), Some(2)]);
+    }
+}
@@ -0,0 +1,132 @@
+use no_nulls;
+use no_nulls::{rolling_apply_agg_window, RollingAggWindowNoNulls};
+
+use super::*;
+
+pub struct FirstWindow<'a, T> {
+    slice: &'a [T],
+}
+
+impl<'a, T: NativeType> RollingAggWindowNoNulls<'a, T> for FirstWindow<'a, T> {
+    fn new(slice: &'a [T], _start: usize, _end: usize) -> Self {
+        Self { slice }
+    }
+
+    unsafe fn update(&mut self, start: usize, _end: usize) -> T {
+        *self.slice.get_unchecked(start)
+    }
+}
+
+pub struct LastWindow<'a, T> {
+    slice: &'a [T],
+}
+
+impl<'a, T: NativeType> RollingAggWindowNoNulls<'a, T> for LastWindow<'a, T> {
+    fn new(slice: &'a [T], _start: usize, _end: usize) -> Self {
+        Self { slice }
+    }
+
+    unsafe fn update(&mut self, _start: usize, end: usize) -> T {
+        *self.slice.get_unchecked(end - 1)
+    }
+}
+
+/// Take the first (oldest) value of every window.
+///
+/// `weights` only make this match the signature of the other rolling kernels, they must be
+/// `None`, as the callers reject them.
+pub fn rolling_first<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    debug_assert!(
+        weights.is_none(),
+        "weights are not supported for 'rolling_first'"
+    );
+    if center {
+        rolling_apply_agg_window::<FirstWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<FirstWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+/// Take the last (newest) value of every window.
+///
+/// `weights` only make this match the signature of the other rolling kernels, they must be
+/// `None`, as the callers reject them.
+pub fn rolling_last<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    debug_assert!(
+        weights.is_none(),
+        "weights are not supported for 'rolling_last'"
+    );
+    if center {
+        rolling_apply_agg_window::<LastWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<LastWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_first_last() {
+        let values = &[1i32, 2, 3, 4, 5];
+
+        let out = rolling_first(values, 3, 2, false, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(1), Some(1), Some(2), Some(3)]);
+
+        let out = rolling_last(values, 3, 2, false, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(2), Some(3), Some(4), Some(5)]);
+
+        let out = rolling_first(values, 3, 2, true, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), Some(1), Some(2), Some(3), Some(4)]);
+
+        let out = rolling_last(values, 3, 2, true, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(2), Some(3), Some(4), Some(5), Some(5)]);
+    }
+}
@@ -488,6 +488,54 @@ where
     }
 }
 
+/// Tracks the min and the max of a window at once, to compute their difference.
+pub struct RangeWindow<'a, T: NativeType + PartialOrd + IsFloat> {
+    min: MinWindow<'a, T>,
+    max: MaxWindow<'a, T>,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd + Sub<Output = T>> RollingAggWindowNoNulls<'a, T>
+    for RangeWindow<'a, T>
+{
+    fn new(slice: &'a [T], start: usize, end: usize) -> Self {
+        Self {
+            min: MinWindow::new(slice, start, end),
+            max: MaxWindow::new(slice, start, end),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        self.max.update(start, end) - self.min.update(start, end)
+    }
+}
+
+/// Compute the range (`max - min`) of every window in a single pass.
+pub fn rolling_range<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat + Sub<Output = T>,
+{
+    if center {
+        rolling_apply_agg_window::<RangeWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<RangeWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -560,4 +608,22 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_rolling_range() {
+        let values = &[1.0f64, 5.0, 3.0, 4.0, 4.0];
+
+        let out = rolling_range(values, 2, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(4.0), Some(2.0), Some(1.0), Some(0.0)]);
+
+        let out = rolling_range(values, 3, 1, true);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(4.0), Some(4.0), Some(2.0), Some(1.0), Some(0.0)]
+        );
+    }
 }
@@ -1,22 +1,34 @@
+mod arg_min_max;
+mod first_last;
 mod mean;
 mod min_max;
+mod mode;
+mod n_unique;
 mod quantile;
+mod rank;
 mod sum;
 mod variance;
+mod zero_crossings;
 
 use std::fmt::Debug;
 
+pub use arg_min_max::*;
 use arrow::array::PrimitiveArray;
 use arrow::datatypes::DataType;
 use arrow::types::NativeType;
+pub use first_last::*;
 pub use mean::*;
 pub use min_max::*;
+pub use mode::*;
+pub use n_unique::*;
 use num_traits::{Float, NumCast};
 pub use quantile::*;
+pub use rank::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use sum::*;
 pub use variance::*;
+pub use zero_crossings::*;
 
 use super::*;
 use crate::utils::CustomIterTools;
@@ -41,10 +53,28 @@ where
     Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
     Agg: RollingAggWindowNoNulls<'a, T>,
     T: Debug + IsFloat + NativeType,
+{
+    rolling_apply_agg_window_with(values, window_size, min_periods, det_offsets_fn, Agg::new)
+}
+
+/// Like [`rolling_apply_agg_window`], but the window is created by `new_window`,
+/// e.g. to set the parameters of the aggregation.
+pub(super) fn rolling_apply_agg_window_with<'a, Agg, T, Fo, N>(
+    values: &'a [T],
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+    new_window: N,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+    Agg: RollingAggWindowNoNulls<'a, T>,
+    N: FnOnce(&'a [T], usize, usize) -> Agg,
+    T: Debug + IsFloat + NativeType,
 {
     let len = values.len();
     let (start, end) = det_offsets_fn(0, window_size, len);
-    let mut agg_window = Agg::new(values, start, end);
+    let mut agg_window = new_window(values, start, end);
 
     let out = (0..len)
         .map(|idx| {
@@ -106,7 +136,7 @@ where
     ))
 }
 
-fn compute_var_weights<T>(vals: &[T], weights: &[T]) -> T
+fn compute_var_weights<T>(vals: &[T], weights: &[T], ddof: u8) -> T
 where
     T: Float + std::ops::AddAssign,
 {
@@ -122,8 +152,9 @@ where
     let count = NumCast::from(vals.len()).unwrap();
 
     let mean = sum / count;
+    let ddof: T = NumCast::from(ddof).unwrap();
     // apply Bessel's correction
-    ((sum_of_squares / count) - mean * mean) / (count - T::one()) * count
+    ((sum_of_squares / count) - mean * mean) / (count - ddof) * count
 }
 
 pub(crate) fn compute_mean_weights<T>(values: &[T], weights: &[T]) -> T
@@ -0,0 +1,48 @@
+use super::*;
+
+/// Take the most frequent value of every window.
+/// Ties are broken by taking the smallest value.
+pub fn rolling_mode<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    if center {
+        rolling_mode_impl(values, None, window_size, min_periods, det_offsets_center)
+    } else {
+        rolling_mode_impl(values, None, window_size, min_periods, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_mode() {
+        let values = &[1i32, 2, 2, 3, 3, 1];
+        let out = rolling_mode(values, 3, 3, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // every full window has a clear winner
+        assert_eq!(out, &[None, None, Some(2), Some(2), Some(3), Some(3)]);
+
+        // every window of two distinct values is a tie, broken by the smallest value
+        let out = rolling_mode(values, 2, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), Some(1), Some(2), Some(2), Some(3), Some(1)]);
+
+        let values = &[0.5f64, f64::NAN, f64::NAN, 0.5];
+        let out = rolling_mode(values, 4, 1, true);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // NaNs are counted together and are larger than every other value in a tie
+        assert_eq!(out[..3], [Some(0.5), Some(0.5), Some(0.5)]);
+        assert!(out[3].unwrap().is_nan());
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Count the distinct values of every window.
+pub fn rolling_n_unique<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    if center {
+        rolling_n_unique_impl(values, None, window_size, min_periods, det_offsets_center)
+    } else {
+        rolling_n_unique_impl(values, None, window_size, min_periods, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_n_unique() {
+        let values = &[1i64, 2, 1, 3, 3, 4];
+        let out = rolling_n_unique(values, 3, 3, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the window gains a duplicate 1 at 2 and loses it at 3, then gains a duplicate 3 at 4
+        assert_eq!(out, &[None, None, Some(2), Some(3), Some(2), Some(2)]);
+
+        let values = &[0.0f64, -0.0, f64::NAN, f64::NAN];
+        let out = rolling_n_unique(values, 4, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), Some(1), Some(2), Some(2)]);
+    }
+}
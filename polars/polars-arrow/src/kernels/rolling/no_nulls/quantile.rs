@@ -290,6 +290,30 @@ where
     ))
 }
 
+/// Compute the median absolute deviation (MAD) from the median of every window.
+/// If `scale` is set, the MAD is multiplied by 1.4826 to estimate the standard deviation
+/// of normally distributed data.
+pub fn rolling_mad(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    scale: bool,
+) -> ArrayRef {
+    if center {
+        rolling_mad_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            scale,
+            det_offsets_center,
+        )
+    } else {
+        rolling_mad_impl(values, None, window_size, min_periods, scale, det_offsets)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -395,4 +419,18 @@ mod test {
             assert_eq!(out1, out2);
         }
     }
+
+    #[test]
+    fn test_rolling_mad() {
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 100.0];
+
+        let out = rolling_mad(values, 3, 2, false, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(0.5), Some(1.0), Some(1.0), Some(1.0)]);
+
+        let out = rolling_mad(values, 3, 2, false, true);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert_eq!(out.get(4), Some(1.4826));
+    }
 }
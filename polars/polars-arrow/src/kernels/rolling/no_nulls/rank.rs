@@ -0,0 +1,76 @@
+use super::*;
+
+/// Rank the value of every row among the values of its window.
+pub fn rolling_rank<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: RollingRankParams,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    if center {
+        rolling_rank_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            params,
+            det_offsets_center,
+        )
+    } else {
+        rolling_rank_impl(values, None, window_size, min_periods, params, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_rank() {
+        let rank = |values: &[i32], method, descending| {
+            let params = RollingRankParams { method, descending };
+            let out = rolling_rank(values, 4, 2, false, params);
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            out.into_iter().map(|v| v.copied()).collect::<Vec<_>>()
+        };
+        // the last windows are [1, 3, 3, 2] and [3, 3, 2, 3]
+        let values = &[1, 3, 3, 2, 3];
+        assert_eq!(
+            rank(values, RollingRankMethod::Average, false),
+            &[None, Some(2.0), Some(2.5), Some(2.0), Some(3.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Min, false),
+            &[None, Some(2.0), Some(2.0), Some(2.0), Some(2.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Max, false),
+            &[None, Some(2.0), Some(3.0), Some(2.0), Some(4.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Dense, false),
+            &[None, Some(2.0), Some(2.0), Some(2.0), Some(2.0)]
+        );
+
+        assert_eq!(
+            rank(values, RollingRankMethod::Average, true),
+            &[None, Some(1.0), Some(1.5), Some(3.0), Some(2.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Min, true),
+            &[None, Some(1.0), Some(1.0), Some(3.0), Some(1.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Max, true),
+            &[None, Some(1.0), Some(2.0), Some(3.0), Some(3.0)]
+        );
+        assert_eq!(
+            rank(values, RollingRankMethod::Dense, true),
+            &[None, Some(1.0), Some(1.0), Some(2.0), Some(1.0)]
+        );
+    }
+}
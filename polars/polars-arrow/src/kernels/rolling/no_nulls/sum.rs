@@ -115,6 +115,57 @@ where
     }
 }
 
+/// Apply a rolling sum that resets to zero whenever `reset` returns `true` for the running sum
+/// of a window, e.g. to compute a CUSUM.
+///
+/// This is considerably slower than [`rolling_sum`] as every window is summed from scratch.
+pub fn rolling_sum_with_reset(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    reset: &dyn Fn(f64) -> bool,
+) -> ArrayRef {
+    if center {
+        rolling_sum_with_reset_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            reset,
+            det_offsets_center,
+        )
+    } else {
+        rolling_sum_with_reset_impl(values, None, window_size, min_periods, reset, det_offsets)
+    }
+}
+
+/// Compute the sum of the positive values (gains) and the absolute sum of the negative values
+/// (losses) of every window. Returns the `(gains, losses)`.
+pub fn rolling_gain_loss(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> (ArrayRef, ArrayRef) {
+    if center {
+        rolling_gain_loss_impl(values, None, window_size, min_periods, det_offsets_center)
+    } else {
+        rolling_gain_loss_impl(values, None, window_size, min_periods, det_offsets)
+    }
+}
+
+/// Compute the dot product of every window with `reference`. The window size is the length
+/// of `reference`, whose first value multiplies the oldest value of the window.
+pub fn rolling_dot(
+    values: &[f64],
+    min_periods: usize,
+    center: bool,
+    reference: &[f64],
+) -> ArrayRef {
+    rolling_dot_impl(values, None, reference, min_periods, center)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -169,4 +220,72 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_rolling_sum_with_reset() {
+        // CUSUM that resets once the running sum exceeds 3
+        let values = &[1.0f64, 1.0, 2.0, 1.0, 1.0, 3.0];
+        let out = rolling_sum_with_reset(values, 6, 1, false, &|sum| sum > 3.0);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(0.0),
+                Some(1.0),
+                Some(2.0),
+                Some(0.0)
+            ]
+        );
+
+        let out = rolling_sum_with_reset(values, 2, 2, false, &|sum| sum > 3.0);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[None, Some(2.0), Some(3.0), Some(3.0), Some(2.0), Some(0.0)]
+        );
+    }
+
+    #[test]
+    fn test_rolling_gain_loss() {
+        let values = &[1.0f64, -2.0, 3.0, 0.0, -4.0];
+        let (gains, losses) = rolling_gain_loss(values, 3, 2, false);
+
+        let gains = gains
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        let gains = gains.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(gains, &[None, Some(1.0), Some(4.0), Some(3.0), Some(3.0)]);
+
+        let losses = losses
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        let losses = losses.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(losses, &[None, Some(2.0), Some(2.0), Some(2.0), Some(4.0)]);
+    }
+
+    #[test]
+    fn test_rolling_dot() {
+        let values = &[1.0f64, 2.0, 3.0, 2.0, 1.0];
+        let reference = &[1.0f64, 2.0, 1.0];
+
+        let out = rolling_dot(values, 3, false, reference);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(8.0), Some(10.0), Some(8.0)]);
+
+        // clipped windows use the matching part of the reference
+        let out = rolling_dot(values, 1, true, reference);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(4.0), Some(8.0), Some(10.0), Some(8.0), Some(4.0)]
+        );
+    }
 }
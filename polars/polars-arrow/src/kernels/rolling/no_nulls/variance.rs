@@ -1,4 +1,4 @@
-use no_nulls::{rolling_apply_agg_window, RollingAggWindowNoNulls};
+use no_nulls::{rolling_apply_agg_window_with, RollingAggWindowNoNulls};
 use num_traits::pow::Pow;
 
 use super::mean::MeanWindow;
@@ -80,6 +80,15 @@ impl<'a, T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign + Mul<
 pub struct VarWindow<'a, T> {
     mean: MeanWindow<'a, T>,
     sum_of_squares: SumSquaredWindow<'a, T>,
+    ddof: u8,
+}
+
+impl<'a, T> VarWindow<'a, T> {
+    /// Set the delta degrees of freedom, 1 by default.
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.ddof = ddof;
+        self
+    }
 }
 
 impl<
@@ -101,6 +110,7 @@ impl<
         Self {
             mean: MeanWindow::new(slice, start, end),
             sum_of_squares: SumSquaredWindow::new(slice, start, end),
+            ddof: 1,
         }
     }
 
@@ -111,11 +121,12 @@ impl<
         let mean = self.mean.update(start, end);
         let var = mean_of_squares - mean * mean;
 
-        if end - start == 1 {
+        if end - start <= self.ddof as usize {
             T::zero()
         } else {
+            let ddof: T = NumCast::from(self.ddof).unwrap();
             // apply Bessel's correction
-            let out = var / (count - T::one()) * count;
+            let out = var / (count - ddof) * count;
             // variance cannot be negative.
             // if it is negative it is due to numeric instability
             if out < T::zero() {
@@ -127,12 +138,14 @@ impl<
     }
 }
 
+/// Compute the variance of every window with `ddof` delta degrees of freedom.
 pub fn rolling_var<T>(
     values: &[T],
     window_size: usize,
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
+    ddof: u8,
 ) -> ArrayRef
 where
     T: NativeType
@@ -148,17 +161,19 @@ where
         + Sub<Output = T>,
 {
     match (center, weights) {
-        (true, None) => rolling_apply_agg_window::<VarWindow<_>, _, _>(
+        (true, None) => rolling_apply_agg_window_with(
             values,
             window_size,
             min_periods,
             det_offsets_center,
+            |slice, start, end| VarWindow::new(slice, start, end).with_ddof(ddof),
         ),
-        (false, None) => rolling_apply_agg_window::<VarWindow<_>, _, _>(
+        (false, None) => rolling_apply_agg_window_with(
             values,
             window_size,
             min_periods,
             det_offsets,
+            |slice, start, end| VarWindow::new(slice, start, end).with_ddof(ddof),
         ),
         (true, Some(weights)) => {
             let weights = coerce_weights(weights);
@@ -167,7 +182,7 @@ where
                 window_size,
                 min_periods,
                 det_offsets_center,
-                compute_var_weights,
+                |vals, weights| compute_var_weights(vals, weights, ddof),
                 &weights,
             )
         }
@@ -178,7 +193,7 @@ where
                 window_size,
                 min_periods,
                 det_offsets,
-                compute_var_weights,
+                |vals, weights| compute_var_weights(vals, weights, ddof),
                 &weights,
             )
         }
@@ -192,6 +207,14 @@ pub struct StdWindow<'a, T> {
     var: VarWindow<'a, T>,
 }
 
+impl<'a, T> StdWindow<'a, T> {
+    /// Set the delta degrees of freedom, 1 by default.
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.var = self.var.with_ddof(ddof);
+        self
+    }
+}
+
 impl<
         'a,
         T: NativeType
@@ -220,12 +243,14 @@ impl<
     }
 }
 
+/// Compute the standard deviation of every window with `ddof` delta degrees of freedom.
 pub fn rolling_std<T>(
     values: &[T],
     window_size: usize,
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
+    ddof: u8,
 ) -> ArrayRef
 where
     T: NativeType
@@ -242,17 +267,19 @@ where
         + Pow<T, Output = T>,
 {
     match (center, weights) {
-        (true, None) => rolling_apply_agg_window::<StdWindow<_>, _, _>(
+        (true, None) => rolling_apply_agg_window_with(
             values,
             window_size,
             min_periods,
             det_offsets_center,
+            |slice, start, end| StdWindow::new(slice, start, end).with_ddof(ddof),
         ),
-        (false, None) => rolling_apply_agg_window::<StdWindow<_>, _, _>(
+        (false, None) => rolling_apply_agg_window_with(
             values,
             window_size,
             min_periods,
             det_offsets,
+            |slice, start, end| StdWindow::new(slice, start, end).with_ddof(ddof),
         ),
         (_, Some(_)) => {
             panic!("weights not yet supported for rolling_std")
@@ -260,6 +287,159 @@ where
     }
 }
 
+/// Compute the coefficient of variation (`std / mean`) of every window with
+/// `ddof` delta degrees of freedom. Windows with a mean of zero are null.
+pub fn rolling_cv(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    ddof: u8,
+) -> ArrayRef {
+    let det_offsets_fn: fn(Idx, WindowSize, Len) -> (Start, End) = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let len = values.len();
+    let (start, end) = det_offsets_fn(0, window_size, len);
+    let mut window = VarWindow::new(values, start, end).with_ddof(ddof);
+    rolling_cv_impl(
+        len,
+        window_size,
+        min_periods,
+        ddof,
+        det_offsets_fn,
+        |start, end| {
+            // safety: we are in bounds
+            unsafe {
+                let var = window.update(start, end);
+                // the window has already slid to `start..end`, so this is just a lookup
+                let mean = window.mean.update(start, end);
+                Some((var, mean, end - start))
+            }
+        },
+    )
+}
+
+/// Compute the autocorrelation at `lag` of every window, i.e. the Pearson correlation between
+/// the values at `i` and `i + lag` within the window.
+pub fn rolling_autocorr(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    lag: usize,
+) -> ArrayRef {
+    if center {
+        rolling_autocorr_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            lag,
+            det_offsets_center,
+        )
+    } else {
+        rolling_autocorr_impl(values, None, window_size, min_periods, lag, det_offsets)
+    }
+}
+
+/// Compute the covariance between every window of `x` and the window of `y` at the same
+/// positions, with `ddof` delta degrees of freedom.
+pub fn rolling_cov(
+    x: &[f64],
+    y: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    ddof: u8,
+) -> ArrayRef {
+    let kind = CovKind::Cov { ddof };
+    if center {
+        rolling_cov_impl(
+            x,
+            y,
+            None,
+            window_size,
+            min_periods,
+            kind,
+            det_offsets_center,
+        )
+    } else {
+        rolling_cov_impl(x, y, None, window_size, min_periods, kind, det_offsets)
+    }
+}
+
+/// Compute the Pearson correlation between every window of `x` and the window of `y` at the
+/// same positions.
+pub fn rolling_corr(
+    x: &[f64],
+    y: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef {
+    if center {
+        rolling_cov_impl(
+            x,
+            y,
+            None,
+            window_size,
+            min_periods,
+            CovKind::Corr,
+            det_offsets_center,
+        )
+    } else {
+        rolling_cov_impl(
+            x,
+            y,
+            None,
+            window_size,
+            min_periods,
+            CovKind::Corr,
+            det_offsets,
+        )
+    }
+}
+
+/// Compute the rescaled range (R/S) statistic of every window, i.e. the range of the cumulative
+/// deviations from the mean divided by the population standard deviation.
+pub fn rolling_rs(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef {
+    if center {
+        rolling_rs_impl(values, None, window_size, min_periods, det_offsets_center)
+    } else {
+        rolling_rs_impl(values, None, window_size, min_periods, det_offsets)
+    }
+}
+
+/// Compute the kurtosis of every window, see [`RollingKurtosisParams`] for the definitions.
+pub fn rolling_kurtosis(
+    values: &[f64],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: RollingKurtosisParams,
+) -> ArrayRef {
+    if center {
+        rolling_kurtosis_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            params,
+            det_offsets_center,
+        )
+    } else {
+        rolling_kurtosis_impl(values, None, window_size, min_periods, params, det_offsets)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -268,12 +448,12 @@ mod test {
     fn test_rolling_var() {
         let values = &[1.0f64, 5.0, 3.0, 4.0];
 
-        let out = rolling_var(values, 2, 2, false, None);
+        let out = rolling_var(values, 2, 2, false, None, 1);
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(8.0), Some(2.0), Some(0.5)]);
 
-        let out = rolling_var(values, 2, 1, false, None);
+        let out = rolling_var(values, 2, 1, false, None, 1);
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -286,7 +466,7 @@ mod test {
         );
         // test nan handling.
         let values = &[-10.0, 2.0, 3.0, f64::nan(), 5.0, 6.0, 7.0];
-        let out = rolling_var(values, 3, 3, false, None);
+        let out = rolling_var(values, 3, 3, false, None, 1);
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         // we cannot compare nans, so we compare the string values
@@ -306,4 +486,169 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_rolling_cv() {
+        let values = &[1.0f64, 3.0, -1.0, 1.0, 2.0];
+
+        let out = rolling_cv(values, 2, 2, false, 1);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let sqrt_2 = 2.0f64.sqrt();
+        let expected = [
+            None,
+            Some(sqrt_2 / 2.0),
+            Some(2.0 * sqrt_2),
+            None,
+            Some(sqrt_2 / 3.0),
+        ];
+        for (out, expected) in out.into_iter().zip(expected) {
+            match (out, expected) {
+                (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-12),
+                (out, expected) => assert_eq!(out.copied(), expected),
+            }
+        }
+
+        // a single value has no sample std
+        let out = rolling_cv(values, 2, 1, false, 1);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert!(out.is_null(0));
+
+        let out = rolling_cv(values, 2, 1, false, 0);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert_eq!(out.get(0), Some(0.0));
+        assert_eq!(out.get(1), Some(0.5));
+    }
+
+    #[test]
+    fn test_rolling_autocorr() {
+        let values = &[1.0f64, 2.0, 1.0, 2.0, 1.0, 3.0];
+        let assert_close = |out: ArrayRef, expected: &[Option<f64>]| {
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            for (out, expected) in out.into_iter().zip(expected) {
+                match (out, expected) {
+                    (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-12),
+                    (out, expected) => assert_eq!(out.copied(), *expected),
+                }
+            }
+        };
+
+        // a window needs at least `lag + 2` values
+        let out = rolling_autocorr(values, 4, 1, false, 2);
+        assert_close(out, &[None, None, None, Some(1.0), Some(1.0), Some(1.0)]);
+
+        let out = rolling_autocorr(values, 4, 1, false, 1);
+        assert_close(
+            out,
+            &[
+                None,
+                None,
+                Some(-1.0),
+                Some(-1.0),
+                Some(-1.0),
+                Some(-(3.0f64).sqrt() / 2.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rolling_rs() {
+        // a ramp of length 3 has cumulative deviations [-1, -1, 0] and a std of sqrt(2/3)
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 4.0];
+        let out = rolling_rs(values, 3, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert!(out.is_null(0));
+        assert!((out.value(1) - 1.0).abs() < 1e-12);
+        assert!((out.value(2) - 1.5f64.sqrt()).abs() < 1e-12);
+        assert!((out.value(3) - 1.5f64.sqrt()).abs() < 1e-12);
+        // [3, 4, 4] has deviations [-2/3, 1/3, 1/3] and a std of sqrt(2) / 3
+        assert!((out.value(4) - 2.0f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_kurtosis() {
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 6.0, 6.0, 6.0];
+        let assert_close = |params: RollingKurtosisParams, expected: &[Option<f64>]| {
+            let out = rolling_kurtosis(values, 4, 4, false, params);
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            assert_eq!(out.len(), expected.len());
+            for (out, expected) in out.into_iter().zip(expected) {
+                match (out, expected) {
+                    (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-12),
+                    (out, expected) => assert_eq!(out.copied(), *expected),
+                }
+            }
+        };
+
+        // scipy.stats.kurtosis([1, 2, 3, 4]) is -1.36, the last window has no variance
+        let params = RollingKurtosisParams::default();
+        let ramp = Some(-1.36);
+        assert_close(
+            params,
+            &[
+                None,
+                None,
+                None,
+                ramp,
+                ramp,
+                ramp,
+                Some(-166.0 / 121.0),
+                Some(-2.0 / 3.0),
+                None,
+            ],
+        );
+        let params = RollingKurtosisParams {
+            fisher: false,
+            bias: false,
+        };
+        let ramp = Some(1.8);
+        assert_close(
+            params,
+            &[
+                None,
+                None,
+                None,
+                ramp,
+                ramp,
+                ramp,
+                Some(207.0 / 121.0),
+                Some(7.0),
+                None,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rolling_cov_corr() {
+        let x = &[1.0f64, 2.0, 3.0, 4.0];
+        let y = &[2.0f64, 4.0, 6.0, 6.0];
+
+        let out = rolling_cov(x, y, 2, 1, false, 1);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // a single pair has no degrees of freedom left
+        assert_eq!(out, &[None, Some(1.0), Some(1.0), Some(0.0)]);
+
+        let out = rolling_corr(x, y, 2, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // `y` has no variance in the last window
+        assert_eq!(out, &[None, Some(1.0), Some(1.0), None]);
+    }
+
+    #[test]
+    fn test_rolling_var_ddof() {
+        let values = &[1.0f64, 5.0, 3.0, 4.0];
+        let collect = |out: ArrayRef| {
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            out.into_iter().map(|v| v.copied()).collect::<Vec<_>>()
+        };
+
+        let out = collect(rolling_var(values, 2, 2, false, None, 0));
+        assert_eq!(out, &[None, Some(4.0), Some(1.0), Some(0.25)]);
+        let out = collect(rolling_std(values, 2, 2, false, None, 0));
+        assert_eq!(out, &[None, Some(2.0), Some(1.0), Some(0.5)]);
+        // the weighted kernel takes `ddof` into account as well
+        let out = collect(rolling_var(values, 2, 2, false, Some(&[1.0, 1.0]), 0));
+        assert_eq!(out, &[None, Some(4.0), Some(1.0), Some(0.25)]);
+    }
 }
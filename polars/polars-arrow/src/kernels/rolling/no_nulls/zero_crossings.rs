@@ -0,0 +1,61 @@
+use super::*;
+
+/// Count the number of times the values cross zero within every window.
+pub fn rolling_zero_crossings<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    policy: ZeroCrossingPolicy,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + Zero,
+{
+    if center {
+        rolling_zero_crossings_impl(
+            values,
+            None,
+            window_size,
+            min_periods,
+            policy,
+            det_offsets_center,
+        )
+    } else {
+        rolling_zero_crossings_impl(values, None, window_size, min_periods, policy, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_zero_crossings() {
+        let values = &[1.0f64, -1.0, 2.0, -2.0];
+        let out = rolling_zero_crossings(values, 4, 4, false, ZeroCrossingPolicy::Skip);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(3)]);
+
+        let out = rolling_zero_crossings(values, 2, 1, false, ZeroCrossingPolicy::Skip);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0), Some(1), Some(1), Some(1)]);
+
+        let values = &[1i32, 0, -1, 0];
+        let out = rolling_zero_crossings(values, 4, 1, false, ZeroCrossingPolicy::Skip);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0), Some(0), Some(1), Some(1)]);
+
+        let out = rolling_zero_crossings(values, 4, 1, false, ZeroCrossingPolicy::Positive);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0), Some(0), Some(1), Some(2)]);
+
+        let out = rolling_zero_crossings(values, 4, 1, false, ZeroCrossingPolicy::Negative);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0), Some(1), Some(1), Some(1)]);
+    }
+}
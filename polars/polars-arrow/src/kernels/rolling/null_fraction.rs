@@ -0,0 +1,47 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the fraction of null values in every window.
+///
+/// Windows are clipped at the edges, so the fraction is relative to the number of
+/// values that are actually in the window. A window is null if it holds fewer than
+/// `min_periods` values, null or not.
+pub(super) fn rolling_null_fraction_impl<Fo>(
+    validity: Option<&Bitmap>,
+    len: usize,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    // `null_count[i]` is the number of nulls up to (excluding) `i`
+    let mut null_count = Vec::with_capacity(len + 1);
+    null_count.push(0usize);
+    for idx in 0..len {
+        let is_null = validity.map_or(false, |v| !v.get_bit(idx));
+        null_count.push(null_count[idx] + is_null as usize);
+    }
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let count = end - start;
+            out_validity.push(count >= min_periods);
+            if count == 0 {
+                0.0
+            } else {
+                (null_count[end] - null_count[start]) as f64 / count as f64
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
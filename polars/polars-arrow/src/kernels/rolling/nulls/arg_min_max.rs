@@ -0,0 +1,70 @@
+use super::*;
+
+/// Find the offset from the start of every window of its largest value.
+/// On ties the first one is taken. Null values are skipped.
+pub fn rolling_argmax<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    rolling_arg_min_max(arr, window_size, min_periods, center, true)
+}
+
+/// Find the offset from the start of every window of its smallest value.
+/// On ties the first one is taken. Null values are skipped.
+pub fn rolling_argmin<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    rolling_arg_min_max(arr, window_size, min_periods, center, false)
+}
+
+fn rolling_arg_min_max<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    max: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_arg_min_max_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            max,
+            det_offsets_center,
+        )
+    } else {
+        rolling_arg_min_max_impl(values, validity, window_size, min_periods, max, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_arg_min_max_nulls() {
+        let arr = PrimitiveArray::from(&[Some(5i32), None, Some(5), None, None]);
+        let out = rolling_argmax(&arr, 3, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the offsets count the nulls at the start of the window
+        assert_eq!(out, &[Some(0), Some(0), Some(0), Some(1), Some(0)]);
+    }
+}
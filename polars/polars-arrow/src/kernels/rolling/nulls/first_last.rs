@@ -0,0 +1,190 @@
+use nulls;
+use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+
+use super::*;
+
+/// Count the null values in `validity[start..end]`.
+unsafe fn count_nulls(validity: &Bitmap, start: usize, end: usize) -> usize {
+    (start..end)
+        .filter(|idx| !validity.get_bit_unchecked(*idx))
+        .count()
+}
+
+pub struct FirstWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    // index of the first valid value in the window
+    first: Option<usize>,
+    last_start: usize,
+    last_end: usize,
+    null_count: usize,
+}
+
+impl<'a, T: NativeType> RollingAggWindowNulls<'a, T> for FirstWindow<'a, T> {
+    unsafe fn new(slice: &'a [T], validity: &'a Bitmap, start: usize, end: usize) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            first: None,
+            last_start: start,
+            last_end: start,
+            null_count: 0,
+        };
+        out.update(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        // where to look for the first valid value, if it isn't in the window anymore
+        let search_from = if start >= self.last_end {
+            // a completely new window
+            self.null_count = count_nulls(self.validity, start, end);
+            Some(start)
+        } else {
+            self.null_count -= count_nulls(self.validity, self.last_start, start);
+            self.null_count += count_nulls(self.validity, self.last_end, end);
+            match self.first {
+                Some(first) if first >= start => None,
+                Some(_) => Some(start),
+                // the previous window only had null values
+                None => Some(std::cmp::max(start, self.last_end)),
+            }
+        };
+        if let Some(from) = search_from {
+            self.first = (from..end).find(|idx| self.validity.get_bit_unchecked(*idx));
+        }
+        self.last_start = start;
+        self.last_end = end;
+        self.first.map(|idx| *self.slice.get_unchecked(idx))
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+pub struct LastWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    // index of the last valid value in the window
+    last: Option<usize>,
+    last_start: usize,
+    last_end: usize,
+    null_count: usize,
+}
+
+impl<'a, T: NativeType> RollingAggWindowNulls<'a, T> for LastWindow<'a, T> {
+    unsafe fn new(slice: &'a [T], validity: &'a Bitmap, start: usize, end: usize) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            last: None,
+            last_start: start,
+            last_end: start,
+            null_count: 0,
+        };
+        out.update(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let entering_from = if start >= self.last_end {
+            // a completely new window
+            self.null_count = count_nulls(self.validity, start, end);
+            start
+        } else {
+            self.null_count -= count_nulls(self.validity, self.last_start, start);
+            self.null_count += count_nulls(self.validity, self.last_end, end);
+            self.last_end
+        };
+        // the newest valid value that entered the window, if any, is the last one
+        match (entering_from..end)
+            .rev()
+            .find(|idx| self.validity.get_bit_unchecked(*idx))
+        {
+            Some(idx) => self.last = Some(idx),
+            None => self.last = self.last.filter(|idx| *idx >= start),
+        }
+        self.last_start = start;
+        self.last_end = end;
+        self.last.map(|idx| *self.slice.get_unchecked(idx))
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+/// Take the first (oldest) valid value of every window.
+///
+/// `weights` only make this match the signature of the other rolling kernels, they must be
+/// `None`, as the callers reject them.
+pub fn rolling_first<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    debug_assert!(
+        weights.is_none(),
+        "weights are not supported for 'rolling_first'"
+    );
+    if center {
+        rolling_apply_agg_window::<FirstWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<FirstWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+/// Take the last (newest) valid value of every window.
+///
+/// `weights` only make this match the signature of the other rolling kernels, they must be
+/// `None`, as the callers reject them.
+pub fn rolling_last<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    debug_assert!(
+        weights.is_none(),
+        "weights are not supported for 'rolling_last'"
+    );
+    if center {
+        rolling_apply_agg_window::<LastWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<LastWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
@@ -432,3 +432,59 @@ where
         )
     }
 }
+
+/// Tracks the min and the max of a window at once, to compute their difference.
+pub struct RangeWindow<'a, T: NativeType + PartialOrd + IsFloat> {
+    min: MinWindow<'a, T>,
+    max: MaxWindow<'a, T>,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd + Sub<Output = T>> RollingAggWindowNulls<'a, T>
+    for RangeWindow<'a, T>
+{
+    unsafe fn new(slice: &'a [T], validity: &'a Bitmap, start: usize, end: usize) -> Self {
+        Self {
+            min: MinWindow::new(slice, validity, start, end),
+            max: MaxWindow::new(slice, validity, start, end),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let min = self.min.update(start, end);
+        let max = self.max.update(start, end);
+        Some(max? - min?)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.min.is_valid(min_periods)
+    }
+}
+
+/// Compute the range (`max - min`) of every window in a single pass. Null values are skipped.
+pub fn rolling_range<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat + Sub<Output = T>,
+{
+    if center {
+        rolling_apply_agg_window::<RangeWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<RangeWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
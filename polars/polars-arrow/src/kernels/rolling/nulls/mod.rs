@@ -1,14 +1,28 @@
+mod arg_min_max;
+mod first_last;
 mod mean;
 mod min_max;
+mod mode;
+mod n_unique;
+mod null_fraction;
 mod quantile;
+mod rank;
 mod sum;
 mod variance;
+mod zero_crossings;
 
+pub use arg_min_max::*;
+pub use first_last::*;
 pub use mean::*;
 pub use min_max::*;
+pub use mode::*;
+pub use n_unique::*;
+pub use null_fraction::*;
 pub use quantile::*;
+pub use rank::*;
 pub use sum::*;
 pub use variance::*;
+pub use zero_crossings::*;
 
 use super::*;
 
@@ -36,11 +50,37 @@ where
     Fo: Fn(Idx, WindowSize, Len) -> (Start, End) + Copy,
     Agg: RollingAggWindowNulls<'a, T>,
     T: IsFloat + NativeType,
+{
+    rolling_apply_agg_window_with(
+        values,
+        validity,
+        window_size,
+        min_periods,
+        det_offsets_fn,
+        // Safety; we are in bounds
+        |values, validity, start, end| unsafe { Agg::new(values, validity, start, end) },
+    )
+}
+
+/// Like [`rolling_apply_agg_window`], but the window is created by `new_window`,
+/// e.g. to set the parameters of the aggregation.
+pub(super) fn rolling_apply_agg_window_with<'a, Agg, T, Fo, N>(
+    values: &'a [T],
+    validity: &'a Bitmap,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+    new_window: N,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End) + Copy,
+    Agg: RollingAggWindowNulls<'a, T>,
+    N: FnOnce(&'a [T], &'a Bitmap, usize, usize) -> Agg,
+    T: IsFloat + NativeType,
 {
     let len = values.len();
     let (start, end) = det_offsets_fn(0, window_size, len);
-    // Safety; we are in bounds
-    let mut agg_window = unsafe { Agg::new(values, validity, start, end) };
+    let mut agg_window = new_window(values, validity, start, end);
 
     let mut validity = match create_validity(min_periods, len, window_size, det_offsets_fn) {
         Some(v) => v,
@@ -163,7 +203,7 @@ mod test {
         let arr = get_null_arr();
         let arr = &arr;
 
-        let out = rolling_var(arr, 3, 1, false, None);
+        let out = rolling_var(arr, 3, 1, false, None, 1);
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -172,7 +212,7 @@ mod test {
 
         assert_eq!(out, &[0.0, 0.0, 2.0, 12.5]);
 
-        let out = rolling_var(arr, 4, 1, false, None);
+        let out = rolling_var(arr, 4, 1, false, None, 1);
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -0,0 +1,56 @@
+use super::*;
+
+/// Take the most frequent value of every window.
+/// Ties are broken by taking the smallest value. Null values are skipped.
+pub fn rolling_mode<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let validity = arr.validity();
+    if center {
+        rolling_mode_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_mode_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_mode_nulls() {
+        let arr =
+            PrimitiveArray::from(&[Some(4i64), None, None, Some(7), Some(4), Some(7), Some(7)]);
+        let out = rolling_mode(&arr, 2, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the window of two nulls is null
+        assert_eq!(
+            out,
+            &[Some(4), Some(4), None, Some(7), Some(4), Some(4), Some(7)]
+        );
+
+        let out = rolling_mode(&arr, 4, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(4), Some(4), Some(7), Some(7)]);
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Count the distinct values of every window. Null values are not counted.
+pub fn rolling_n_unique<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_n_unique_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_n_unique_impl(values, validity, window_size, min_periods, det_offsets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_n_unique_nulls() {
+        let arr = PrimitiveArray::from(&[Some(1i32), None, None, Some(1), Some(2)]);
+        let out = rolling_n_unique(&arr, 2, 1, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the window of two nulls is null
+        assert_eq!(out, &[Some(1), Some(1), None, Some(1), Some(2)]);
+    }
+}
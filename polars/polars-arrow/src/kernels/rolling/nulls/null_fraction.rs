@@ -0,0 +1,61 @@
+use super::*;
+
+/// Compute the fraction of null values within every window, between `0.0` and `1.0`.
+/// Windows are clipped at the edges.
+pub fn rolling_null_fraction<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType,
+{
+    if center {
+        rolling_null_fraction_impl(
+            arr.validity(),
+            arr.len(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_null_fraction_impl(
+            arr.validity(),
+            arr.len(),
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_null_fraction() {
+        let arr = PrimitiveArray::from([Some(1), None, None, Some(4), Some(5)]);
+
+        let out = rolling_null_fraction(&arr, 2, 2, false);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(0.5), Some(1.0), Some(0.5), Some(0.0)]);
+
+        // clipped at the edges
+        let out = rolling_null_fraction(&arr, 3, 1, true);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(0.5),
+                Some(2.0 / 3.0),
+                Some(2.0 / 3.0),
+                Some(1.0 / 3.0),
+                Some(0.0)
+            ]
+        );
+    }
+}
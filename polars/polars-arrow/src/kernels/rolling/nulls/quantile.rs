@@ -2,13 +2,15 @@ use super::*;
 use crate::index::IdxSize;
 use crate::trusted_len::TrustedLen;
 
-// used by agg_quantile
+// used by agg_quantile and the rolling quantile by a time column,
+// a window with fewer than `min_periods` valid values is null
 #[allow(clippy::too_many_arguments)]
 pub fn rolling_quantile_by_iter<T, O>(
     values: &[T],
     bitmap: &Bitmap,
     quantile: f64,
     interpolation: QuantileInterpolOptions,
+    min_periods: usize,
     offsets: O,
 ) -> ArrayRef
 where
@@ -55,7 +57,7 @@ where
                 let null_count = sorted_window.null_count;
                 let window = sorted_window.window();
 
-                match compute_quantile(window, null_count, quantile, interpolation, 1) {
+                match compute_quantile(window, null_count, quantile, interpolation, min_periods) {
                     Some(val) => val,
                     None => {
                         validity.set(idx, false);
@@ -287,6 +289,41 @@ where
     }
 }
 
+/// Compute the median absolute deviation (MAD) from the median of every window.
+/// Null values are skipped.
+///
+/// If `scale` is set, the MAD is multiplied by 1.4826 to estimate the standard deviation
+/// of normally distributed data.
+pub fn rolling_mad(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    scale: bool,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_mad_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            scale,
+            det_offsets_center,
+        )
+    } else {
+        rolling_mad_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            scale,
+            det_offsets,
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use arrow::buffer::Buffer;
@@ -0,0 +1,50 @@
+use super::*;
+
+/// Rank the value of every row among the values of its window.
+/// Null values are skipped, and the rank of a null value is null.
+pub fn rolling_rank<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: RollingRankParams,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let validity = arr.validity();
+    if center {
+        rolling_rank_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            params,
+            det_offsets_center,
+        )
+    } else {
+        rolling_rank_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            params,
+            det_offsets,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_rank_nulls() {
+        let arr = PrimitiveArray::from(&[Some(2.0f64), None, Some(1.0), Some(2.0), None]);
+        let out = rolling_rank(&arr, 3, 2, false, RollingRankParams::default());
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // the window of the third value is [2.0, null, 1.0]
+        assert_eq!(out, &[None, None, Some(1.0), Some(2.0), None]);
+    }
+}
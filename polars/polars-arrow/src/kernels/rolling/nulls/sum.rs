@@ -148,3 +148,78 @@ where
         )
     }
 }
+
+/// Apply a rolling sum that resets to zero whenever `reset` returns `true` for the running sum
+/// of a window. Null values are skipped.
+///
+/// This is considerably slower than [`rolling_sum`] as every window is summed from scratch.
+pub fn rolling_sum_with_reset(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    reset: &dyn Fn(f64) -> bool,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_sum_with_reset_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            reset,
+            det_offsets_center,
+        )
+    } else {
+        rolling_sum_with_reset_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            reset,
+            det_offsets,
+        )
+    }
+}
+
+/// Compute the sum of the positive values (gains) and the absolute sum of the negative values
+/// (losses) of every window. Null values are skipped. Returns the `(gains, losses)`.
+pub fn rolling_gain_loss(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> (ArrayRef, ArrayRef) {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_gain_loss_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_gain_loss_impl(values, validity, window_size, min_periods, det_offsets)
+    }
+}
+
+/// Compute the dot product of every window with `reference`. The window size is the length
+/// of `reference`, whose first value multiplies the oldest value of the window. Null values
+/// are skipped.
+pub fn rolling_dot(
+    arr: &PrimitiveArray<f64>,
+    min_periods: usize,
+    center: bool,
+    reference: &[f64],
+) -> ArrayRef {
+    rolling_dot_impl(
+        arr.values().as_slice(),
+        arr.validity(),
+        reference,
+        min_periods,
+        center,
+    )
+}
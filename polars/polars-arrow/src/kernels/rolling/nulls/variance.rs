@@ -1,9 +1,10 @@
 use mean::MeanWindow;
 use nulls;
-use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+use nulls::{rolling_apply_agg_window_with, RollingAggWindowNulls};
 use num_traits::pow::Pow;
 
 use super::*;
+use crate::utils::combine_validities_and;
 
 pub(super) struct SumSquaredWindow<'a, T> {
     slice: &'a [T],
@@ -127,6 +128,15 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T> + Mul<Outpu
 pub struct VarWindow<'a, T> {
     mean: MeanWindow<'a, T>,
     sum_of_squares: SumSquaredWindow<'a, T>,
+    ddof: u8,
+}
+
+impl<'a, T> VarWindow<'a, T> {
+    /// Set the delta degrees of freedom, 1 by default.
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.ddof = ddof;
+        self
+    }
 }
 
 impl<
@@ -147,24 +157,27 @@ impl<
         Self {
             mean: MeanWindow::new(slice, validity, start, end),
             sum_of_squares: SumSquaredWindow::new(slice, validity, start, end),
+            ddof: 1,
         }
     }
 
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
         let sum_of_squares = self.sum_of_squares.update(start, end)?;
         let null_count = self.sum_of_squares.null_count;
-        let count = NumCast::from(end - start - null_count).unwrap();
+        let valid_count = end - start - null_count;
+        let count = NumCast::from(valid_count).unwrap();
 
         let mean_of_squares = sum_of_squares / count;
         let mean = self.mean.update(start, end)?;
 
-        if count == T::one() {
+        if valid_count <= self.ddof as usize {
             NumCast::from(0)
         } else {
             let var = mean_of_squares - mean * mean;
+            let ddof: T = NumCast::from(self.ddof).unwrap();
 
             // apply Bessel's correction
-            Some(var / (count - T::one()) * count)
+            Some(var / (count - ddof) * count)
         }
     }
     fn is_valid(&self, min_periods: usize) -> bool {
@@ -172,12 +185,15 @@ impl<
     }
 }
 
+/// Compute the variance of every window with `ddof` delta degrees of freedom.
+/// Null values are skipped.
 pub fn rolling_var<T>(
     arr: &PrimitiveArray<T>,
     window_size: usize,
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
+    ddof: u8,
 ) -> ArrayRef
 where
     T: NativeType + std::iter::Sum<T> + Zero + AddAssign + SubAssign + IsFloat + Float,
@@ -186,20 +202,28 @@ where
         panic!("weights not yet supported on array with null values")
     }
     if center {
-        rolling_apply_agg_window::<VarWindow<_>, _, _>(
+        rolling_apply_agg_window_with(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
             det_offsets_center,
+            // Safety; we are in bounds
+            |slice, validity, start, end| unsafe {
+                VarWindow::new(slice, validity, start, end).with_ddof(ddof)
+            },
         )
     } else {
-        rolling_apply_agg_window::<VarWindow<_>, _, _>(
+        rolling_apply_agg_window_with(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
             det_offsets,
+            // Safety; we are in bounds
+            |slice, validity, start, end| unsafe {
+                VarWindow::new(slice, validity, start, end).with_ddof(ddof)
+            },
         )
     }
 }
@@ -208,6 +232,14 @@ pub struct StdWindow<'a, T> {
     var: VarWindow<'a, T>,
 }
 
+impl<'a, T> StdWindow<'a, T> {
+    /// Set the delta degrees of freedom, 1 by default.
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.var = self.var.with_ddof(ddof);
+        self
+    }
+}
+
 impl<
         'a,
         T: NativeType
@@ -239,12 +271,15 @@ impl<
     }
 }
 
+/// Compute the standard deviation of every window with `ddof` delta degrees of freedom.
+/// Null values are skipped.
 pub fn rolling_std<T>(
     arr: &PrimitiveArray<T>,
     window_size: usize,
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
+    ddof: u8,
 ) -> ArrayRef
 where
     T: NativeType
@@ -260,20 +295,217 @@ where
         panic!("weights not yet supported on array with null values")
     }
     if center {
-        rolling_apply_agg_window::<StdWindow<_>, _, _>(
+        rolling_apply_agg_window_with(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
             det_offsets_center,
+            // Safety; we are in bounds
+            |slice, validity, start, end| unsafe {
+                StdWindow::new(slice, validity, start, end).with_ddof(ddof)
+            },
         )
     } else {
-        rolling_apply_agg_window::<StdWindow<_>, _, _>(
+        rolling_apply_agg_window_with(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
             det_offsets,
+            // Safety; we are in bounds
+            |slice, validity, start, end| unsafe {
+                StdWindow::new(slice, validity, start, end).with_ddof(ddof)
+            },
+        )
+    }
+}
+
+/// Compute the coefficient of variation (`std / mean`) of every window with
+/// `ddof` delta degrees of freedom. Null values are skipped and windows with
+/// a mean of zero are null.
+pub fn rolling_cv(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    ddof: u8,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = match arr.validity() {
+        Some(validity) => validity,
+        None => return no_nulls::rolling_cv(values, window_size, min_periods, center, ddof),
+    };
+    let det_offsets_fn: fn(Idx, WindowSize, Len) -> (Start, End) = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let len = values.len();
+    let (start, end) = det_offsets_fn(0, window_size, len);
+    // Safety; we are in bounds
+    let mut window = unsafe { VarWindow::new(values, validity, start, end).with_ddof(ddof) };
+    rolling_cv_impl(
+        len,
+        window_size,
+        min_periods,
+        ddof,
+        det_offsets_fn,
+        |start, end| {
+            // safety: we are in bounds
+            unsafe {
+                let var = window.update(start, end)?;
+                // the window has already slid to `start..end`, so this is just a lookup
+                let mean = window.mean.update(start, end)?;
+                let count = end - start - window.sum_of_squares.null_count;
+                Some((var, mean, count))
+            }
+        },
+    )
+}
+
+/// Compute the autocorrelation at `lag` of every window, i.e. the Pearson correlation between
+/// the values at `i` and `i + lag` within the window. Pairs with a null value are skipped.
+pub fn rolling_autocorr(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    lag: usize,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_autocorr_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            lag,
+            det_offsets_center,
+        )
+    } else {
+        rolling_autocorr_impl(values, validity, window_size, min_periods, lag, det_offsets)
+    }
+}
+
+/// Compute the covariance between every window of `x` and the window of `y` at the same
+/// positions, with `ddof` delta degrees of freedom. Pairs with a null value are skipped.
+pub fn rolling_cov(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    ddof: u8,
+) -> ArrayRef {
+    rolling_cov_nulls(
+        x,
+        y,
+        window_size,
+        min_periods,
+        center,
+        CovKind::Cov { ddof },
+    )
+}
+
+/// Compute the Pearson correlation between every window of `x` and the window of `y` at the
+/// same positions. Pairs with a null value are skipped.
+pub fn rolling_corr(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef {
+    rolling_cov_nulls(x, y, window_size, min_periods, center, CovKind::Corr)
+}
+
+fn rolling_cov_nulls(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    kind: CovKind,
+) -> ArrayRef {
+    let validity = combine_validities_and(x.validity(), y.validity());
+    let (x, y) = (x.values().as_slice(), y.values().as_slice());
+    if center {
+        rolling_cov_impl(
+            x,
+            y,
+            validity.as_ref(),
+            window_size,
+            min_periods,
+            kind,
+            det_offsets_center,
+        )
+    } else {
+        rolling_cov_impl(
+            x,
+            y,
+            validity.as_ref(),
+            window_size,
+            min_periods,
+            kind,
+            det_offsets,
+        )
+    }
+}
+
+/// Compute the rescaled range (R/S) statistic of every window, i.e. the range of the cumulative
+/// deviations from the mean divided by the population standard deviation. Null values are
+/// skipped.
+pub fn rolling_rs(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_rs_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_rs_impl(values, validity, window_size, min_periods, det_offsets)
+    }
+}
+
+/// Compute the kurtosis of every window, see [`RollingKurtosisParams`] for the definitions.
+/// Null values are skipped.
+pub fn rolling_kurtosis(
+    arr: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: RollingKurtosisParams,
+) -> ArrayRef {
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+    if center {
+        rolling_kurtosis_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            params,
+            det_offsets_center,
+        )
+    } else {
+        rolling_kurtosis_impl(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            params,
+            det_offsets,
         )
     }
 }
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Count the number of times the values cross zero within every window.
+/// Null values are skipped.
+pub fn rolling_zero_crossings<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    policy: ZeroCrossingPolicy,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + Zero,
+{
+    let validity = arr.validity();
+    if center {
+        rolling_zero_crossings_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            policy,
+            det_offsets_center,
+        )
+    } else {
+        rolling_zero_crossings_impl(
+            arr.values().as_slice(),
+            validity,
+            window_size,
+            min_periods,
+            policy,
+            det_offsets,
+        )
+    }
+}
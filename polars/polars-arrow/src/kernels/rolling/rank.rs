@@ -0,0 +1,115 @@
+use arrow::datatypes::DataType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// How equal values are ranked by a rolling rank.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RollingRankMethod {
+    /// Equal values get the average of the ranks they span.
+    #[default]
+    Average,
+    /// Equal values get the lowest of the ranks they span.
+    Min,
+    /// Equal values get the highest of the ranks they span.
+    Max,
+    /// Like `Min`, but the next distinct value gets the next rank, without gaps.
+    Dense,
+}
+
+/// The parameters of a rolling rank.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingRankParams {
+    pub method: RollingRankMethod,
+    /// Give the largest value rank 1 instead of the smallest.
+    pub descending: bool,
+}
+
+/// The 1-based rank of `value` among the values of `window`, which should include `value`.
+///
+/// NaN is larger than every other value.
+pub fn window_rank<T, I>(value: T, window: I, params: RollingRankParams) -> f64
+where
+    T: NativeType + PartialOrd + IsFloat,
+    I: IntoIterator<Item = T>,
+{
+    let compare = |a: &T, b: &T| {
+        let ord = compare_fn_nan_max(a, b);
+        if params.descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    };
+    let dense = params.method == RollingRankMethod::Dense;
+
+    let mut less = 0usize;
+    let mut equal = 0usize;
+    let mut smaller = vec![];
+    for v in window {
+        match compare(&v, &value) {
+            Ordering::Less => {
+                less += 1;
+                if dense {
+                    smaller.push(v);
+                }
+            }
+            Ordering::Equal => equal += 1,
+            Ordering::Greater => {}
+        }
+    }
+    match params.method {
+        RollingRankMethod::Average => less as f64 + (equal as f64 + 1.0) / 2.0,
+        RollingRankMethod::Min => (less + 1) as f64,
+        RollingRankMethod::Max => (less + equal) as f64,
+        RollingRankMethod::Dense => {
+            smaller.sort_by(compare);
+            smaller.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+            (smaller.len() + 1) as f64
+        }
+    }
+}
+
+/// Rank the value of every row among the values of its window.
+///
+/// Null values are skipped. The output is null where the value is null or its window has
+/// fewer than `min_periods` non-null values.
+pub(super) fn rolling_rank_impl<T, Fo>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    params: RollingRankParams,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let window = (start..end).filter(|&i| is_valid(i)).map(|i| values[i]);
+            if !is_valid(idx) || window.clone().count() < min_periods {
+                out_validity.push(false);
+                0.0
+            } else {
+                out_validity.push(true);
+                window_rank(values[idx], window, params)
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
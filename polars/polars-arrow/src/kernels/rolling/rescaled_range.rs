@@ -0,0 +1,68 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Compute the rescaled range (R/S) statistic of every window: the range of the cumulative
+/// deviations from the window's mean, divided by the (population) standard deviation.
+///
+/// Null values are skipped. A window is null if it has fewer than `min_periods` non-null
+/// values, fewer than two non-null values or no variance.
+pub(super) fn rolling_rs_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let window = || (start..end).filter(|i| is_valid(*i)).map(|i| values[i]);
+
+            let mut count = 0usize;
+            let mut sum = 0.0;
+            for v in window() {
+                count += 1;
+                sum += v;
+            }
+            if count < min_periods || count < 2 {
+                out_validity.push(false);
+                return 0.0;
+            }
+            let mean = sum / count as f64;
+
+            // the cumulative deviations end at zero, so the range always includes it
+            let mut cum_dev = 0.0f64;
+            let mut min_dev = 0.0f64;
+            let mut max_dev = 0.0f64;
+            let mut ssd = 0.0;
+            for v in window() {
+                let dev = v - mean;
+                cum_dev += dev;
+                min_dev = min_dev.min(cum_dev);
+                max_dev = max_dev.max(cum_dev);
+                ssd += dev * dev;
+            }
+            let std = (ssd / count as f64).sqrt();
+            if std == 0.0 {
+                out_validity.push(false);
+                return 0.0;
+            }
+            out_validity.push(true);
+            (max_dev - min_dev) / std
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        out_validity.into(),
+    ))
+}
@@ -0,0 +1,51 @@
+use arrow::datatypes::DataType;
+
+use super::*;
+
+/// Sum every window from left to right, resetting the running sum to zero
+/// whenever `reset` returns `true` for it.
+///
+/// Null values are skipped. A window is null if it has fewer than
+/// `min_periods` non-null values.
+pub(super) fn rolling_sum_with_reset_impl<Fo>(
+    values: &[f64],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    reset: &dyn Fn(f64) -> bool,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let mut validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            let mut sum = 0.0;
+            let mut count = 0;
+            // the running sum depends on where the window starts,
+            // so every window is computed from scratch
+            for i in start..end {
+                if is_valid(i) {
+                    count += 1;
+                    sum += values[i];
+                    if reset(sum) {
+                        sum = 0.0;
+                    }
+                }
+            }
+            validity.push(count >= min_periods);
+            sum
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::Float64,
+        out.into(),
+        validity.into(),
+    ))
+}
@@ -0,0 +1,112 @@
+use arrow::datatypes::DataType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// How zeros are treated when counting sign changes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ZeroCrossingPolicy {
+    /// Zeros have no sign and are skipped, so `[1, 0, -1]` crosses zero once.
+    #[default]
+    Skip,
+    /// Zeros are treated as positive values.
+    Positive,
+    /// Zeros are treated as negative values.
+    Negative,
+}
+
+/// Count the sign changes in every window.
+///
+/// Null and NaN values are skipped. A window is null if it has fewer than
+/// `min_periods` non-null values.
+pub(super) fn rolling_zero_crossings_impl<T, Fo>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    policy: ZeroCrossingPolicy,
+    det_offsets_fn: Fo,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + Zero,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    let len = values.len();
+    let is_valid = |idx: usize| validity.map_or(true, |v| v.get_bit(idx));
+
+    let signs = values
+        .iter()
+        .enumerate()
+        .map(|(idx, v)| {
+            if !is_valid(idx) {
+                None
+            } else if *v > T::zero() {
+                Some(true)
+            } else if *v < T::zero() {
+                Some(false)
+            } else if *v == T::zero() {
+                match policy {
+                    ZeroCrossingPolicy::Skip => None,
+                    ZeroCrossingPolicy::Positive => Some(true),
+                    ZeroCrossingPolicy::Negative => Some(false),
+                }
+            } else {
+                // NaN
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // `crossings[i]` is the number of sign changes up to (excluding) `i`
+    // and `valid_count[i]` the number of non-null values up to (excluding) `i`.
+    let mut crossings = Vec::with_capacity(len + 1);
+    let mut valid_count = Vec::with_capacity(len + 1);
+    crossings.push(0u32);
+    valid_count.push(0usize);
+    let mut previous = None;
+    for (idx, sign) in signs.iter().enumerate() {
+        let crossed = match (previous, sign) {
+            (Some(previous), Some(sign)) => previous != *sign,
+            _ => false,
+        };
+        if sign.is_some() {
+            previous = *sign;
+        }
+        crossings.push(crossings[idx] + crossed as u32);
+        valid_count.push(valid_count[idx] + is_valid(idx) as usize);
+    }
+
+    // index of the first value with a sign at or after `i`
+    let mut next_signed = vec![len; len + 1];
+    for idx in (0..len).rev() {
+        next_signed[idx] = if signs[idx].is_some() {
+            idx
+        } else {
+            next_signed[idx + 1]
+        };
+    }
+
+    let mut validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            validity.push(valid_count[end] - valid_count[start] >= min_periods);
+            // the crossing registered at the first signed value of the window
+            // is relative to a value outside the window, so we skip it
+            let first = next_signed[start];
+            if first < end {
+                crossings[end] - crossings[first + 1]
+            } else {
+                0
+            }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    Box::new(PrimitiveArray::new(
+        DataType::UInt32,
+        out.into(),
+        validity.into(),
+    ))
+}
@@ -1,12 +1,15 @@
+#[cfg(feature = "timezones")]
+use arrow::array::BooleanArray;
 use arrow::array::PrimitiveArray;
-use arrow::compute::arity::try_unary;
+#[cfg(feature = "timezones")]
+use arrow::bitmap::MutableBitmap;
 use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
 use arrow::error::{Error as ArrowError, Result};
 use arrow::temporal_conversions::{
-    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, SECONDS_IN_DAY,
 };
 #[cfg(feature = "timezones")]
-use chrono::{LocalResult, NaiveDateTime, TimeZone};
+use chrono::{DateTime, LocalResult, NaiveDateTime, Offset, TimeZone};
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 use polars_error::polars_bail;
@@ -14,79 +17,182 @@ use polars_error::polars_bail;
 use crate::error::PolarsResult;
 use crate::prelude::ArrayRef;
 
+/// How to localize a datetime that is ambiguous in a time zone, e.g. because it's repeated when
+/// the clocks go back.
+#[cfg(feature = "timezones")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Ambiguous {
+    /// Take the earliest of the two instants.
+    Earliest,
+    /// Take the latest of the two instants.
+    Latest,
+    /// Raise an error.
+    #[default]
+    Raise,
+}
+
+#[cfg(feature = "timezones")]
+impl Ambiguous {
+    /// Parse one of `'earliest'`, `'latest'` or `'raise'`.
+    pub fn parse(s: &str) -> PolarsResult<Self> {
+        Ok(match s {
+            "earliest" => Ambiguous::Earliest,
+            "latest" => Ambiguous::Latest,
+            "raise" => Ambiguous::Raise,
+            _ => polars_bail!(
+                ComputeError: "invalid 'ambiguous' policy '{}', expected one of 'earliest', \
+                'latest' or 'raise'", s
+            ),
+        })
+    }
+}
+
+#[cfg(feature = "timezones")]
+impl From<Option<bool>> for Ambiguous {
+    /// Convert a `use_earliest` flag, where `None` means an ambiguous datetime is an error.
+    fn from(use_earliest: Option<bool>) -> Self {
+        match use_earliest {
+            Some(true) => Ambiguous::Earliest,
+            Some(false) => Ambiguous::Latest,
+            None => Ambiguous::Raise,
+        }
+    }
+}
+
+/// How to localize a datetime that is non-existent in a time zone, e.g. because it's skipped when
+/// the clocks go forward.
+#[cfg(feature = "timezones")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NonExistent {
+    /// Set it to null.
+    Null,
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Take the first instant after the gap, i.e. the moment the clocks went forward.
+    Forward,
+}
+
+#[cfg(feature = "timezones")]
+impl NonExistent {
+    /// Parse one of `'null'`, `'raise'` or `'forward'`.
+    pub fn parse(s: &str) -> PolarsResult<Self> {
+        Ok(match s {
+            "null" => NonExistent::Null,
+            "raise" => NonExistent::Raise,
+            "forward" => NonExistent::Forward,
+            _ => polars_bail!(
+                ComputeError: "invalid 'non_existent' policy '{}', expected one of 'null', \
+                'raise' or 'forward'", s
+            ),
+        })
+    }
+}
+
+/// The moment the clocks went forward in time zone `tz` to skip the non-existent `ndt`.
+#[cfg(feature = "timezones")]
+fn end_of_gap(tz: &Tz, ndt: NaiveDateTime) -> Option<DateTime<Tz>> {
+    let offset_at = |secs: i64| {
+        let utc = NaiveDateTime::from_timestamp_opt(secs, 0).unwrap();
+        tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64
+    };
+    let secs = ndt.timestamp();
+    let before = offset_at(secs - SECONDS_IN_DAY);
+    let after = offset_at(secs + SECONDS_IN_DAY);
+    // the clocks went forward from `before` to `after` at an instant in
+    // (ndt - after, ndt - before] (in UTC), find it
+    let (mut lo, mut hi) = (secs - after, secs - before + 1);
+    if offset_at(lo) != before || offset_at(hi) != after {
+        return None;
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if offset_at(mid) == after {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    tz.timestamp_opt(hi, 0).single()
+}
+
 #[cfg(feature = "timezones")]
 fn convert_to_naive_local(
     from_tz: &Tz,
     to_tz: &Tz,
     ndt: NaiveDateTime,
-    use_earliest: Option<bool>,
-) -> Result<NaiveDateTime> {
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+) -> Result<Option<NaiveDateTime>> {
     match from_tz.from_local_datetime(&ndt) {
-        LocalResult::Single(dt) => Ok(dt.with_timezone(to_tz).naive_local()),
-        LocalResult::Ambiguous(dt_earliest, dt_latest) => match use_earliest {
-            Some(true) => Ok(dt_earliest.with_timezone(to_tz).naive_local()),
-            Some(false) => Ok(dt_latest.with_timezone(to_tz).naive_local()),
-            None => Err(ArrowError::InvalidArgumentError(
+        LocalResult::Single(dt) => Ok(Some(dt.with_timezone(to_tz).naive_local())),
+        LocalResult::Ambiguous(dt_earliest, dt_latest) => match ambiguous {
+            Ambiguous::Earliest => Ok(Some(dt_earliest.with_timezone(to_tz).naive_local())),
+            Ambiguous::Latest => Ok(Some(dt_latest.with_timezone(to_tz).naive_local())),
+            Ambiguous::Raise => Err(ArrowError::InvalidArgumentError(
                 format!("datetime '{}' is ambiguous in time zone '{}'. Please use `use_earliest` to tell how it should be localized.", ndt, from_tz)
             ))
         },
-        LocalResult::None => Err(ArrowError::InvalidArgumentError(
-            format!(
-                "datetime '{}' is non-existent in time zone '{}'. Non-existent datetimes are not yet supported",
-                ndt, from_tz
-            )
-            ,
-        )),
+        LocalResult::None => match non_existent {
+            NonExistent::Null => Ok(None),
+            NonExistent::Forward => match end_of_gap(from_tz, ndt) {
+                Some(dt) => Ok(Some(dt.with_timezone(to_tz).naive_local())),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "could not find the end of the gap in time zone '{}' in which datetime '{}' is non-existent",
+                    from_tz, ndt
+                ))),
+            },
+            NonExistent::Raise => Err(ArrowError::InvalidArgumentError(
+                format!("datetime '{}' is non-existent in time zone '{}'. Please use `non_existent` to tell how it should be localized.", ndt, from_tz)
+            )),
+        },
+    }
+}
+
+#[cfg(feature = "timezones")]
+fn parse_time_zone(tz: &str) -> PolarsResult<Tz> {
+    match tz.parse::<Tz>() {
+        Ok(tz) => Ok(tz),
+        Err(_) => polars_bail!(ComputeError: "unable to parse time zone: '{}'", tz),
     }
 }
 
+/// Apply `convert` to the index and local datetime of the values in `arr`, in time unit `tu`. A
+/// datetime converted to `None` becomes null.
 #[cfg(feature = "timezones")]
-fn convert_to_timestamp(
-    from_tz: Tz,
-    to_tz: Tz,
+fn convert_local_datetimes<F>(
     arr: &PrimitiveArray<i64>,
     tu: TimeUnit,
-    use_earliest: Option<bool>,
-) -> PolarsResult<ArrayRef> {
-    match tu {
-        TimeUnit::Millisecond => {
-            let data = try_unary(
-                arr,
-                |value| {
-                    let ndt = timestamp_ms_to_datetime(value);
-                    Ok(convert_to_naive_local(&from_tz, &to_tz, ndt, use_earliest)?
-                        .timestamp_millis())
-                },
-                ArrowDataType::Int64,
-            )?;
-            Ok(Box::new(data))
-        }
-        TimeUnit::Microsecond => {
-            let data = try_unary(
-                arr,
-                |value| {
-                    let ndt = timestamp_us_to_datetime(value);
-                    Ok(convert_to_naive_local(&from_tz, &to_tz, ndt, use_earliest)?
-                        .timestamp_micros())
-                },
-                ArrowDataType::Int64,
-            )?;
-            Ok(Box::new(data))
-        }
-        TimeUnit::Nanosecond => {
-            let data = try_unary(
-                arr,
-                |value| {
-                    let ndt = timestamp_ns_to_datetime(value);
-                    Ok(convert_to_naive_local(&from_tz, &to_tz, ndt, use_earliest)?
-                        .timestamp_nanos())
-                },
-                ArrowDataType::Int64,
-            )?;
-            Ok(Box::new(data))
-        }
-        _ => unreachable!(),
-    }
+    convert: F,
+) -> PolarsResult<ArrayRef>
+where
+    F: Fn(usize, NaiveDateTime) -> Result<Option<NaiveDateTime>>,
+{
+    let (to_datetime, to_timestamp): (fn(i64) -> NaiveDateTime, fn(&NaiveDateTime) -> i64) =
+        match tu {
+            TimeUnit::Millisecond => (timestamp_ms_to_datetime, NaiveDateTime::timestamp_millis),
+            TimeUnit::Microsecond => (timestamp_us_to_datetime, NaiveDateTime::timestamp_micros),
+            TimeUnit::Nanosecond => (timestamp_ns_to_datetime, NaiveDateTime::timestamp_nanos),
+            _ => unreachable!(),
+        };
+    let mut validity = MutableBitmap::with_capacity(arr.len());
+    let values = arr
+        .iter()
+        .enumerate()
+        .map(|(i, opt_value)| {
+            let ndt = match opt_value {
+                Some(value) => convert(i, to_datetime(*value))?,
+                None => None,
+            };
+            validity.push(ndt.is_some());
+            Ok(ndt.map(|ndt| to_timestamp(&ndt)).unwrap_or_default())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(PrimitiveArray::new(
+        ArrowDataType::Int64,
+        values.into(),
+        validity.into(),
+    )))
 }
 
 #[cfg(feature = "timezones")]
@@ -95,13 +201,215 @@ pub fn replace_timezone(
     tu: TimeUnit,
     from: &str,
     to: &str,
-    use_earliest: Option<bool>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<ArrayRef> {
-    Ok(match from.parse::<chrono_tz::Tz>() {
-        Ok(from_tz) => match to.parse::<chrono_tz::Tz>() {
-            Ok(to_tz) => convert_to_timestamp(from_tz, to_tz, arr, tu, use_earliest)?,
-            Err(_) => polars_bail!(ComputeError: "unable to parse time zone: '{}'", to),
-        },
-        Err(_) => polars_bail!(ComputeError: "unable to parse time zone: '{}'", from),
+    let from_tz = parse_time_zone(from)?;
+    let to_tz = parse_time_zone(to)?;
+    convert_local_datetimes(arr, tu, |_, ndt| {
+        convert_to_naive_local(&from_tz, &to_tz, ndt, ambiguous, non_existent)
     })
 }
+
+/// Like [`replace_timezone`], with the policies for ambiguous and non-existent datetimes given
+/// per value of `arr`.
+#[cfg(feature = "timezones")]
+pub fn replace_timezone_by_row(
+    arr: &PrimitiveArray<i64>,
+    tu: TimeUnit,
+    from: &str,
+    to: &str,
+    policies: &[(Ambiguous, NonExistent)],
+) -> PolarsResult<ArrayRef> {
+    assert_eq!(arr.len(), policies.len());
+    let from_tz = parse_time_zone(from)?;
+    let to_tz = parse_time_zone(to)?;
+    convert_local_datetimes(arr, tu, |i, ndt| {
+        let (ambiguous, non_existent) = policies[i];
+        convert_to_naive_local(&from_tz, &to_tz, ndt, ambiguous, non_existent)
+    })
+}
+
+/// Flag the local datetimes in `arr` that are ambiguous (e.g. repeated when the clocks go back)
+/// or non-existent (e.g. skipped when the clocks go forward) in time zone `tz`.
+///
+/// These are the datetimes that [`replace_timezone`] can't localize with [`Ambiguous::Raise`],
+/// respectively with [`NonExistent::Raise`]. Returns the `(ambiguous, non_existent)` flags.
+#[cfg(feature = "timezones")]
+pub fn dst_transition_flags(
+    arr: &PrimitiveArray<i64>,
+    tu: TimeUnit,
+    tz: &str,
+) -> PolarsResult<(BooleanArray, BooleanArray)> {
+    let tz = parse_time_zone(tz)?;
+    let to_datetime = match tu {
+        TimeUnit::Millisecond => timestamp_ms_to_datetime,
+        TimeUnit::Microsecond => timestamp_us_to_datetime,
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime,
+        _ => unreachable!(),
+    };
+
+    let mut ambiguous = MutableBitmap::with_capacity(arr.len());
+    let mut non_existent = MutableBitmap::with_capacity(arr.len());
+    for value in arr.values().iter() {
+        let (is_ambiguous, is_non_existent) = match tz.from_local_datetime(&to_datetime(*value)) {
+            LocalResult::Single(_) => (false, false),
+            LocalResult::Ambiguous(_, _) => (true, false),
+            LocalResult::None => (false, true),
+        };
+        ambiguous.push(is_ambiguous);
+        non_existent.push(is_non_existent);
+    }
+    let validity = arr.validity().cloned();
+    Ok((
+        BooleanArray::new(ArrowDataType::Boolean, ambiguous.into(), validity.clone()),
+        BooleanArray::new(ArrowDataType::Boolean, non_existent.into(), validity),
+    ))
+}
+
+#[cfg(test)]
+#[cfg(feature = "timezones")]
+mod test {
+    use arrow::array::Array;
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn local_ms(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    #[test]
+    fn test_replace_timezone_invalid_time_zone() {
+        let arr = PrimitiveArray::from_slice([local_ms(2021, 1, 1, 0, 0)]);
+        for (from, to) in [("Foo/Bar", "UTC"), ("UTC", "Foo/Bar")] {
+            let err = replace_timezone(
+                &arr,
+                TimeUnit::Millisecond,
+                from,
+                to,
+                Ambiguous::Raise,
+                NonExistent::Raise,
+            )
+            .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("unable to parse time zone: 'Foo/Bar'"));
+        }
+    }
+
+    #[test]
+    fn test_replace_timezone_dst_transitions() {
+        // the clocks went forward from 01:00 to 02:00 on 2021-03-28 in London
+        let arr = PrimitiveArray::from_slice([local_ms(2021, 3, 28, 1, 30)]);
+        let err = replace_timezone(
+            &arr,
+            TimeUnit::Millisecond,
+            "Europe/London",
+            "UTC",
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("non-existent"));
+
+        // and back from 02:00 to 01:00 on 2021-10-31
+        let arr = PrimitiveArray::from_slice([local_ms(2021, 10, 31, 1, 30)]);
+        let err = replace_timezone(
+            &arr,
+            TimeUnit::Millisecond,
+            "Europe/London",
+            "UTC",
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+        let earliest = local_ms(2021, 10, 31, 0, 30);
+        let latest = local_ms(2021, 10, 31, 1, 30);
+        for (ambiguous, expected) in [(Ambiguous::Earliest, earliest), (Ambiguous::Latest, latest)]
+        {
+            let out = replace_timezone(
+                &arr,
+                TimeUnit::Millisecond,
+                "Europe/London",
+                "UTC",
+                ambiguous,
+                NonExistent::Raise,
+            )
+            .unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+            assert_eq!(out.value(0), expected);
+        }
+    }
+
+    #[test]
+    fn test_replace_timezone_time_units_and_zones() {
+        // the `Etc/GMT` zones have a fixed offset, with the sign inverted
+        let cases = [
+            ("Asia/Kathmandu", "America/New_York", 5 * 60 + 45 + 5 * 60),
+            ("Asia/Kathmandu", "Etc/GMT+3", 5 * 60 + 45 + 3 * 60),
+            ("Etc/GMT-2", "Asia/Kathmandu", 2 * 60 - (5 * 60 + 45)),
+            ("Etc/GMT-2", "Etc/GMT+3", 2 * 60 + 3 * 60),
+        ];
+        let local = local_ms(2021, 1, 15, 12, 0);
+        for (tu, multiplier) in [
+            (TimeUnit::Millisecond, 1),
+            (TimeUnit::Microsecond, 1_000),
+            (TimeUnit::Nanosecond, 1_000_000),
+        ] {
+            let arr = PrimitiveArray::from_slice([local * multiplier]);
+            for (from, to, offset_minutes) in cases {
+                let out =
+                    replace_timezone(&arr, tu, from, to, Ambiguous::Raise, NonExistent::Raise)
+                        .unwrap();
+                let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+                let expected = (local - offset_minutes * 60_000) * multiplier;
+                assert_eq!(out.value(0), expected, "{from} -> {to} in {tu:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_replace_timezone_non_existent() {
+        // the clocks went forward from 01:00 to 02:00 on 2021-03-28 in London
+        let arr = PrimitiveArray::from_slice([
+            local_ms(2021, 3, 28, 1, 30),
+            local_ms(2021, 3, 28, 2, 30),
+        ]);
+        let convert = |non_existent| {
+            replace_timezone(
+                &arr,
+                TimeUnit::Millisecond,
+                "Europe/London",
+                "UTC",
+                Ambiguous::Raise,
+                non_existent,
+            )
+        };
+        assert!(convert(NonExistent::Raise)
+            .unwrap_err()
+            .to_string()
+            .contains("non-existent"));
+
+        let out = convert(NonExistent::Null).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        assert_eq!(
+            out.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            [None, Some(local_ms(2021, 3, 28, 1, 30))]
+        );
+
+        // 02:00 BST is 01:00 UTC
+        let out = convert(NonExistent::Forward).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        assert_eq!(out.null_count(), 0);
+        assert_eq!(
+            out.values().as_slice(),
+            [local_ms(2021, 3, 28, 1, 0), local_ms(2021, 3, 28, 1, 30)]
+        );
+    }
+}
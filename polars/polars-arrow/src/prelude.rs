@@ -6,6 +6,9 @@ pub use crate::bitmap::mutable::MutableBitmapExtension;
 pub use crate::data_types::*;
 pub use crate::index::*;
 pub use crate::kernels::rolling::no_nulls::QuantileInterpolOptions;
+pub use crate::kernels::rolling::{
+    RollingKurtosisParams, RollingRankMethod, RollingRankParams, ZeroCrossingPolicy,
+};
 
 pub type LargeStringArray = Utf8Array<i64>;
 pub type LargeBinaryArray = BinaryArray<i64>;
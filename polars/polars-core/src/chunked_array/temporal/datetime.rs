@@ -9,13 +9,32 @@ use chrono::TimeZone as TimeZoneTrait;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 #[cfg(feature = "timezones")]
-use polars_arrow::kernels::replace_timezone;
+use polars_arrow::kernels::{
+    dst_transition_flags, replace_timezone, replace_timezone_by_row, Ambiguous,
+};
 
 use super::conversion::{datetime_to_timestamp_ms, datetime_to_timestamp_ns};
 use super::*;
 use crate::prelude::DataType::Datetime;
 use crate::prelude::*;
 
+/// Parse a column of policies with `parse`, repeating a single policy `len` times.
+#[cfg(feature = "timezones")]
+fn broadcast_policies<T: Copy + Default>(
+    policies: &Utf8Chunked,
+    len: usize,
+    parse: fn(&str) -> PolarsResult<T>,
+) -> PolarsResult<Vec<T>> {
+    let policies = policies
+        .into_iter()
+        .map(|opt_policy| opt_policy.map_or(Ok(T::default()), parse))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(match policies.as_slice() {
+        [policy] => vec![*policy; len],
+        _ => policies,
+    })
+}
+
 #[cfg(feature = "timezones")]
 fn validate_time_zone(tz: TimeZone) -> PolarsResult<()> {
     match tz.parse::<Tz>() {
@@ -100,29 +119,138 @@ impl DatetimeChunked {
         }
     }
 
+    /// Replace the time zone, keeping the wall-clock time.
+    ///
+    /// Wall-clock times that are repeated in `time_zone` are localized according to
+    /// `use_earliest`, and those that are skipped according to `non_existent`.
     #[cfg(feature = "timezones")]
     pub fn replace_time_zone(
         &self,
         time_zone: Option<&str>,
         use_earliest: Option<bool>,
+        non_existent: NonExistent,
     ) -> PolarsResult<DatetimeChunked> {
-        let out: PolarsResult<_> = {
-            let from = self.time_zone().as_deref().unwrap_or("UTC");
-            let to = time_zone.unwrap_or("UTC");
-            let chunks = self
-                .downcast_iter()
-                .map(|arr| {
-                    replace_timezone(arr, self.time_unit().to_arrow(), to, from, use_earliest)
-                })
-                .collect::<PolarsResult<_>>()?;
-            let out = unsafe { ChunkedArray::from_chunks(self.name(), chunks) };
-            Ok(out.into_datetime(self.time_unit(), time_zone.map(|x| x.to_string())))
-        };
-        let mut out = out?;
+        let ambiguous = Ambiguous::from(use_earliest);
+        self.replace_time_zone_with(time_zone, |arr, tu, from, to, _| {
+            replace_timezone(arr, tu, from, to, ambiguous, non_existent)
+        })
+    }
+
+    /// Replace the time zone, keeping the wall-clock time, with a policy per row for wall-clock
+    /// times that are repeated (`'earliest'`, `'latest'` or `'raise'`) or skipped (`'null'`,
+    /// `'raise'` or `'forward'`) in `time_zone`.
+    ///
+    /// A policy column of length 1 applies to every row, and a null policy raises.
+    #[cfg(feature = "timezones")]
+    pub fn replace_time_zone_by_row(
+        &self,
+        time_zone: Option<&str>,
+        ambiguous: &Utf8Chunked,
+        non_existent: &Utf8Chunked,
+    ) -> PolarsResult<DatetimeChunked> {
+        let len = self.len();
+        for (name, policies) in [("ambiguous", ambiguous), ("non_existent", non_existent)] {
+            polars_ensure!(
+                policies.len() == len || policies.len() == 1,
+                ShapeMismatch: "'{}' has length {}, expected 1 or the length of the datetimes ({})",
+                name, policies.len(), len
+            );
+        }
+        let ambiguous = broadcast_policies(ambiguous, len, Ambiguous::parse)?;
+        let non_existent = broadcast_policies(non_existent, len, NonExistent::parse)?;
+        let policies = ambiguous.into_iter().zip(non_existent).collect::<Vec<_>>();
+        self.replace_time_zone_with(time_zone, |arr, tu, from, to, offset| {
+            replace_timezone_by_row(arr, tu, from, to, &policies[offset..offset + arr.len()])
+        })
+    }
+
+    /// Replace the time zone with `replace`, which converts the wall-clock times of a chunk (at
+    /// the given offset) from its first time zone to its second.
+    #[cfg(feature = "timezones")]
+    fn replace_time_zone_with<F>(
+        &self,
+        time_zone: Option<&str>,
+        replace: F,
+    ) -> PolarsResult<DatetimeChunked>
+    where
+        F: Fn(&PrimitiveArray<i64>, ArrowTimeUnit, &str, &str, usize) -> PolarsResult<ArrayRef>,
+    {
+        let from = self.time_zone().as_deref().unwrap_or("UTC");
+        let to = time_zone.unwrap_or("UTC");
+        let tu = self.time_unit().to_arrow();
+        let mut offset = 0;
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| {
+                let out = if from == "UTC" || to == "UTC" {
+                    replace(arr, tu, to, from, offset)
+                } else {
+                    // go through the wall-clock time, so that the offset of either time zone
+                    // is determined at the right moment around DST transitions
+                    let local = replace_timezone(
+                        arr,
+                        tu,
+                        "UTC",
+                        from,
+                        Ambiguous::Raise,
+                        NonExistent::Raise,
+                    )?;
+                    let local = local
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<i64>>()
+                        .unwrap();
+                    replace(local, tu, to, "UTC", offset)
+                };
+                offset += arr.len();
+                out
+            })
+            .collect::<PolarsResult<_>>()?;
+        let out = unsafe { ChunkedArray::from_chunks(self.name(), chunks) };
+        let mut out = out.into_datetime(self.time_unit(), time_zone.map(|x| x.to_string()));
         out.set_sorted_flag(self.is_sorted_flag());
         Ok(out)
     }
 
+    /// Flag the values whose wall-clock time is ambiguous or non-existent in `time_zone`
+    /// (defaults to the time zone of `self`) because of a daylight saving time transition.
+    ///
+    /// Returns the `(ambiguous, non_existent)` flags. Flagged values can't be localized by
+    /// [`DatetimeChunked::replace_time_zone`] without `use_earliest`, respectively with
+    /// [`NonExistent::Raise`].
+    #[cfg(feature = "timezones")]
+    pub fn dst_transition_flags(
+        &self,
+        time_zone: Option<&str>,
+    ) -> PolarsResult<(BooleanChunked, BooleanChunked)> {
+        let Some(tz) = time_zone.or(self.time_zone().as_deref()) else {
+            polars_bail!(ComputeError: "cannot determine DST transitions without a time zone")
+        };
+        // the flags are about the wall-clock time, so drop the time zone of tz-aware values
+        let local = match self.time_zone() {
+            Some(_) => self.replace_time_zone(None, None, NonExistent::Raise)?,
+            None => self.clone(),
+        };
+        let (ambiguous, non_existent): (Vec<_>, Vec<_>) = local
+            .downcast_iter()
+            .map(|arr| {
+                let (ambiguous, non_existent) =
+                    dst_transition_flags(arr, self.time_unit().to_arrow(), tz)?;
+                Ok((
+                    Box::new(ambiguous) as ArrayRef,
+                    Box::new(non_existent) as ArrayRef,
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        unsafe {
+            Ok((
+                BooleanChunked::from_chunks("ambiguous", ambiguous),
+                BooleanChunked::from_chunks("non_existent", non_existent),
+            ))
+        }
+    }
+
     /// Convert from Datetime into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> PolarsResult<Utf8Chunked> {
@@ -157,15 +285,13 @@ impl DatetimeChunked {
 
         let mut ca: Utf8Chunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
-            Some(time_zone) => self.apply_kernel_cast(&|arr| {
-                format_tz(
-                    time_zone.parse::<Tz>().unwrap(),
-                    arr,
-                    format,
-                    &fmted,
-                    conversion_f,
-                )
-            }),
+            Some(time_zone) => {
+                let tz = time_zone.parse::<Tz>().map_err(
+                    |_| polars_err!(ComputeError: "unable to parse time zone: '{}'", time_zone),
+                )?;
+                // formats the local wall-clock time of `tz`, so `%z` and `%Z` render its offset
+                self.apply_kernel_cast(&|arr| format_tz(tz, arr, format, &fmted, conversion_f))
+            }
             _ => self.apply_kernel_cast(&|arr| format_naive(arr, format, &fmted, conversion_f)),
         };
         ca.rename(self.name());
@@ -311,4 +437,178 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn to_string_tz_aware() {
+        // 12:34:56.123456 EDT
+        let utc =
+            NaiveDateTime::parse_from_str("2021-06-01 16:34:56.123456", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap();
+        let mut dt = DatetimeChunked::from_naive_datetime("name", [utc], TimeUnit::Microseconds);
+        dt.set_time_zone("America/New_York".to_string()).unwrap();
+
+        let out = dt.to_string("%Y-%m-%d %H:%M:%S%.6f %z").unwrap();
+        assert_eq!(out.get(0), Some("2021-06-01 12:34:56.123456 -0400"));
+
+        let out = dt.to_string("%Y-%m-%d %H:%M:%S%.3f %Z").unwrap();
+        assert_eq!(out.get(0), Some("2021-06-01 12:34:56.123 EDT"));
+    }
+
+    /// Deterministic pseudo-random numbers in `-range..range`, so that failures are reproducible.
+    #[cfg(feature = "timezones")]
+    fn pseudo_random(n: usize, range: i64) -> impl Iterator<Item = i64> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..n).map(move |_| {
+            // xorshift
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % (2 * range as u64)) as i64 - range
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn time_zone_round_trip_around_dst() {
+        use arrow::temporal_conversions::timestamp_ms_to_datetime;
+        use chrono::{LocalResult, TimeZone};
+        use chrono_tz::Tz;
+
+        // UTC instants of DST transitions in America/New_York and Europe/London (and Paris)
+        let transitions = [
+            "2022-03-13 07:00:00",
+            "2022-03-27 01:00:00",
+            "2022-10-30 01:00:00",
+            "2022-11-06 06:00:00",
+        ];
+        let timestamps = transitions
+            .iter()
+            .flat_map(|t| {
+                let t = NaiveDateTime::parse_from_str(t, "%Y-%m-%d %H:%M:%S")
+                    .unwrap()
+                    .timestamp_millis();
+                pseudo_random(100, 6 * 3_600_000).map(move |offset| t + offset)
+            })
+            .collect::<Vec<_>>();
+        let time_zones = ["UTC", "America/New_York", "Europe/London", "Europe/Paris"];
+
+        for from in time_zones {
+            let mut ca =
+                Int64Chunked::new("", &timestamps).into_datetime(TimeUnit::Milliseconds, None);
+            ca.set_time_zone(from.to_string()).unwrap();
+            let from_tz = from.parse::<Tz>().unwrap();
+
+            for to in time_zones {
+                let to_tz = to.parse::<Tz>().unwrap();
+
+                // converting keeps the instant
+                let converted = ca.clone().convert_time_zone(to.to_string()).unwrap();
+                assert_eq!(converted.cont_slice().unwrap(), timestamps.as_slice());
+
+                for &t in &timestamps {
+                    let ca = Int64Chunked::new("", &[t])
+                        .into_datetime(TimeUnit::Milliseconds, Some(from.to_string()));
+                    let wall = from_tz
+                        .from_utc_datetime(&timestamp_ms_to_datetime(t))
+                        .naive_local();
+                    // replacing keeps the wall-clock time, if it exists once in `to`
+                    let LocalResult::Single(expected) = to_tz.from_local_datetime(&wall) else {
+                        continue;
+                    };
+                    let replaced = ca
+                        .replace_time_zone(Some(to), None, NonExistent::Raise)
+                        .unwrap();
+                    assert_eq!(
+                        replaced.get(0),
+                        Some(expected.timestamp_millis()),
+                        "replacing {from} by {to} at {wall}"
+                    );
+                    // and the inverse recovers the original, if it isn't ambiguous in `from`
+                    if let LocalResult::Single(_) = from_tz.from_local_datetime(&wall) {
+                        let back = replaced
+                            .replace_time_zone(Some(from), None, NonExistent::Raise)
+                            .unwrap();
+                        assert_eq!(back.get(0), Some(t), "replacing {to} by {from} at {wall}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn replace_time_zone_across_dst_transition() {
+        // 2022-03-26 23:00 in New York (EDT), when London is still on GMT. Offsetting it as
+        // the wall-clock time 03:00 in London would wrongly pick BST.
+        let utc = NaiveDateTime::parse_from_str("2022-03-27 03:00:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .timestamp_millis();
+        let ca = Int64Chunked::new("", &[utc])
+            .into_datetime(TimeUnit::Milliseconds, Some("America/New_York".to_string()));
+        let out = ca
+            .replace_time_zone(Some("Europe/London"), None, NonExistent::Raise)
+            .unwrap();
+        let expected = NaiveDateTime::parse_from_str("2022-03-26 23:00:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(out.get(0), Some(expected));
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn replace_time_zone_by_row() {
+        let ms = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M")
+                .unwrap()
+                .timestamp_millis()
+        };
+        // the clocks went forward from 01:00 to 02:00 on 2021-03-28 in London, and back from
+        // 02:00 to 01:00 on 2021-10-31
+        let mut ca = Int64Chunked::new("", &[ms("2021-03-28 01:30"), ms("2021-03-28 01:30")]);
+        ca.append(&Int64Chunked::new(
+            "",
+            &[
+                ms("2021-03-28 12:00"),
+                ms("2021-10-31 01:30"),
+                ms("2021-10-31 01:30"),
+            ],
+        ));
+        let ca = ca.into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(ca.chunks().len(), 2);
+
+        let ambiguous = Utf8Chunked::new("", &["raise", "raise", "raise", "earliest", "latest"]);
+        let non_existent = Utf8Chunked::new("", &["null", "forward", "raise", "raise", "raise"]);
+        let out = ca
+            .replace_time_zone_by_row(Some("Europe/London"), &ambiguous, &non_existent)
+            .unwrap();
+        let expected = [
+            None,
+            Some(ms("2021-03-28 01:00")),
+            Some(ms("2021-03-28 11:00")),
+            Some(ms("2021-10-31 00:30")),
+            Some(ms("2021-10-31 01:30")),
+        ];
+        assert_eq!(out.into_iter().collect::<Vec<_>>(), expected);
+
+        // a single policy applies to every row
+        let forward = Utf8Chunked::new("", &["forward"]);
+        let earliest = Utf8Chunked::new("", &["earliest"]);
+        let out = ca
+            .replace_time_zone_by_row(Some("Europe/London"), &earliest, &forward)
+            .unwrap();
+        assert_eq!(out.get(0), Some(ms("2021-03-28 01:00")));
+        assert_eq!(out.get(4), Some(ms("2021-10-31 00:30")));
+
+        // a row in the gap with a 'raise' policy raises
+        let raise = Utf8Chunked::new("", &["raise"]);
+        assert!(ca
+            .replace_time_zone_by_row(Some("Europe/London"), &earliest, &raise)
+            .is_err());
+        // the policies must have length 1 or that of the datetimes
+        let too_short = Utf8Chunked::new("", &["null", "forward"]);
+        assert!(ca
+            .replace_time_zone_by_row(Some("Europe/London"), &earliest, &too_short)
+            .is_err());
+    }
 }
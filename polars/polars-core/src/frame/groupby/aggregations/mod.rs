@@ -303,6 +303,7 @@ where
                         validity,
                         quantile,
                         interpol,
+                        1,
                         offset_iter,
                     ),
                 };
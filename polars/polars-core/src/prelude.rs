@@ -6,6 +6,8 @@ pub use arrow::datatypes::{Field as ArrowField, Schema as ArrowSchema};
 pub(crate) use polars_arrow::export::*;
 #[cfg(feature = "ewma")]
 pub use polars_arrow::kernels::ewm::EWMOptions;
+#[cfg(feature = "timezones")]
+pub use polars_arrow::kernels::NonExistent;
 pub use polars_arrow::prelude::*;
 pub(crate) use polars_arrow::trusted_len::TrustedLen;
 
@@ -22,4 +22,25 @@ impl BinaryNameSpace {
         let sub = sub.as_ref().into();
         self.0.map_private(BinaryFunction::StartsWith(sub).into())
     }
+
+    /// Remove the byte sequence `prefix` from the start of every binary value that starts with it.
+    pub fn strip_prefix<S: AsRef<[u8]>>(self, prefix: S) -> Expr {
+        let prefix = prefix.as_ref().into();
+        self.0
+            .map_private(BinaryFunction::StripPrefix(prefix).into())
+    }
+
+    /// Remove the byte sequence `suffix` from the end of every binary value that ends with it.
+    pub fn strip_suffix<S: AsRef<[u8]>>(self, suffix: S) -> Expr {
+        let suffix = suffix.as_ref().into();
+        self.0
+            .map_private(BinaryFunction::StripSuffix(suffix).into())
+    }
+
+    /// Remove the leading and trailing bytes that are in `bytes` from every binary value,
+    /// or ASCII whitespace if `bytes` is `None`.
+    pub fn strip_chars(self, bytes: Option<&[u8]>) -> Expr {
+        let bytes = bytes.map(|bytes| bytes.to_vec());
+        self.0.map_private(BinaryFunction::StripChars(bytes).into())
+    }
 }
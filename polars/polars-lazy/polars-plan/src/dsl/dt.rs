@@ -129,6 +129,12 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Month))
     }
 
+    /// Get the English name of the month of a Date/Datetime, e.g. "January".
+    pub fn month_name(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MonthName))
+    }
+
     /// Extract quarter from underlying NaiveDateTime representation.
     /// Quarters range from 1 to 4.
     pub fn quarter(self) -> Expr {
@@ -155,6 +161,12 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::WeekDay))
     }
 
+    /// Get the English name of the weekday of a Date/Datetime, e.g. "Monday".
+    pub fn day_name(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::DayName))
+    }
+
     /// Get the month of a Date/Datetime
     pub fn day(self) -> Expr {
         self.0
@@ -226,6 +238,17 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TimeStamp(tu)))
     }
 
+    /// The number of time units since the Unix epoch of a Date or Datetime, as an Int64.
+    ///
+    /// `unit` is one of "s", "ms", "us", "ns" or "d". A value that isn't a whole number of
+    /// `unit`s is rounded down.
+    pub fn epoch<S: AsRef<str>>(self, unit: S) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Epoch(
+                unit.as_ref().to_string(),
+            )))
+    }
+
     pub fn truncate<S: AsRef<str>>(self, every: S, offset: S) -> Expr {
         let every = every.as_ref().into();
         let offset = offset.as_ref().into();
@@ -235,6 +258,38 @@ impl DateLikeNameSpace {
             )))
     }
 
+    /// Truncate a Date/Datetime by the per-row duration strings in `every` and `offset`.
+    ///
+    /// Either can also be a single duration string, which is broadcast. Rows where either
+    /// is null are null.
+    pub fn truncate_by(self, every: Expr, offset: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::TruncateBy),
+            &[every, offset],
+            false,
+        )
+    }
+
+    /// Floor a Datetime to midnight, keeping its time zone.
+    ///
+    /// In a time zone where midnight doesn't exist on some day, the first instant of that
+    /// day is used, and the earliest one if midnight is ambiguous.
+    pub fn normalize(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Normalize))
+    }
+
+    /// The wall-clock time of day of a Datetime or Time, as a Duration since midnight.
+    ///
+    /// This is the inverse of [`combine`](Self::combine). A tz-aware Datetime uses its
+    /// wall-clock time, even on days when clocks are changed.
+    #[cfg(feature = "dtype-duration")]
+    pub fn time_since_midnight(self) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::TimeSinceMidnight,
+        ))
+    }
+
     // roll backward to the first day of the month
     #[cfg(feature = "date_offset")]
     pub fn month_start(self) -> Expr {
@@ -260,11 +315,25 @@ impl DateLikeNameSpace {
 
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
+    ///
+    /// A negative offset, e.g. `-1mo`, is subtracted. The values stay sorted, unless a
+    /// calendar offset (days, weeks or months) is added in a time zone with DST transitions.
+    /// A `Date` can only be offset by whole days, weeks and months.
     #[cfg(feature = "date_offset")]
     pub fn offset_by(self, by: Duration) -> Expr {
         self.0.map_private(FunctionExpr::DateOffset(by))
     }
 
+    /// Offset this `Date/Datetime` by the per-row duration strings in `by`.
+    ///
+    /// `by` can also be a single duration string, which is broadcast. Rows where it is
+    /// null are null.
+    #[cfg(feature = "date_offset")]
+    pub fn offset_by_expr(self, by: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::DateOffsetBy, &[by], false)
+    }
+
     #[cfg(feature = "timezones")]
     pub fn replace_time_zone(
         self,
@@ -278,9 +347,25 @@ impl DateLikeNameSpace {
             )))
     }
 
-    pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
+    /// Flag the values whose wall-clock time is ambiguous or non-existent in `time_zone`
+    /// (defaults to the time zone of the column) because of a daylight saving time transition.
+    ///
+    /// Returns a struct with the boolean fields `ambiguous` and `non_existent`. This can be used
+    /// to clean the data before calling `replace_time_zone`.
+    #[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+    pub fn dst_transition_flags(self, time_zone: Option<TimeZone>) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::DstTransitionFlags(time_zone),
+        ))
+    }
+
+    /// Combine the date of a Date or Datetime with a Time, into a Datetime in `tu`.
+    ///
+    /// A tz-aware Datetime keeps its time zone, in which a combined wall-clock time that is
+    /// ambiguous is localized according to `use_earliest` (and raises if it's `None`).
+    pub fn combine(self, time: Expr, tu: TimeUnit, use_earliest: Option<bool>) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
+            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu, use_earliest)),
             &[time],
             false,
         )
@@ -9,6 +9,9 @@ pub enum BinaryFunction {
     Contains { pat: Vec<u8>, literal: bool },
     StartsWith(Vec<u8>),
     EndsWith(Vec<u8>),
+    StripPrefix(Vec<u8>),
+    StripSuffix(Vec<u8>),
+    StripChars(Option<Vec<u8>>),
 }
 
 impl Display for BinaryFunction {
@@ -18,6 +21,9 @@ impl Display for BinaryFunction {
             Contains { .. } => "contains",
             StartsWith(_) => "starts_with",
             EndsWith(_) => "ends_with",
+            StripPrefix(_) => "strip_prefix",
+            StripSuffix(_) => "strip_suffix",
+            StripChars(_) => "strip_chars",
         };
         write!(f, "bin.{s}")
     }
@@ -41,6 +47,21 @@ pub(super) fn starts_with(s: &Series, sub: &[u8]) -> PolarsResult<Series> {
     Ok(ca.starts_with(sub).into_series())
 }
 
+pub(super) fn strip_prefix(s: &Series, prefix: &[u8]) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.strip_prefix(prefix).into_series())
+}
+
+pub(super) fn strip_suffix(s: &Series, suffix: &[u8]) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.strip_suffix(suffix).into_series())
+}
+
+pub(super) fn strip_chars(s: &Series, bytes: Option<&[u8]>) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.strip_chars(bytes).into_series())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
@@ -13,8 +13,10 @@ pub enum TemporalFunction {
     IsoYear,
     Quarter,
     Month,
+    MonthName,
     Week,
     WeekDay,
+    DayName,
     Day,
     OrdinalDay,
     Time,
@@ -27,7 +29,12 @@ pub enum TemporalFunction {
     Microsecond,
     Nanosecond,
     TimeStamp(TimeUnit),
+    Epoch(String),
     Truncate(String, String),
+    TruncateBy,
+    Normalize,
+    #[cfg(feature = "dtype-duration")]
+    TimeSinceMidnight,
     #[cfg(feature = "date_offset")]
     MonthStart,
     #[cfg(feature = "date_offset")]
@@ -37,16 +44,23 @@ pub enum TemporalFunction {
     CastTimezone(Option<TimeZone>, Option<bool>),
     #[cfg(feature = "timezones")]
     TzLocalize(TimeZone),
+    #[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+    DstTransitionFlags(Option<TimeZone>),
     DateRange {
         every: Duration,
         closed: ClosedWindow,
         tz: Option<TimeZone>,
+        strict: bool,
     },
     TimeRange {
         every: Duration,
         closed: ClosedWindow,
     },
-    Combine(TimeUnit),
+    BusinessDayCount {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    Combine(TimeUnit, Option<bool>),
 }
 
 impl Display for TemporalFunction {
@@ -58,8 +72,10 @@ impl Display for TemporalFunction {
             IsoYear => "iso_year",
             Quarter => "quarter",
             Month => "month",
+            MonthName => "month_name",
             Week => "week",
             WeekDay => "weekday",
+            DayName => "day_name",
             Day => "day",
             OrdinalDay => "ordinal_day",
             Time => "time",
@@ -72,7 +88,12 @@ impl Display for TemporalFunction {
             Microsecond => "microsecond",
             Nanosecond => "nanosecond",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
+            Epoch(unit) => return write!(f, "dt.epoch({unit})"),
             Truncate(..) => "truncate",
+            TruncateBy => "truncate_by",
+            Normalize => "normalize",
+            #[cfg(feature = "dtype-duration")]
+            TimeSinceMidnight => "time_since_midnight",
             #[cfg(feature = "date_offset")]
             MonthStart => "month_start",
             #[cfg(feature = "date_offset")]
@@ -82,9 +103,12 @@ impl Display for TemporalFunction {
             CastTimezone(_, _) => "replace_timezone",
             #[cfg(feature = "timezones")]
             TzLocalize(_) => "tz_localize",
+            #[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+            DstTransitionFlags(_) => "dst_transition_flags",
             DateRange { .. } => return write!(f, "date_range"),
             TimeRange { .. } => return write!(f, "time_range"),
-            Combine(_) => "combine",
+            BusinessDayCount { .. } => return write!(f, "business_day_count"),
+            Combine(_, _) => "combine",
         };
         write!(f, "dt.{s}")
     }
@@ -105,12 +129,56 @@ pub(super) fn month(s: &Series) -> PolarsResult<Series> {
 pub(super) fn quarter(s: &Series) -> PolarsResult<Series> {
     s.quarter().map(|ca| ca.into_series())
 }
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Map the 1-based numbers in `ca` onto `names`.
+///
+/// Only English names are supported for now; a `locale` would select a different `names` table.
+fn number_to_name(ca: &UInt32Chunked, names: &[&'static str]) -> Series {
+    let mut out: Utf8Chunked = ca
+        .into_iter()
+        .map(|opt_v| opt_v.map(|v| names[(v - 1) as usize]))
+        .collect();
+    out.rename(ca.name());
+    out.into_series()
+}
+
+pub(super) fn month_name(s: &Series) -> PolarsResult<Series> {
+    // `month` already respects the time zone, so this is the wall-clock month.
+    s.month().map(|ca| number_to_name(&ca, &MONTH_NAMES))
+}
 pub(super) fn week(s: &Series) -> PolarsResult<Series> {
     s.week().map(|ca| ca.into_series())
 }
 pub(super) fn weekday(s: &Series) -> PolarsResult<Series> {
     s.weekday().map(|ca| ca.into_series())
 }
+pub(super) fn day_name(s: &Series) -> PolarsResult<Series> {
+    s.weekday().map(|ca| number_to_name(&ca, &DAY_NAMES))
+}
 pub(super) fn day(s: &Series) -> PolarsResult<Series> {
     s.day().map(|ca| ca.into_series())
 }
@@ -123,7 +191,7 @@ pub(super) fn time(s: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, Some(_)) => s
             .datetime()
             .unwrap()
-            .replace_time_zone(None, None)?
+            .replace_time_zone(None, None, NonExistent::Raise)?
             .cast(&DataType::Time),
         DataType::Datetime(_, _) => s.datetime().unwrap().cast(&DataType::Time),
         DataType::Date => s.datetime().unwrap().cast(&DataType::Time),
@@ -137,7 +205,7 @@ pub(super) fn date(s: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, Some(_)) => s
             .datetime()
             .unwrap()
-            .replace_time_zone(None, None)?
+            .replace_time_zone(None, None, NonExistent::Raise)?
             .cast(&DataType::Date),
         DataType::Datetime(_, _) => s.datetime().unwrap().cast(&DataType::Date),
         DataType::Date => Ok(s.clone()),
@@ -150,7 +218,7 @@ pub(super) fn datetime(s: &Series) -> PolarsResult<Series> {
         DataType::Datetime(tu, Some(_)) => s
             .datetime()
             .unwrap()
-            .replace_time_zone(None, None)?
+            .replace_time_zone(None, None, NonExistent::Raise)?
             .cast(&DataType::Datetime(*tu, None)),
         DataType::Datetime(tu, _) => s.datetime().unwrap().cast(&DataType::Datetime(*tu, None)),
         dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
@@ -181,27 +249,28 @@ pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
 pub(super) fn truncate(s: &Series, every: &str, offset: &str) -> PolarsResult<Series> {
     let every = Duration::parse(every);
     let offset = Duration::parse(offset);
-    Ok(match s.dtype() {
-        DataType::Datetime(_, tz) => match tz {
-            #[cfg(feature = "timezones")]
-            Some(tz) => s
-                .datetime()
-                .unwrap()
-                .truncate(every, offset, tz.parse::<Tz>().ok().as_ref())?
-                .into_series(),
-            _ => s
-                .datetime()
-                .unwrap()
-                .truncate(every, offset, None)?
-                .into_series(),
-        },
-        DataType::Date => s
-            .date()
-            .unwrap()
-            .truncate(every, offset, None)?
-            .into_series(),
-        dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
-    })
+    // a tz-aware Datetime is truncated in its own time zone, which is parsed once
+    s.truncate_temporal(every, offset, None)
+}
+
+pub(super) fn truncate_by(s: &[Series]) -> PolarsResult<Series> {
+    polars_time::truncate_by(&s[0], s[1].utf8()?, s[2].utf8()?)
+}
+
+pub(super) fn normalize(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(_, _) => {
+            let ca = s.datetime().unwrap();
+            let tz = parse_datetime_time_zone(ca)?;
+            Ok(ca.normalize(tz.as_ref())?.into_series())
+        }
+        dt => polars_bail!(opq = normalize, got = dt, expected = "datetime"),
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+pub(super) fn time_since_midnight(s: &Series) -> PolarsResult<Series> {
+    s.time_since_midnight().map(|ca| ca.into_series())
 }
 
 #[cfg(feature = "date_offset")]
@@ -241,23 +310,8 @@ pub(super) fn month_end(s: &Series) -> PolarsResult<Series> {
 pub(super) fn round(s: &Series, every: &str, offset: &str) -> PolarsResult<Series> {
     let every = Duration::parse(every);
     let offset = Duration::parse(offset);
-    Ok(match s.dtype() {
-        DataType::Datetime(_, tz) => match tz {
-            #[cfg(feature = "timezones")]
-            Some(tz) => s
-                .datetime()
-                .unwrap()
-                .round(every, offset, tz.parse::<Tz>().ok().as_ref())?
-                .into_series(),
-            _ => s
-                .datetime()
-                .unwrap()
-                .round(every, offset, None)?
-                .into_series(),
-        },
-        DataType::Date => s.date().unwrap().round(every, offset, None)?.into_series(),
-        dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
-    })
+    // a tz-aware Datetime is rounded in its own time zone, which is parsed once
+    s.round_temporal(every, offset, None)
 }
 
 #[cfg(feature = "timezones")]
@@ -267,10 +321,21 @@ pub(super) fn replace_timezone(
     use_earliest: Option<bool>,
 ) -> PolarsResult<Series> {
     let ca = s.datetime()?;
-    ca.replace_time_zone(time_zone, use_earliest)
+    ca.replace_time_zone(time_zone, use_earliest, NonExistent::Raise)
         .map(|ca| ca.into_series())
 }
 
+#[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+pub(super) fn dst_transition_flags(s: &Series, time_zone: Option<&str>) -> PolarsResult<Series> {
+    let ca = s.datetime()?;
+    let (ambiguous, non_existent) = ca.dst_transition_flags(time_zone)?;
+    StructChunked::new(
+        ca.name(),
+        &[ambiguous.into_series(), non_existent.into_series()],
+    )
+    .map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "timezones")]
 #[deprecated(note = "use replace_time_zone")]
 pub(super) fn tz_localize(s: &Series, tz: &str) -> PolarsResult<Series> {
@@ -281,5 +346,7 @@ pub(super) fn tz_localize(s: &Series, tz: &str) -> PolarsResult<Series> {
         "cannot localize a tz-aware datetime \
         (consider using 'dt.convert_time_zone' or 'dt.replace_time_zone')"
     );
-    Ok(ca.replace_time_zone(Some(tz), None)?.into_series())
+    Ok(ca
+        .replace_time_zone(Some(tz), None, NonExistent::Raise)?
+        .into_series())
 }
@@ -92,6 +92,8 @@ pub enum FunctionExpr {
     TemporalExpr(TemporalFunction),
     #[cfg(feature = "date_offset")]
     DateOffset(polars_time::Duration),
+    #[cfg(feature = "date_offset")]
+    DateOffsetBy,
     #[cfg(feature = "trigonometry")]
     Trigonometry(TrigonometricFunction),
     #[cfg(feature = "sign")]
@@ -202,6 +204,8 @@ impl Display for FunctionExpr {
             TemporalExpr(fun) => return write!(f, "{fun}"),
             #[cfg(feature = "date_offset")]
             DateOffset(_) => "dt.offset_by",
+            #[cfg(feature = "date_offset")]
+            DateOffsetBy => "dt.offset_by",
             #[cfg(feature = "trigonometry")]
             Trigonometry(func) => return write!(f, "{func}"),
             #[cfg(feature = "sign")]
@@ -384,6 +388,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             DateOffset(offset) => {
                 map_owned!(temporal::date_offset, offset)
             }
+            #[cfg(feature = "date_offset")]
+            DateOffsetBy => map_as_slice!(temporal::date_offset_by),
             #[cfg(feature = "trigonometry")]
             Trigonometry(trig_function) => {
                 map!(trigonometry::apply_trigonometric_function, trig_function)
@@ -531,8 +537,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Uppercase => map!(strings::uppercase),
             Lowercase => map!(strings::lowercase),
             Strip(matches) => map!(strings::strip, matches.as_deref()),
-            LStrip(matches) => map!(strings::lstrip, matches.as_deref()),
-            RStrip(matches) => map!(strings::rstrip, matches.as_deref()),
+            LStrip(matches, n) => map!(strings::lstrip, matches.as_deref(), n),
+            RStrip(matches, n) => map!(strings::rstrip, matches.as_deref(), n),
             #[cfg(feature = "string_from_radix")]
             FromRadix(radix, strict) => map!(strings::from_radix, radix, strict),
             Slice(start, length) => map!(strings::str_slice, start, length),
@@ -556,6 +562,15 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             StartsWith(sub) => {
                 map!(binary::starts_with, &sub)
             }
+            StripPrefix(prefix) => {
+                map!(binary::strip_prefix, &prefix)
+            }
+            StripSuffix(suffix) => {
+                map!(binary::strip_suffix, &suffix)
+            }
+            StripChars(bytes) => {
+                map!(binary::strip_chars, bytes.as_deref())
+            }
         }
     }
 }
@@ -570,9 +585,11 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             IsLeapYear => map!(datetime::is_leap_year),
             IsoYear => map!(datetime::iso_year),
             Month => map!(datetime::month),
+            MonthName => map!(datetime::month_name),
             Quarter => map!(datetime::quarter),
             Week => map!(datetime::week),
             WeekDay => map!(datetime::weekday),
+            DayName => map!(datetime::day_name),
             Day => map!(datetime::day),
             OrdinalDay => map!(datetime::ordinal_day),
             Time => map!(datetime::time),
@@ -585,7 +602,12 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Microsecond => map!(datetime::microsecond),
             Nanosecond => map!(datetime::nanosecond),
             TimeStamp(tu) => map!(datetime::timestamp, tu),
+            Epoch(unit) => map!(temporal::to_epoch, &unit),
             Truncate(every, offset) => map!(datetime::truncate, &every, &offset),
+            TruncateBy => map_as_slice!(datetime::truncate_by),
+            Normalize => map!(datetime::normalize),
+            #[cfg(feature = "dtype-duration")]
+            TimeSinceMidnight => map!(datetime::time_since_midnight),
             #[cfg(feature = "date_offset")]
             MonthStart => map!(datetime::month_start),
             #[cfg(feature = "date_offset")]
@@ -597,14 +619,22 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             }
             #[cfg(feature = "timezones")]
             TzLocalize(tz) => map!(datetime::tz_localize, &tz),
-            Combine(tu) => map_as_slice!(temporal::combine, tu),
-            DateRange { every, closed, tz } => {
+            #[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+            DstTransitionFlags(tz) => map!(datetime::dst_transition_flags, tz.as_deref()),
+            Combine(tu, use_earliest) => map_as_slice!(temporal::combine, tu, use_earliest),
+            DateRange {
+                every,
+                closed,
+                tz,
+                strict,
+            } => {
                 map_as_slice!(
                     temporal::temporal_range_dispatch,
                     "date",
                     every,
                     closed,
-                    tz.clone()
+                    tz.clone(),
+                    strict
                 )
             }
             TimeRange { every, closed } => {
@@ -613,9 +643,16 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     "time",
                     every,
                     closed,
-                    None
+                    None,
+                    true
                 )
             }
+            BusinessDayCount {
+                week_mask,
+                holidays,
+            } => {
+                map_as_slice!(temporal::business_day_count, week_mask, &holidays)
+            }
         }
     }
 }
@@ -30,6 +30,7 @@ impl FunctionExpr {
                     Contains { .. } | EndsWith(_) | StartsWith(_) => {
                         mapper.with_dtype(DataType::Boolean)
                     }
+                    StripPrefix(_) | StripSuffix(_) | StripChars(_) => mapper.with_same_dtype(),
                 }
             }
             #[cfg(feature = "temporal")]
@@ -39,8 +40,9 @@ impl FunctionExpr {
                     Year | IsoYear => DataType::Int32,
                     Month | Quarter | Week | WeekDay | Day | OrdinalDay | Hour | Minute
                     | Millisecond | Microsecond | Nanosecond | Second => DataType::UInt32,
-                    TimeStamp(_) => DataType::Int64,
+                    TimeStamp(_) | Epoch(_) => DataType::Int64,
                     IsLeapYear => DataType::Boolean,
+                    MonthName | DayName => DataType::Utf8,
                     Time => DataType::Time,
                     Date => DataType::Date,
                     Datetime => match mapper.with_same_dtype().unwrap().dtype {
@@ -48,6 +50,16 @@ impl FunctionExpr {
                         dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
                     },
                     Truncate(..) => mapper.with_same_dtype().unwrap().dtype,
+                    TruncateBy => mapper.with_same_dtype().unwrap().dtype,
+                    Normalize => mapper.with_same_dtype().unwrap().dtype,
+                    #[cfg(feature = "dtype-duration")]
+                    TimeSinceMidnight => match mapper.with_same_dtype().unwrap().dtype {
+                        DataType::Datetime(tu, _) => DataType::Duration(tu),
+                        DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
+                        dtype => {
+                            polars_bail!(ComputeError: "expected Datetime or Time, got {}", dtype)
+                        }
+                    },
                     #[cfg(feature = "date_offset")]
                     MonthStart => mapper.with_same_dtype().unwrap().dtype,
                     #[cfg(feature = "date_offset")]
@@ -59,9 +71,15 @@ impl FunctionExpr {
                     }
                     #[cfg(feature = "timezones")]
                     TzLocalize(tz) => return mapper.map_datetime_dtype_timezone(Some(tz)),
+                    #[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+                    DstTransitionFlags(_) => DataType::Struct(vec![
+                        Field::new("ambiguous", DataType::Boolean),
+                        Field::new("non_existent", DataType::Boolean),
+                    ]),
                     DateRange { .. } => return mapper.map_to_supertype(),
                     TimeRange { .. } => DataType::Time,
-                    Combine(tu) => match mapper.with_same_dtype().unwrap().dtype {
+                    BusinessDayCount { .. } => DataType::Int32,
+                    Combine(tu, _use_earliest) => match mapper.with_same_dtype().unwrap().dtype {
                         DataType::Datetime(_, tz) => DataType::Datetime(*tu, tz),
                         DataType::Date => DataType::Datetime(*tu, None),
                         dtype => {
@@ -74,6 +92,8 @@ impl FunctionExpr {
 
             #[cfg(feature = "date_offset")]
             DateOffset(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "date_offset")]
+            DateOffsetBy => mapper.with_same_dtype(),
             #[cfg(feature = "trigonometry")]
             Trigonometry(_) => mapper.map_to_float_dtype(),
             #[cfg(feature = "sign")]
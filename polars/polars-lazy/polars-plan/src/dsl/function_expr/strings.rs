@@ -58,8 +58,8 @@ pub enum StringFunction {
     Uppercase,
     Lowercase,
     Strip(Option<String>),
-    RStrip(Option<String>),
-    LStrip(Option<String>),
+    RStrip(Option<String>, Option<usize>),
+    LStrip(Option<String>, Option<usize>),
     #[cfg(feature = "string_from_radix")]
     FromRadix(u32, bool),
     Slice(i64, Option<u64>),
@@ -86,7 +86,7 @@ impl StringFunction {
             ConcatVertical(_) | ConcatHorizontal(_) => mapper.with_dtype(DataType::Utf8),
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_dtype(DataType::Utf8),
-            Uppercase | Lowercase | Strip(_) | LStrip(_) | RStrip(_) | Slice(_, _) => {
+            Uppercase | Lowercase | Strip(_) | LStrip(_, _) | RStrip(_, _) | Slice(_, _) => {
                 mapper.with_dtype(DataType::Utf8)
             }
             #[cfg(feature = "string_from_radix")]
@@ -125,8 +125,8 @@ impl Display for StringFunction {
             StringFunction::Uppercase => "uppercase",
             StringFunction::Lowercase => "lowercase",
             StringFunction::Strip(_) => "strip",
-            StringFunction::LStrip(_) => "lstrip",
-            StringFunction::RStrip(_) => "rstrip",
+            StringFunction::LStrip(_, _) => "lstrip",
+            StringFunction::RStrip(_, _) => "rstrip",
             #[cfg(feature = "string_from_radix")]
             StringFunction::FromRadix { .. } => "from_radix",
             StringFunction::Slice(_, _) => "str_slice",
@@ -290,8 +290,30 @@ pub(super) fn strip(s: &Series, matches: Option<&str>) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn lstrip(s: &Series, matches: Option<&str>) -> PolarsResult<Series> {
+/// Whether `c` is one of the `matches`, or whitespace if there are none.
+fn is_stripped(c: char, matches: Option<&str>) -> bool {
+    match matches {
+        Some(matches) => matches.contains(c),
+        None => c.is_whitespace(),
+    }
+}
+
+pub(super) fn lstrip(s: &Series, matches: Option<&str>, n: Option<usize>) -> PolarsResult<Series> {
     let ca = s.utf8()?;
+    if let Some(n) = n {
+        // strip at most `n` characters
+        return Ok(ca
+            .apply(|s| {
+                let start = s
+                    .chars()
+                    .take(n)
+                    .take_while(|c| is_stripped(*c, matches))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+                Cow::Borrowed(&s[start..])
+            })
+            .into_series());
+    }
 
     if let Some(matches) = matches {
         if matches.chars().count() == 1 {
@@ -309,8 +331,23 @@ pub(super) fn lstrip(s: &Series, matches: Option<&str>) -> PolarsResult<Series>
     }
 }
 
-pub(super) fn rstrip(s: &Series, matches: Option<&str>) -> PolarsResult<Series> {
+pub(super) fn rstrip(s: &Series, matches: Option<&str>, n: Option<usize>) -> PolarsResult<Series> {
     let ca = s.utf8()?;
+    if let Some(n) = n {
+        // strip at most `n` characters
+        return Ok(ca
+            .apply(|s| {
+                let stripped = s
+                    .chars()
+                    .rev()
+                    .take(n)
+                    .take_while(|c| is_stripped(*c, matches))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+                Cow::Borrowed(&s[..s.len() - stripped])
+            })
+            .into_series());
+    }
     if let Some(matches) = matches {
         if matches.chars().count() == 1 {
             // Fast path for when a single character is passed
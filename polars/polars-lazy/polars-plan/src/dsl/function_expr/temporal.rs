@@ -6,25 +6,31 @@ use polars_time::prelude::*;
 
 use super::*;
 
+#[cfg(feature = "date_offset")]
+fn offset_fn(tu: TimeUnit) -> fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64> {
+    match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    }
+}
+
 #[cfg(feature = "date_offset")]
 pub(super) fn date_offset(s: Series, offset: Duration) -> PolarsResult<Series> {
     match s.dtype().clone() {
         DataType::Date => {
-            let s = s
-                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
-                .unwrap();
-            date_offset(s, offset).and_then(|s| s.cast(&DataType::Date))
+            let ca = s.date().unwrap();
+            let mut out = ca.0.try_apply(|t| offset.add_date(t))?.into_date();
+            // a Date has no time zone, so adding the same offset to all dates keeps their order
+            out.set_sorted_flag(ca.is_sorted_flag());
+            Ok(out.into_series())
         }
         DataType::Datetime(tu, tz) => {
             let ca = s.datetime().unwrap();
-
-            fn offset_fn(tu: TimeUnit) -> fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64> {
-                match tu {
-                    TimeUnit::Nanoseconds => Duration::add_ns,
-                    TimeUnit::Microseconds => Duration::add_us,
-                    TimeUnit::Milliseconds => Duration::add_ms,
-                }
-            }
+            // adding the same offset keeps the order, whether it is negative or not, unless a
+            // calendar offset in a time zone lands on either side of a DST transition
+            let preserve_sortedness =
+                tz.is_none() || (offset.months() == 0 && offset.weeks() == 0 && offset.days() == 0);
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
@@ -37,7 +43,11 @@ pub(super) fn date_offset(s: Series, offset: Duration) -> PolarsResult<Series> {
                     ca.0.try_apply(|v| offset_fn(&offset, v, None))
                 }
             }?;
-            out.cast(&DataType::Datetime(tu, tz))
+            let mut out = out.cast(&DataType::Datetime(tu, tz))?;
+            if preserve_sortedness {
+                out.set_sorted_flag(s.is_sorted_flag());
+            }
+            Ok(out)
         }
         dt => polars_bail!(
             ComputeError: "cannot use 'date_offset' on Series of datatype {}", dt,
@@ -45,40 +55,125 @@ pub(super) fn date_offset(s: Series, offset: Duration) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
+#[cfg(feature = "date_offset")]
+pub(super) fn date_offset_by(s: &[Series]) -> PolarsResult<Series> {
+    polars_time::offset_by(&s[0], s[1].utf8()?, None, Roll::Raise, true)
+}
+
+/// The number of `unit`s ("s", "ms", "us", "ns" or "d") since the Unix epoch of a Date or
+/// Datetime, rounded down.
+pub(super) fn to_epoch(s: &Series, unit: &str) -> PolarsResult<Series> {
+    let unit_ns = match unit {
+        "ns" => 1,
+        "us" => 1_000,
+        "ms" => 1_000_000,
+        "s" => 1_000_000_000,
+        "d" => SECONDS_IN_DAY * 1_000_000_000,
+        _ => polars_bail!(
+            ComputeError: "invalid epoch unit '{}', expected one of 's', 'ms', 'us', 'ns' or 'd'",
+            unit
+        ),
+    };
+    // scale from the time unit of the input, so that nothing is truncated before rounding down
+    let tu = match s.dtype() {
+        DataType::Datetime(tu, _) => *tu,
+        _ => TimeUnit::Milliseconds,
+    };
+    let tu_ns = match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    };
+    let ca = s.timestamp(tu)?;
+    let out = if unit_ns >= tu_ns {
+        let factor = unit_ns / tu_ns;
+        ca.apply(|t| t.div_euclid(factor))
+    } else {
+        let factor = tu_ns / unit_ns;
+        ca.apply(|t| t * factor)
+    };
+    Ok(out.into_series())
+}
+
+pub(super) fn combine(
+    s: &[Series],
+    tu: TimeUnit,
+    use_earliest: Option<bool>,
+) -> PolarsResult<Series> {
     let date = &s[0];
     let time = &s[1];
 
-    let tz = match date.dtype() {
-        DataType::Date => None,
-        DataType::Datetime(_, tz) => tz.as_ref(),
+    // the midnight of each date, as a naive Datetime
+    let (mut date, tz) = match date.dtype() {
+        DataType::Date => (date.cast(&DataType::Datetime(tu, None))?, None),
+        DataType::Datetime(dtu, tz) => {
+            let ca = date.datetime()?;
+            // keep the date of the wall-clock time, in the time unit of the input
+            let local = match tz {
+                #[cfg(feature = "timezones")]
+                Some(_) => ca.replace_time_zone(None, None, NonExistent::Raise)?,
+                _ => ca.clone(),
+            };
+            let day = SECONDS_IN_DAY
+                * match dtu {
+                    TimeUnit::Nanoseconds => 1_000_000_000,
+                    TimeUnit::Microseconds => 1_000_000,
+                    TimeUnit::Milliseconds => 1_000,
+                };
+            let midnight = local
+                .apply(|t| t - t.rem_euclid(day))
+                .into_datetime(*dtu, None)
+                .into_series()
+                .cast(&DataType::Datetime(tu, None))?;
+            (midnight, tz.as_ref())
+        }
         _dtype => {
             polars_bail!(ComputeError: format!("expected Date or Datetime, got {}", _dtype))
         }
     };
 
-    let date = date.cast(&DataType::Date)?;
-    let datetime = date.cast(&DataType::Datetime(tu, None)).unwrap();
+    let mut time = time.clone();
+    // broadcast a single date or time to the length of the other input
+    match (date.len(), time.len()) {
+        (l, r) if l == r => {}
+        (1, len) => date = date.new_from_index(0, len),
+        (len, 1) => time = time.new_from_index(0, len),
+        (l, r) => polars_bail!(
+            ComputeError: "cannot combine a date of length {} with a time of length {}", l, r
+        ),
+    }
 
     let duration = time.cast(&DataType::Duration(tu))?;
-    let result_naive = datetime + duration;
+    let result_naive = date + duration;
     match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => Ok(result_naive
             .datetime()
             .unwrap()
-            .replace_time_zone(Some(tz), None)?
+            .replace_time_zone(Some(tz), use_earliest, NonExistent::Raise)?
             .into()),
         _ => Ok(result_naive),
     }
 }
 
+pub(super) fn business_day_count(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    let start = s[0].cast(&DataType::Date)?;
+    let end = s[1].cast(&DataType::Date)?;
+    polars_time::business_day_count(start.date()?, end.date()?, week_mask, holidays)
+        .map(|ca| ca.into_series())
+}
+
 pub(super) fn temporal_range_dispatch(
     s: &[Series],
     name: &str,
     every: Duration,
     closed: ClosedWindow,
     _tz: Option<TimeZone>, // todo: respect _tz: https://github.com/pola-rs/polars/issues/8512
+    strict: bool,
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let stop = &s[1];
@@ -87,24 +182,63 @@ pub(super) fn temporal_range_dispatch(
         start.len() == stop.len(),
         ComputeError: "'start' and 'stop' should have the same length",
     );
+    polars_ensure!(
+        !start.is_empty(),
+        ComputeError: "'start' and 'stop' of a {} range cannot be empty", name
+    );
+    let dtype = start.dtype();
+    polars_ensure!(
+        matches!(dtype, DataType::Date | DataType::Datetime(_, _) | DataType::Time),
+        ComputeError: "'start' of a {} range should be of type Date, Datetime or Time, got {}",
+        name, dtype
+    );
+    // unless `strict`, the bounds that can't be cast to the type of `start` are null,
+    // and so are their ranges
+    let stop = if strict {
+        stop.strict_cast(dtype)?
+    } else {
+        stop.cast(dtype)?
+    };
+    // the physical values of a tz-aware Datetime are UTC instants, whereas `date_range_impl`
+    // takes wall-clock times in its time zone, which it localizes itself
+    #[cfg(feature = "timezones")]
+    let (start, stop) = match dtype {
+        DataType::Datetime(_, Some(_)) => {
+            let wall_clock = |s: &Series| -> PolarsResult<Series> {
+                Ok(s.datetime()?
+                    .replace_time_zone(None, None, NonExistent::Raise)?
+                    .into_series())
+            };
+            (wall_clock(start)?, wall_clock(&stop)?)
+        }
+        _ => (start.clone(), stop),
+    };
     const TO_MS: i64 = SECONDS_IN_DAY * 1000;
 
     let rng_start = start.to_physical_repr();
     let rng_stop = stop.to_physical_repr();
-    let dtype = start.dtype();
 
     let mut start = rng_start.cast(&DataType::Int64)?;
     let mut stop = rng_stop.cast(&DataType::Int64)?;
 
     let (tu, tz) = match dtype {
         DataType::Date => {
+            polars_ensure!(
+                every.is_full_days(),
+                ComputeError: "`interval` of a Date range must consist of full days, \
+                got {} month(s), {} week(s), {} day(s) and {} nanosecond(s): \
+                the sub-day part of {} nanosecond(s) is not allowed \
+                (consider casting 'start' and 'stop' to Datetime)",
+                every.months(), every.weeks(), every.days(), every.nanoseconds(),
+                every.nanoseconds()
+            );
             start = &start * TO_MS;
             stop = &stop * TO_MS;
             (TimeUnit::Milliseconds, None)
         }
         DataType::Datetime(tu, tz) => (*tu, tz.as_ref()),
         DataType::Time => (TimeUnit::Nanoseconds, None),
-        _ => unimplemented!(),
+        _ => unreachable!(),
     };
     let start = start.i64().unwrap();
     let stop = stop.i64().unwrap();
@@ -149,7 +283,7 @@ pub(super) fn temporal_range_dispatch(
             }
             builder.finish().into_series()
         }
-        _ => unimplemented!(),
+        _ => unreachable!(),
     };
 
     let to_type = DataType::List(Box::new(dtype.clone()));
@@ -520,6 +520,14 @@ macro_rules! impl_unit_setter {
 /// can then set the other fields with the `with_*` methods, or use `with_hms` to set `hour`, `minute`, and `second` all
 /// at once.
 ///
+/// The `nanosecond` is added to the `microsecond`. The result is in `time_unit` (microseconds by default, set with
+/// `with_time_unit`), so that a `nanosecond` is only kept with `TimeUnit::Nanoseconds`: with a coarser time unit, the
+/// datetime is truncated (towards the past) to a multiple of that unit.
+///
+/// Components that don't form a valid datetime (e.g. a month of 13, or February 30), or whose datetime doesn't fit in
+/// the time unit (e.g. the year 3000 in nanoseconds), produce a null, unless `strict` is set with `with_strict`, in
+/// which case they raise an error.
+///
 /// # Examples
 /// ```
 /// // construct a DatetimeArgs set to July 20, 1969 at 20:17
@@ -539,6 +547,9 @@ pub struct DatetimeArgs {
     pub minute: Expr,
     pub second: Expr,
     pub microsecond: Expr,
+    pub nanosecond: Expr,
+    pub time_unit: TimeUnit,
+    pub strict: bool,
 }
 
 impl DatetimeArgs {
@@ -554,6 +565,9 @@ impl DatetimeArgs {
             minute: lit(0),
             second: lit(0),
             microsecond: lit(0),
+            nanosecond: lit(0),
+            time_unit: TimeUnit::Microseconds,
+            strict: false,
         }
     }
 
@@ -581,12 +595,39 @@ impl DatetimeArgs {
     impl_unit_setter!(with_minute(minute));
     impl_unit_setter!(with_second(second));
     impl_unit_setter!(with_microsecond(microsecond));
+    impl_unit_setter!(with_nanosecond(nanosecond));
+
+    /// Set the time unit of the result
+    pub fn with_time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
+
+    /// Set whether components that don't form a valid datetime raise an error, instead of producing a null
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+/// Iterate over the values of `ca`, or over its first value repeated `len` times if it's shorter.
+#[cfg(feature = "temporal")]
+fn broadcast_iter<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    len: usize,
+) -> Box<dyn PolarsIterator<Item = Option<T::Native>> + '_> {
+    if ca.len() < len {
+        let value = ca.get(0);
+        Box::new((0..len).map(move |_| value))
+    } else {
+        Box::new(ca.into_iter())
+    }
 }
 
 /// Construct a column of `Datetime` from the provided [`DatetimeArgs`].
 #[cfg(feature = "temporal")]
 pub fn datetime(args: DatetimeArgs) -> Expr {
-    use polars_core::export::chrono::NaiveDate;
+    use polars_core::export::chrono::{NaiveDate, NaiveDateTime};
     use polars_core::utils::CustomIterTools;
 
     let year = args.year;
@@ -596,79 +637,107 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
     let minute = args.minute;
     let second = args.second;
     let microsecond = args.microsecond;
+    let nanosecond = args.nanosecond;
+    let time_unit = args.time_unit;
+    let strict = args.strict;
 
     let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
-        assert_eq!(s.len(), 7);
+        assert_eq!(s.len(), 8);
         let max_len = s.iter().map(|s| s.len()).max().unwrap();
-        let mut year = s[0].cast(&DataType::Int32)?;
-        if year.len() < max_len {
-            year = year.new_from_index(0, max_len)
-        }
-        let year = year.i32()?;
-        let mut month = s[1].cast(&DataType::UInt32)?;
-        if month.len() < max_len {
-            month = month.new_from_index(0, max_len);
-        }
-        let month = month.u32()?;
-        let mut day = s[2].cast(&DataType::UInt32)?;
-        if day.len() < max_len {
-            day = day.new_from_index(0, max_len);
-        }
-        let day = day.u32()?;
-        let mut hour = s[3].cast(&DataType::UInt32)?;
-        if hour.len() < max_len {
-            hour = hour.new_from_index(0, max_len);
-        }
-        let hour = hour.u32()?;
-
-        let mut minute = s[4].cast(&DataType::UInt32)?;
-        if minute.len() < max_len {
-            minute = minute.new_from_index(0, max_len);
-        }
-        let minute = minute.u32()?;
-
-        let mut second = s[5].cast(&DataType::UInt32)?;
-        if second.len() < max_len {
-            second = second.new_from_index(0, max_len);
-        }
-        let second = second.u32()?;
+        let year = s[0].cast(&DataType::Int32)?;
+        let month = s[1].cast(&DataType::UInt32)?;
+        let day = s[2].cast(&DataType::UInt32)?;
+        let hour = s[3].cast(&DataType::UInt32)?;
+        let minute = s[4].cast(&DataType::UInt32)?;
+        let second = s[5].cast(&DataType::UInt32)?;
+        let microsecond = s[6].cast(&DataType::UInt32)?;
+        let nanosecond = s[7].cast(&DataType::UInt32)?;
+
+        // checked, as e.g. nanoseconds since the epoch only fit in an i64 between the years
+        // 1677 and 2262; datetimes that don't fit are invalid
+        let to_timestamp: fn(NaiveDateTime) -> Option<i64> = match time_unit {
+            TimeUnit::Nanoseconds => |ndt| {
+                ndt.timestamp()
+                    .checked_mul(1_000_000_000)?
+                    .checked_add(ndt.timestamp_subsec_nanos() as i64)
+            },
+            TimeUnit::Microseconds => |ndt| {
+                ndt.timestamp()
+                    .checked_mul(1_000_000)?
+                    .checked_add(ndt.timestamp_subsec_micros() as i64)
+            },
+            TimeUnit::Milliseconds => |ndt| {
+                ndt.timestamp()
+                    .checked_mul(1_000)?
+                    .checked_add(ndt.timestamp_subsec_millis() as i64)
+            },
+        };
 
-        let mut microsecond = s[6].cast(&DataType::UInt32)?;
-        if microsecond.len() < max_len {
-            microsecond = microsecond.new_from_index(0, max_len);
-        }
-        let microsecond = microsecond.u32()?;
-
-        let ca: Int64Chunked = year
-            .into_iter()
-            .zip(month.into_iter())
-            .zip(day.into_iter())
-            .zip(hour.into_iter())
-            .zip(minute.into_iter())
-            .zip(second.into_iter())
-            .zip(microsecond.into_iter())
-            .map(|((((((y, m), d), h), mnt), s), us)| {
-                if let (Some(y), Some(m), Some(d), Some(h), Some(mnt), Some(s), Some(us)) =
-                    (y, m, d, h, mnt, s, us)
+        let mut first_invalid = None;
+        // components shorter than the longest one (e.g. literals) are broadcast by repeating
+        // their first value, without materializing them
+        let ca: Int64Chunked = broadcast_iter(year.i32()?, max_len)
+            .zip(broadcast_iter(month.u32()?, max_len))
+            .zip(broadcast_iter(day.u32()?, max_len))
+            .zip(broadcast_iter(hour.u32()?, max_len))
+            .zip(broadcast_iter(minute.u32()?, max_len))
+            .zip(broadcast_iter(second.u32()?, max_len))
+            .zip(broadcast_iter(microsecond.u32()?, max_len))
+            .zip(broadcast_iter(nanosecond.u32()?, max_len))
+            .enumerate()
+            .map(|(i, (((((((y, m), d), h), mnt), s), us), ns))| {
+                if let (
+                    Some(y),
+                    Some(m),
+                    Some(d),
+                    Some(h),
+                    Some(mnt),
+                    Some(s),
+                    Some(us),
+                    Some(ns),
+                ) = (y, m, d, h, mnt, s, us, ns)
                 {
-                    NaiveDate::from_ymd_opt(y, m, d)
-                        .and_then(|nd| nd.and_hms_micro_opt(h, mnt, s, us))
-                        .map(|ndt| ndt.timestamp_micros())
+                    let out = NaiveDate::from_ymd_opt(y, m, d)
+                        .and_then(|nd| {
+                            let nano = us.checked_mul(1_000)?.checked_add(ns)?;
+                            nd.and_hms_nano_opt(h, mnt, s, nano)
+                        })
+                        .and_then(to_timestamp);
+                    if out.is_none() && first_invalid.is_none() {
+                        first_invalid = Some((i, y, m, d, h, mnt, s, us, ns));
+                    }
+                    out
                 } else {
                     None
                 }
             })
             .collect_trusted();
+        if strict {
+            if let Some((i, y, m, d, h, mnt, s, us, ns)) = first_invalid {
+                polars_bail!(
+                    ComputeError: "invalid datetime components in row {}: year={}, month={}, \
+                    day={}, hour={}, minute={}, second={}, microsecond={}, nanosecond={}",
+                    i, y, m, d, h, mnt, s, us, ns
+                );
+            }
+        }
 
-        Ok(Some(
-            ca.into_datetime(TimeUnit::Microseconds, None).into_series(),
-        ))
+        Ok(Some(ca.into_datetime(time_unit, None).into_series()))
     }) as Arc<dyn SeriesUdf>);
 
     Expr::AnonymousFunction {
-        input: vec![year, month, day, hour, minute, second, microsecond],
+        input: vec![
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            microsecond,
+            nanosecond,
+        ],
         function,
-        output_type: GetOutput::from_type(DataType::Datetime(TimeUnit::Microseconds, None)),
+        output_type: GetOutput::from_type(DataType::Datetime(time_unit, None)),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: true,
@@ -682,7 +751,7 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
 /// Arguments used by [`duration`] in order to produce an `Expr` of `Duration`
 ///
 /// To construct a `DurationArgs`, use struct literal syntax with `..Default::default()` to leave unspecified fields at
-/// their default value of `lit(0)`, as demonstrated below.
+/// their default value of `lit(0)` (and a time unit of nanoseconds), as demonstrated below.
 ///
 /// ```
 /// let args = DurationArgs {
@@ -706,6 +775,7 @@ pub struct DurationArgs {
     pub milliseconds: Expr,
     pub microseconds: Expr,
     pub nanoseconds: Expr,
+    pub time_unit: TimeUnit,
 }
 
 impl Default for DurationArgs {
@@ -719,6 +789,7 @@ impl Default for DurationArgs {
             milliseconds: lit(0),
             microseconds: lit(0),
             nanoseconds: lit(0),
+            time_unit: TimeUnit::Nanoseconds,
         }
     }
 }
@@ -776,79 +847,102 @@ impl DurationArgs {
     impl_unit_setter!(with_milliseconds(milliseconds));
     impl_unit_setter!(with_microseconds(microseconds));
     impl_unit_setter!(with_nanoseconds(nanoseconds));
+
+    /// Set the time unit of the result
+    pub fn with_time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
 }
 
 /// Construct a column of `Duration` from the provided [`DurationArgs`]
+///
+/// The components are summed into the time unit of the args, rounding down any remainder
+/// of a finer component. A total that doesn't fit in that time unit raises an error.
 #[cfg(feature = "temporal")]
 pub fn duration(args: DurationArgs) -> Expr {
+    use polars_core::utils::CustomIterTools;
+
+    let time_unit = args.time_unit;
+
     let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
         assert_eq!(s.len(), 8);
         if s.iter().any(|s| s.is_empty()) {
             return Ok(Some(Series::new_empty(
                 s[0].name(),
-                &DataType::Duration(TimeUnit::Nanoseconds),
+                &DataType::Duration(time_unit),
             )));
         }
-
-        let days = s[0].cast(&DataType::Int64).unwrap();
-        let seconds = s[1].cast(&DataType::Int64).unwrap();
-        let mut nanoseconds = s[2].cast(&DataType::Int64).unwrap();
-        let microseconds = s[3].cast(&DataType::Int64).unwrap();
-        let milliseconds = s[4].cast(&DataType::Int64).unwrap();
-        let minutes = s[5].cast(&DataType::Int64).unwrap();
-        let hours = s[6].cast(&DataType::Int64).unwrap();
-        let weeks = s[7].cast(&DataType::Int64).unwrap();
-
         let max_len = s.iter().map(|s| s.len()).max().unwrap();
-
-        let condition = |s: &Series| {
-            // check if not literal 0 || full column
-            (s.len() != max_len && s.get(0).unwrap() != AnyValue::Int64(0)) || s.len() == max_len
+        let components = s
+            .iter()
+            .map(|s| s.cast(&DataType::Int64))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // the number of nanoseconds in one of each component, in the order of the inputs
+        const NS_SECOND: i128 = NANOSECONDS as i128;
+        const NS_DAY: i128 = NS_SECOND * SECONDS_IN_DAY as i128;
+        const NS_PER_COMPONENT: [i128; 8] = [
+            NS_DAY * 7,
+            NS_DAY,
+            NS_SECOND * 3600,
+            NS_SECOND * 60,
+            NS_SECOND,
+            1_000_000,
+            1_000,
+            1,
+        ];
+        let ns_per_unit: i128 = match time_unit {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
         };
 
-        if nanoseconds.len() != max_len {
-            nanoseconds = nanoseconds.new_from_index(0, max_len);
-        }
-        if condition(&microseconds) {
-            nanoseconds = nanoseconds + (microseconds * 1_000);
-        }
-        if condition(&milliseconds) {
-            nanoseconds = nanoseconds + (milliseconds * 1_000_000);
-        }
-        if condition(&seconds) {
-            nanoseconds = nanoseconds + (seconds * NANOSECONDS);
-        }
-        if condition(&days) {
-            nanoseconds = nanoseconds + (days * NANOSECONDS * SECONDS_IN_DAY);
-        }
-        if condition(&minutes) {
-            nanoseconds = nanoseconds + minutes * NANOSECONDS * 60;
-        }
-        if condition(&hours) {
-            nanoseconds = nanoseconds + hours * NANOSECONDS * 60 * 60;
-        }
-        if condition(&weeks) {
-            nanoseconds = nanoseconds + weeks * NANOSECONDS * SECONDS_IN_DAY * 7;
+        let mut iters = components
+            .iter()
+            .map(|s| Ok(broadcast_iter(s.i64()?, max_len)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut overflow = None;
+        let ca: Int64Chunked = (0..max_len)
+            .map(|i| {
+                // an i128 can hold the sum of any eight i64 components in nanoseconds
+                let mut total_ns = Some(0i128);
+                for (iter, ns) in iters.iter_mut().zip(NS_PER_COMPONENT) {
+                    let value = iter.next().unwrap();
+                    total_ns = total_ns.zip(value).map(|(t, v)| t + v as i128 * ns);
+                }
+                let total_ns = total_ns?;
+                let out = i64::try_from(total_ns.div_euclid(ns_per_unit)).ok();
+                if out.is_none() && overflow.is_none() {
+                    overflow = Some((i, total_ns));
+                }
+                out
+            })
+            .collect_trusted();
+        if let Some((i, total_ns)) = overflow {
+            polars_bail!(
+                ComputeError: "duration of {} nanoseconds in row {} overflows a Duration \
+                with time unit '{}'",
+                total_ns, i, time_unit.to_ascii()
+            );
         }
 
-        nanoseconds
-            .cast(&DataType::Duration(TimeUnit::Nanoseconds))
-            .map(Some)
+        Ok(Some(ca.into_duration(time_unit).into_series()))
     }) as Arc<dyn SeriesUdf>);
 
     Expr::AnonymousFunction {
         input: vec![
+            args.weeks,
             args.days,
+            args.hours,
+            args.minutes,
             args.seconds,
-            args.nanoseconds,
-            args.microseconds,
             args.milliseconds,
-            args.minutes,
-            args.hours,
-            args.weeks,
+            args.microseconds,
+            args.nanoseconds,
         ],
         function,
-        output_type: GetOutput::from_type(DataType::Duration(TimeUnit::Nanoseconds)),
+        output_type: GetOutput::from_type(DataType::Duration(time_unit)),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: true,
@@ -1353,6 +1447,10 @@ pub fn coalesce(exprs: &[Expr]) -> Expr {
 }
 
 /// Create a date range from a `start` and `stop` expression.
+///
+/// If `strict`, an error is raised if `stop` can't be cast to the type of `start`. Otherwise,
+/// the ranges of these bounds are null. An error is raised if a range can't be created either
+/// way, e.g. if `every` is zero.
 #[cfg(feature = "temporal")]
 pub fn date_range(
     start: Expr,
@@ -1360,12 +1458,18 @@ pub fn date_range(
     every: Duration,
     closed: ClosedWindow,
     tz: Option<TimeZone>,
+    strict: bool,
 ) -> Expr {
     let input = vec![start, end];
 
     Expr::Function {
         input,
-        function: FunctionExpr::TemporalExpr(TemporalFunction::DateRange { every, closed, tz }),
+        function: FunctionExpr::TemporalExpr(TemporalFunction::DateRange {
+            every,
+            closed,
+            tz,
+            strict,
+        }),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyGroups,
             cast_to_supertypes: true,
@@ -1375,6 +1479,44 @@ pub fn date_range(
     }
 }
 
+/// Create a date range from a `start` and `stop` expression and return it as a struct with
+/// the generated points and their ordinal index (`0..n`) in the `"ordinal"` field.
+///
+/// Every range is exploded, so the ordinal restarts at `0` for every `start`/`stop` pair.
+#[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+pub fn date_range_with_ordinal(
+    start: Expr,
+    end: Expr,
+    every: Duration,
+    closed: ClosedWindow,
+    tz: Option<TimeZone>,
+) -> Expr {
+    date_range(start, end, every, closed, tz, true).map(
+        |s| {
+            let ca = s.list()?;
+            let values = s.explode()?;
+            let mut ordinals = Vec::with_capacity(values.len());
+            for opt_s in ca.into_iter() {
+                match opt_s {
+                    Some(s) if !s.is_empty() => {
+                        ordinals.extend((0..s.len() as IdxSize).map(Some));
+                    }
+                    // `explode` produces a single null for empty and null lists
+                    _ => ordinals.push(None),
+                }
+            }
+            let ordinal = IdxCa::from_slice_options("ordinal", &ordinals).into_series();
+            StructChunked::new(s.name(), &[values, ordinal]).map(|ca| Some(ca.into_series()))
+        },
+        GetOutput::map_field(|fld| {
+            Field::new(
+                fld.name(),
+                DataType::Struct(vec![fld.clone(), Field::new("ordinal", IDX_DTYPE)]),
+            )
+        }),
+    )
+}
+
 /// Create a time range from a `start` and `stop` expression.
 #[cfg(feature = "temporal")]
 pub fn time_range(start: Expr, end: Expr, every: Duration, closed: ClosedWindow) -> Expr {
@@ -1392,6 +1534,34 @@ pub fn time_range(start: Expr, end: Expr, every: Duration, closed: ClosedWindow)
     }
 }
 
+/// Count the business days between the `start` and `end` dates, `end` excluded.
+///
+/// `week_mask` tells which days of the week, starting on Monday, are business days, and
+/// `holidays` are dates, as days since the Unix epoch, which aren't business days either.
+#[cfg(feature = "temporal")]
+pub fn business_day_count(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::TemporalExpr(TemporalFunction::BusinessDayCount {
+            week_mask,
+            holidays,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            cast_to_supertypes: false,
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
+
 #[cfg(feature = "rolling_window")]
 pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
     let x = x.cache();
@@ -1217,6 +1217,11 @@ impl Expr {
                         options.weights.is_none(),
                         ComputeError: "`weights` is not supported in 'rolling by' expression"
                     );
+                    polars_ensure!(
+                        !options.require_full_window,
+                        InvalidOperation: "'require_full_window' is not supported in 'rolling by' \
+                        expression, as its windows don't have a fixed number of values"
+                    );
                     let (by, tz) = match by.dtype() {
                         DataType::Datetime(_, tz) => (
                             by.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
@@ -1237,11 +1242,16 @@ impl Expr {
                         window_size: options.window_size,
                         min_periods: options.min_periods,
                         weights: None,
+                        weights_order: options.weights_order,
                         center: options.center,
                         by: Some(by_values),
                         tu: Some(tu),
                         tz: tz.as_ref(),
                         closed_window: options.closed_window,
+                        respect_current_null: options.respect_current_null,
+                        require_full_window: options.require_full_window,
+                        var_transform: options.var_transform,
+                        ddof: options.ddof,
                     };
 
                     rolling_fn(s, options).map(Some)
@@ -1311,7 +1321,10 @@ impl Expr {
             "rolling_sum",
             "rolling_sum_by",
             Arc::new(|s, options| s.rolling_sum(options)),
-            GetOutput::same_type(),
+            GetOutput::map_dtype(|dt| match dt {
+                DataType::UInt8 | DataType::UInt16 | DataType::UInt32 => DataType::UInt64,
+                dt => dt.clone(),
+            }),
         )
     }
 
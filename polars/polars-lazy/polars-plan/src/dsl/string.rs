@@ -427,13 +427,35 @@ impl StringNameSpace {
     /// Remove leading characters, or whitespace if matches is None.
     pub fn lstrip(self, matches: Option<String>) -> Expr {
         self.0
-            .map_private(FunctionExpr::StringExpr(StringFunction::LStrip(matches)))
+            .map_private(FunctionExpr::StringExpr(StringFunction::LStrip(
+                matches, None,
+            )))
+    }
+
+    /// Remove at most `n` leading characters, or whitespace if matches is None.
+    pub fn lstrip_n(self, matches: Option<String>, n: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::LStrip(
+                matches,
+                Some(n),
+            )))
     }
 
     /// Remove trailing characters, or whitespace if matches is None..
     pub fn rstrip(self, matches: Option<String>) -> Expr {
         self.0
-            .map_private(FunctionExpr::StringExpr(StringFunction::RStrip(matches)))
+            .map_private(FunctionExpr::StringExpr(StringFunction::RStrip(
+                matches, None,
+            )))
+    }
+
+    /// Remove at most `n` trailing characters, or whitespace if matches is None.
+    pub fn rstrip_n(self, matches: Option<String>, n: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::RStrip(
+                matches,
+                Some(n),
+            )))
     }
 
     /// Convert all characters to lowercase.
@@ -1,4 +1,3 @@
-#[cfg(feature = "binary_encoding")]
 use std::borrow::Cow;
 
 #[cfg(feature = "binary_encoding")]
@@ -46,6 +45,36 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         out
     }
 
+    /// Remove the byte sequence `prefix` from the start of every value that starts with it.
+    fn strip_prefix(&self, prefix: &[u8]) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply(|s| Cow::Borrowed(s.strip_prefix(prefix).unwrap_or(s)))
+    }
+
+    /// Remove the byte sequence `suffix` from the end of every value that ends with it.
+    fn strip_suffix(&self, suffix: &[u8]) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply(|s| Cow::Borrowed(s.strip_suffix(suffix).unwrap_or(s)))
+    }
+
+    /// Remove the leading and trailing bytes that are in `bytes` from every value. If `bytes`
+    /// is `None`, ASCII whitespace is removed.
+    ///
+    /// Unlike stripping a `Utf8` value, this works on single bytes and doesn't require the
+    /// values to be valid UTF-8.
+    fn strip_chars(&self, bytes: Option<&[u8]>) -> BinaryChunked {
+        let ca = self.as_binary();
+        let strip = |b: &u8| match bytes {
+            Some(bytes) => bytes.contains(b),
+            None => b.is_ascii_whitespace(),
+        };
+        ca.apply(|s| {
+            let start = s.iter().position(|b| !strip(b)).unwrap_or(s.len());
+            let end = s.iter().rposition(|b| !strip(b)).map_or(start, |i| i + 1);
+            Cow::Borrowed(&s[start..end])
+        })
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
@@ -106,3 +135,43 @@ pub trait BinaryNameSpaceImpl: AsBinary {
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_strip_binary() {
+        // a PNG signature followed by data that isn't valid UTF-8
+        let magic: &[u8] = b"\x89PNG\r\n\x1a\n";
+        let data: &[u8] = b"\xff\x00data";
+        let blob = [magic, data].concat();
+        let ca = BinaryChunked::new(
+            "blobs",
+            &[
+                Some(blob.as_slice()),
+                Some(b"\xffno magic".as_slice()),
+                None,
+            ],
+        );
+
+        let out = ca.strip_prefix(magic);
+        assert_eq!(out.name(), "blobs");
+        assert_eq!(out.get(0), Some(data));
+        assert_eq!(out.get(1), Some(b"\xffno magic".as_slice()));
+        assert_eq!(out.get(2), None);
+
+        let out = ca.strip_suffix(b"data");
+        assert_eq!(out.get(0), Some(&blob[..blob.len() - 4]));
+
+        let out = ca.strip_chars(Some(b"\x89\xff".as_slice()));
+        assert_eq!(out.get(0), Some(&blob[1..]));
+        assert_eq!(out.get(1), Some(b"no magic".as_slice()));
+        assert_eq!(out.get(2), None);
+
+        let ca = BinaryChunked::new("blobs", &[b" \t\xff \n".as_slice(), b"  ".as_slice()]);
+        let out = ca.strip_chars(None);
+        assert_eq!(out.get(0), Some(b"\xff".as_slice()));
+        assert_eq!(out.get(1), Some(b"".as_slice()));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use polars_core::prelude::Utf8Chunked;
+use polars_core::prelude::*;
 
 pub(super) fn ljust<'a>(ca: &'a Utf8Chunked, width: usize, fillchar: char) -> Utf8Chunked {
     // amortize allocation
@@ -46,34 +46,30 @@ pub(super) fn rjust<'a>(ca: &'a Utf8Chunked, width: usize, fillchar: char) -> Ut
     ca.apply_mut(f)
 }
 
+/// Write `s` left filled with ASCII '0' digits to a length of `width` into `buf`, inserting the
+/// padding after a leading sign. Returns `false`, leaving `buf` untouched, if `s` is already at
+/// least `width` long.
+fn write_zfilled(buf: &mut String, s: &str, width: usize) -> bool {
+    let padding = width.saturating_sub(s.len());
+    if padding == 0 {
+        return false;
+    }
+    buf.clear();
+    let (sign, digits) = match s.as_bytes().first() {
+        Some(b'-' | b'+') => s.split_at(1),
+        _ => ("", s),
+    };
+    write!(buf, "{sign}{:0padding$}{digits}", 0, padding = padding).unwrap();
+    true
+}
+
 pub(super) fn zfill<'a>(ca: &'a Utf8Chunked, alignment: usize) -> Utf8Chunked {
     // amortize allocation
     let mut buf = String::new();
     let f = |s: &'a str| {
-        let alignment = alignment.saturating_sub(s.len());
-        if alignment == 0 {
+        if !write_zfilled(&mut buf, s, alignment) {
             return s;
         }
-        buf.clear();
-        if let Some(stripped) = s.strip_prefix('-') {
-            write!(
-                &mut buf,
-                "-{:0alignment$}{value}",
-                0,
-                alignment = alignment,
-                value = stripped
-            )
-            .unwrap();
-        } else {
-            write!(
-                &mut buf,
-                "{:0alignment$}{value}",
-                0,
-                alignment = alignment,
-                value = s
-            )
-            .unwrap();
-        };
         // extend lifetime
         // lifetime is bound to 'a
         let slice = buf.as_str();
@@ -81,3 +77,84 @@ pub(super) fn zfill<'a>(ca: &'a Utf8Chunked, alignment: usize) -> Utf8Chunked {
     };
     ca.apply_mut(f)
 }
+
+pub(super) fn zfill_by(ca: &Utf8Chunked, length: &UInt64Chunked) -> PolarsResult<Utf8Chunked> {
+    match (ca.len(), length.len()) {
+        (_, 1) => Ok(match length.get(0) {
+            Some(length) => zfill(ca, length as usize),
+            None => Utf8Chunked::full_null(ca.name(), ca.len()),
+        }),
+        (1, len) => {
+            let ca = ca.new_from_index(0, len);
+            zfill_by(&ca, length)
+        }
+        (a, b) => {
+            polars_ensure!(
+                a == b,
+                ShapeMismatch: "cannot zfill strings of length {} with lengths of length {}", a, b
+            );
+            // amortize allocation
+            let mut buf = String::new();
+            let mut builder = Utf8ChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+            for (opt_s, opt_length) in ca.into_iter().zip(length) {
+                match (opt_s, opt_length) {
+                    (Some(s), Some(length)) => {
+                        if write_zfilled(&mut buf, s, length as usize) {
+                            builder.append_value(&buf)
+                        } else {
+                            builder.append_value(s)
+                        }
+                    }
+                    _ => builder.append_null(),
+                }
+            }
+            Ok(builder.finish())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn zfilled(values: &[&str], lengths: &[u64]) -> Vec<Option<String>> {
+        let ca = Utf8Chunked::from_slice("", values);
+        let length = UInt64Chunked::from_slice("", lengths);
+        zfill_by(&ca, &length)
+            .unwrap()
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_zfill_by() {
+        let expected = |values: &[&str]| {
+            values
+                .iter()
+                .map(|s| Some(s.to_string()))
+                .collect::<Vec<_>>()
+        };
+        // the padding goes after a sign
+        assert_eq!(
+            zfilled(&["-7", "+7", "7"], &[4]),
+            expected(&["-007", "+007", "0007"])
+        );
+        // strings at or over the width are unchanged
+        assert_eq!(
+            zfilled(&["1234", "-123", "12345"], &[4]),
+            expected(&["1234", "-123", "12345"])
+        );
+        // a length per row, or a single string with many lengths
+        assert_eq!(
+            zfilled(&["1", "-1", "123"], &[3, 4, 2]),
+            expected(&["001", "-001", "123"])
+        );
+        assert_eq!(zfilled(&["-1"], &[2, 3]), expected(&["-1", "-01"]));
+        assert!(zfill_by(
+            &Utf8Chunked::from_slice("", &["1", "2"]),
+            &UInt64Chunked::from_slice("", &[1, 2, 3])
+        )
+        .is_err());
+    }
+}
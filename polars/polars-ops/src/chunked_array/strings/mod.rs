@@ -8,6 +8,8 @@ mod justify;
 mod namespace;
 #[cfg(feature = "strings")]
 mod replace;
+#[cfg(feature = "strings")]
+mod strip;
 
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
@@ -114,6 +114,14 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         justify::zfill(ca, alignment)
     }
 
+    /// Like [`zfill`](Self::zfill), with a width per string, or a single width for all strings.
+    /// A null width gives a null.
+    #[cfg(feature = "string_justify")]
+    fn zfill_by(&self, length: &UInt64Chunked) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_utf8();
+        justify::zfill_by(ca, length)
+    }
+
     /// Return the string left justified in a string of length width.
     /// Padding is done using the specified `fillchar`,
     /// The original string is returned if width is less than or equal to `s.len()`.
@@ -132,6 +140,56 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         justify::rjust(ca, width, fillchar)
     }
 
+    /// Remove the leading and trailing runs of matches of the regex `pat`, e.g. `\s` or `[0-9]`.
+    /// A match is only removed at either end of a string, never in the middle.
+    fn strip_chars_regex(&self, pat: &str) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_utf8();
+        let (start, end) = strip::strip_regexes(pat)?;
+        Ok(ca.apply(|s| Cow::Borrowed(strip::strip_regex(s, &start, &end))))
+    }
+
+    /// Remove `prefix` from the start of every string that starts with it, ignoring the case if
+    /// `case_insensitive`. Only ASCII letters are compared case-insensitively, unless the
+    /// `string_unicode_case_fold` feature is enabled.
+    fn strip_prefix(&self, prefix: &str, case_insensitive: bool) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        ca.apply(|s| Cow::Borrowed(strip::strip_prefix(s, prefix, case_insensitive)))
+    }
+
+    /// Remove `suffix` from the end of every string that ends with it, ignoring the case if
+    /// `case_insensitive`. Only ASCII letters are compared case-insensitively, unless the
+    /// `string_unicode_case_fold` feature is enabled.
+    fn strip_suffix(&self, suffix: &str, case_insensitive: bool) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        ca.apply(|s| Cow::Borrowed(strip::strip_suffix(s, suffix, case_insensitive)))
+    }
+
+    /// Like [`strip_prefix`](Self::strip_prefix), with a prefix per string, or a single prefix
+    /// for all strings.
+    fn strip_prefix_by(
+        &self,
+        prefix: &Utf8Chunked,
+        case_insensitive: bool,
+    ) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_utf8();
+        strip::strip_by(ca, prefix, |s, prefix| {
+            strip::strip_prefix(s, prefix, case_insensitive)
+        })
+    }
+
+    /// Like [`strip_suffix`](Self::strip_suffix), with a suffix per string, or a single suffix
+    /// for all strings.
+    fn strip_suffix_by(
+        &self,
+        suffix: &Utf8Chunked,
+        case_insensitive: bool,
+    ) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_utf8();
+        strip::strip_by(ca, suffix, |s, suffix| {
+            strip::strip_suffix(s, suffix, case_insensitive)
+        })
+    }
+
     /// Check if strings contain a regex pattern.
     fn contains(&self, pat: &str, strict: bool) -> PolarsResult<BooleanChunked> {
         let ca = self.as_utf8();
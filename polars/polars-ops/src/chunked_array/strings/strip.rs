@@ -0,0 +1,220 @@
+use std::borrow::Cow;
+
+use polars_core::export::regex::Regex;
+use polars_core::prelude::*;
+
+/// Compile the regexes that match the leading, respectively trailing, run of matches of `pat`.
+pub(super) fn strip_regexes(pat: &str) -> PolarsResult<(Regex, Regex)> {
+    // the repetition makes e.g. `[0-9]` strip all leading digits, and the regex engine doesn't
+    // loop on empty matches
+    let start = Regex::new(&format!("^(?:{pat})+"))?;
+    let end = Regex::new(&format!("(?:{pat})+$"))?;
+    Ok((start, end))
+}
+
+/// Strip the leading and trailing runs of matches in `s`, with the regexes of [`strip_regexes`].
+pub(super) fn strip_regex<'a>(s: &'a str, start: &Regex, end: &Regex) -> &'a str {
+    let s = match start.find(s) {
+        Some(m) => &s[m.end()..],
+        None => s,
+    };
+    // the leftmost match that runs to the end is the longest trailing run
+    match end.find(s) {
+        Some(m) => &s[..m.start()],
+        None => s,
+    }
+}
+
+/// The length in bytes of the leading chars of `s` that equal `pat`, when comparing the chars of
+/// both after applying `fold` to each of them.
+fn folded_match_len<I, J, F, K>(s: I, pat: J, fold: F) -> Option<usize>
+where
+    I: Iterator<Item = char>,
+    J: Iterator<Item = char>,
+    F: Fn(char) -> K,
+    K: Iterator<Item = char>,
+{
+    let mut pat = pat.flat_map(&fold).peekable();
+    let mut len = 0;
+    for c in s {
+        if pat.peek().is_none() {
+            break;
+        }
+        for folded in fold(c) {
+            if pat.next() != Some(folded) {
+                return None;
+            }
+        }
+        len += c.len_utf8();
+    }
+    pat.peek().is_none().then_some(len)
+}
+
+/// Strip `prefix` from `s` if it starts with it, ignoring the case if `case_insensitive`.
+pub(super) fn strip_prefix<'a>(s: &'a str, prefix: &str, case_insensitive: bool) -> &'a str {
+    if !case_insensitive {
+        return s.strip_prefix(prefix).unwrap_or(s);
+    }
+    #[cfg(feature = "string_unicode_case_fold")]
+    let len = folded_match_len(s.chars(), prefix.chars(), char::to_lowercase);
+    #[cfg(not(feature = "string_unicode_case_fold"))]
+    let len = folded_match_len(s.chars(), prefix.chars(), |c| {
+        std::iter::once(c.to_ascii_lowercase())
+    });
+    len.map_or(s, |len| &s[len..])
+}
+
+/// Strip `suffix` from `s` if it ends with it, ignoring the case if `case_insensitive`.
+pub(super) fn strip_suffix<'a>(s: &'a str, suffix: &str, case_insensitive: bool) -> &'a str {
+    if !case_insensitive {
+        return s.strip_suffix(suffix).unwrap_or(s);
+    }
+    // match the reversed chars
+    #[cfg(feature = "string_unicode_case_fold")]
+    let len = folded_match_len(s.chars().rev(), suffix.chars().rev(), |c| {
+        c.to_lowercase().rev()
+    });
+    #[cfg(not(feature = "string_unicode_case_fold"))]
+    let len = folded_match_len(s.chars().rev(), suffix.chars().rev(), |c| {
+        std::iter::once(c.to_ascii_lowercase())
+    });
+    len.map_or(s, |len| &s[..s.len() - len])
+}
+
+/// Apply `strip` to every string with the pattern of the same row, or with a single pattern. A
+/// single string is broadcast to the length of the patterns. A null string or pattern gives a
+/// null.
+pub(super) fn strip_by<F>(
+    ca: &Utf8Chunked,
+    pat: &Utf8Chunked,
+    strip: F,
+) -> PolarsResult<Utf8Chunked>
+where
+    F: for<'a> Fn(&'a str, &str) -> &'a str,
+{
+    let mut out: Utf8Chunked = match (ca.len(), pat.len()) {
+        (_, 1) => match pat.get(0) {
+            Some(pat) => return Ok(ca.apply(|s| Cow::Borrowed(strip(s, pat)))),
+            None => return Ok(Utf8Chunked::full_null(ca.name(), ca.len())),
+        },
+        (1, _) => match ca.get(0) {
+            Some(s) => pat
+                .into_iter()
+                .map(|opt_pat| opt_pat.map(|pat| strip(s, pat)))
+                .collect(),
+            None => return Ok(Utf8Chunked::full_null(ca.name(), pat.len())),
+        },
+        (a, b) => {
+            polars_ensure!(
+                a == b,
+                ComputeError: "cannot strip a pattern of length {} from strings of length {}", b, a
+            );
+            ca.into_iter()
+                .zip(pat)
+                .map(|(opt_s, opt_pat)| match (opt_s, opt_pat) {
+                    (Some(s), Some(pat)) => Some(strip(s, pat)),
+                    _ => None,
+                })
+                .collect()
+        }
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn strip(s: &str, pat: &str) -> String {
+        let (start, end) = strip_regexes(pat).unwrap();
+        strip_regex(s, &start, &end).to_string()
+    }
+
+    #[test]
+    fn test_strip_regex() {
+        // runs of digits at both ends, but not in the middle
+        assert_eq!(strip("123abc45def6789", "[0-9]"), "abc45def");
+        assert_eq!(strip("123abc45def6789", "[0-9]+"), "abc45def");
+        assert_eq!(strip("abc", "[0-9]"), "abc");
+        assert_eq!(strip("12345", "[0-9]"), "");
+        assert_eq!(strip("", "[0-9]"), "");
+        assert_eq!(strip(" \t a b \n", r"\s"), "a b");
+        // alternations are anchored as a whole
+        assert_eq!(strip("xyabyx", "x|y"), "ab");
+        // a pattern that matches the empty string strips nothing else
+        assert_eq!(strip("abc", "[0-9]*"), "abc");
+        assert_eq!(strip("1abc1", "[0-9]*"), "abc");
+    }
+
+    #[test]
+    fn test_strip_regex_invalid_pattern() {
+        assert!(strip_regexes("[0-9").is_err());
+    }
+
+    #[test]
+    fn test_strip_prefix_suffix_case_insensitive() {
+        let url = "http://example.COM";
+        assert_eq!(strip_prefix(url, "HTTP://", false), url);
+        assert_eq!(strip_prefix(url, "HTTP://", true), "example.COM");
+        assert_eq!(strip_prefix(url, "hTtP://", true), "example.COM");
+        assert_eq!(strip_suffix(url, ".com", false), url);
+        assert_eq!(strip_suffix(url, ".com", true), "http://example");
+        // the result borrows from the input
+        assert!(std::ptr::eq(
+            strip_prefix(url, "HTTP://", true).as_ptr(),
+            url[7..].as_ptr()
+        ));
+        // a prefix longer than the string, or not matching it
+        assert_eq!(strip_prefix("http", "HTTP://", true), "http");
+        assert_eq!(strip_suffix("com", "EXAMPLE.COM", true), "com");
+        assert_eq!(strip_prefix(url, "HTTPS://", true), url);
+    }
+
+    #[test]
+    fn test_strip_prefix_suffix_multibyte() {
+        // 'é' is two bytes, the boundary of which a one-byte prefix mustn't split
+        assert_eq!(strip_prefix("école", "e", true), "école");
+        assert_eq!(strip_suffix("café", "e", true), "café");
+        assert_eq!(strip_prefix("école", "éc", true), "ole");
+        assert_eq!(strip_suffix("CAFÉ", "fÉ", true), "CA");
+        #[cfg(feature = "string_unicode_case_fold")]
+        {
+            assert_eq!(strip_prefix("École", "éC", true), "ole");
+            assert_eq!(strip_suffix("CAFÉ", "fé", true), "CA");
+        }
+        #[cfg(not(feature = "string_unicode_case_fold"))]
+        {
+            // only ASCII letters are compared case-insensitively
+            assert_eq!(strip_prefix("École", "éC", true), "École");
+            assert_eq!(strip_suffix("CAFÉ", "fé", true), "CAFÉ");
+        }
+    }
+
+    #[test]
+    fn test_strip_by() {
+        let ca = Utf8Chunked::from_slice("a", &["ab", "Ac", "bd"]);
+        fn strip<'a>(s: &'a str, pat: &str) -> &'a str {
+            strip_prefix(s, pat, true)
+        }
+
+        let pat = Utf8Chunked::from_slice("", &["a", "A", "a"]);
+        let out = strip_by(&ca, &pat, strip).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), [Some("b"), Some("c"), Some("bd")]);
+
+        // a single pattern, or a single string
+        let pat = Utf8Chunked::from_slice("", &["A"]);
+        let out = strip_by(&ca, &pat, strip).unwrap();
+        assert_eq!(Vec::from(&out), [Some("b"), Some("c"), Some("bd")]);
+        let out = strip_by(&ca.slice(0, 1), &ca, strip).unwrap();
+        assert_eq!(Vec::from(&out), [Some(""), Some("ab"), Some("ab")]);
+
+        // incompatible lengths
+        let pat = Utf8Chunked::from_slice("", &["a", "b"]);
+        let err = strip_by(&ca, &pat, strip).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("cannot strip a pattern of length 2 from strings of length 3"));
+    }
+}
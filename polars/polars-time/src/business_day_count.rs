@@ -0,0 +1,267 @@
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+/// Validate a week mask, where the `n`-th entry tells whether the `n`-th day of the week
+/// (starting on Monday) is a business day.
+///
+/// The mask must have exactly 7 entries and at least one business day, as there would be no
+/// business day to land on otherwise.
+pub fn validate_week_mask(week_mask: &[bool]) -> PolarsResult<[bool; 7]> {
+    let week_mask: [bool; 7] = week_mask.try_into().map_err(|_| {
+        polars_err!(
+            ComputeError: "expected a week mask with 7 entries (Monday to Sunday), got {}",
+            week_mask.len()
+        )
+    })?;
+    polars_ensure!(
+        week_mask.contains(&true),
+        ComputeError: "a week mask must contain at least one business day"
+    );
+    Ok(week_mask)
+}
+
+/// The day of the week of a date given as days since the Unix epoch,
+/// from 0 (Monday) to 6 (Sunday).
+fn weekday(date: i32) -> i32 {
+    // 1970-01-01 was a Thursday
+    (date + 3).rem_euclid(7)
+}
+
+/// Whether `date` is a business day: its day of the week is enabled in `week_mask`, and it isn't
+/// one of the sorted `holidays`.
+fn is_business_day(date: i32, week_mask: &[bool; 7], holidays: &[i32]) -> bool {
+    week_mask[weekday(date) as usize] && holidays.binary_search(&date).is_err()
+}
+
+/// Count the business days in the half-open interval `[start, end)` of two dates given as days
+/// since the Unix epoch, like numpy's `busday_count`. `week_mask` tells which days of the week,
+/// starting on Monday, are business days. `holidays` is a sorted slice of dates which are not
+/// business days; duplicates and holidays outside of the interval or on a day off are ignored.
+/// If `end` is before `start`, the count is negative.
+pub fn business_day_count_impl(
+    start: i32,
+    end: i32,
+    week_mask: &[bool; 7],
+    holidays: &[i32],
+) -> i32 {
+    if end < start {
+        return -business_day_count_impl(end, start, week_mask, holidays);
+    }
+    let days = end - start;
+    let (full_weeks, remainder) = (days / 7, days % 7);
+    let business_days_per_week = week_mask.iter().filter(|&&b| b).count() as i32;
+    let start_weekday = weekday(start);
+    let partial_week = (0..remainder)
+        .filter(|day| week_mask[((start_weekday + day) % 7) as usize])
+        .count() as i32;
+    let lower = holidays.partition_point(|&holiday| holiday < start);
+    let upper = holidays.partition_point(|&holiday| holiday < end);
+    let mut previous = None;
+    let holidays_in_range = holidays[lower..upper]
+        .iter()
+        .filter(|&&holiday| {
+            let is_new = previous != Some(holiday);
+            previous = Some(holiday);
+            is_new && week_mask[weekday(holiday) as usize]
+        })
+        .count() as i32;
+    full_weeks * business_days_per_week + partial_week - holidays_in_range
+}
+
+/// Count the business days between every pair of `start` and `end` dates,
+/// see [`business_day_count_impl`]. Either side may be of length 1, and is then broadcast.
+/// `holidays` don't have to be sorted.
+pub fn business_day_count(
+    start: &DateChunked,
+    end: &DateChunked,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Int32Chunked> {
+    let week_mask = validate_week_mask(&week_mask)?;
+    // only holidays on business days change the count
+    let mut holidays: Vec<i32> = holidays
+        .iter()
+        .copied()
+        .filter(|&holiday| week_mask[weekday(holiday) as usize])
+        .collect();
+    holidays.sort_unstable();
+    holidays.dedup();
+    let count = |start: Option<i32>, end: Option<i32>| {
+        Some(business_day_count_impl(start?, end?, &week_mask, &holidays))
+    };
+    let out: Int32Chunked = match (start.len(), end.len()) {
+        (_, 1) => {
+            let end = end.get(0);
+            start.into_iter().map(|start| count(start, end)).collect()
+        }
+        (1, _) => {
+            let start = start.get(0);
+            end.into_iter().map(|end| count(start, end)).collect()
+        }
+        (start_len, end_len) => {
+            polars_ensure!(
+                start_len == end_len,
+                ShapeMismatch: "the `start` and `end` dates have different lengths: {} and {}",
+                start_len, end_len
+            );
+            start
+                .into_iter()
+                .zip(end.into_iter())
+                .map(|(start, end)| count(start, end))
+                .collect()
+        }
+    };
+    Ok(out.with_name(start.name()))
+}
+
+/// Create a [`DateChunked`] of the business days from `start` to `end`, both included, as days
+/// since the Unix epoch. Only every `every`-th business day, counting from the first one, is
+/// taken. `week_mask` and `holidays` are as in [`business_day_count`].
+pub fn business_date_range(
+    name: &str,
+    start: i32,
+    end: i32,
+    every: usize,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<DateChunked> {
+    polars_ensure!(
+        every > 0,
+        ComputeError: "'every' should be a positive number of business days"
+    );
+    let week_mask = validate_week_mask(&week_mask)?;
+    let mut holidays = holidays.to_vec();
+    holidays.sort_unstable();
+    let dates: Vec<i32> = (start..=end)
+        .filter(|&date| is_business_day(date, &week_mask, &holidays))
+        .step_by(every)
+        .collect();
+    let mut out = Int32Chunked::from_vec(name, dates).into_date();
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        (chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap() - epoch).num_days() as i32
+    }
+
+    #[test]
+    fn test_business_day_count() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let count = |start, end| business_day_count_impl(start, end, &week_mask, &[]);
+        // Monday 2023-01-02 to Friday 2023-01-06, the end is excluded
+        assert_eq!(count(date(2023, 1, 2), date(2023, 1, 6)), 4);
+        assert_eq!(count(date(2023, 1, 2), date(2023, 1, 7)), 5);
+        // Friday to Monday spans a weekend
+        assert_eq!(count(date(2023, 1, 6), date(2023, 1, 9)), 1);
+        // a weekend alone has no business days
+        assert_eq!(count(date(2023, 1, 7), date(2023, 1, 9)), 0);
+        assert_eq!(count(date(2023, 1, 4), date(2023, 1, 4)), 0);
+        // Wednesday 2023-01-04 to Tuesday 2023-01-31: 3 full weeks and a partial one
+        assert_eq!(count(date(2023, 1, 4), date(2023, 1, 31)), 19);
+        // reversed ranges are negative
+        assert_eq!(count(date(2023, 1, 31), date(2023, 1, 4)), -19);
+        // dates before the epoch
+        assert_eq!(count(date(1969, 12, 26), date(1970, 1, 2)), 5);
+    }
+
+    #[test]
+    fn test_business_day_count_week_mask() {
+        // Saturday is a business day as well
+        let week_mask = [true, true, true, true, true, true, false];
+        let count = |start, end| business_day_count_impl(start, end, &week_mask, &[]);
+        assert_eq!(count(date(2023, 1, 6), date(2023, 1, 9)), 2);
+        assert_eq!(count(date(2023, 1, 4), date(2023, 1, 31)), 23);
+
+        // Monday is not a business day
+        let week_mask = [false, true, true, true, true, false, false];
+        let count = |start, end| business_day_count_impl(start, end, &week_mask, &[]);
+        assert_eq!(count(date(2023, 1, 2), date(2023, 1, 7)), 4);
+        assert_eq!(count(date(2023, 1, 6), date(2023, 1, 10)), 1);
+        assert_eq!(count(date(2023, 1, 4), date(2023, 1, 31)), 15);
+
+        // a week mask without business days is an error
+        let start = DateChunked::from_naive_date(
+            "start",
+            [chrono::NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()],
+        );
+        let end = start.clone();
+        assert!(business_day_count(&start, &end, [false; 7], &[]).is_err());
+    }
+
+    #[test]
+    fn test_business_day_count_holidays() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let count = |start, end, holidays: &[i32]| {
+            business_day_count_impl(start, end, &week_mask, holidays)
+        };
+        let (start, end) = (date(2023, 1, 2), date(2023, 1, 9));
+        assert_eq!(count(start, end, &[]), 5);
+        // Wednesday 2023-01-04 is a holiday
+        assert_eq!(count(start, end, &[date(2023, 1, 4)]), 4);
+        assert_eq!(count(end, start, &[date(2023, 1, 4)]), -4);
+        // duplicates are only subtracted once
+        assert_eq!(count(start, end, &[date(2023, 1, 4), date(2023, 1, 4)]), 4);
+        // a holiday on a Saturday changes nothing
+        assert_eq!(count(start, end, &[date(2023, 1, 7)]), 5);
+        // holidays outside of `[start, end)` are ignored
+        assert_eq!(count(start, end, &[date(2023, 1, 1), date(2023, 1, 9)]), 5);
+        assert_eq!(count(start, end, &[date(2023, 1, 2)]), 4);
+
+        let start = Int32Chunked::from_vec("start", vec![start]).into_date();
+        let end = Int32Chunked::from_vec("end", vec![end]).into_date();
+        // unsorted holidays are accepted by the chunked version
+        let holidays = [date(2023, 1, 6), date(2023, 1, 7), date(2023, 1, 4)];
+        let out = business_day_count(&start, &end, week_mask, &holidays).unwrap();
+        assert_eq!(out.get(0), Some(3));
+    }
+
+    #[test]
+    fn test_business_date_range() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let days = |days: &[u32]| days.iter().map(|&d| date(2023, 1, d)).collect::<Vec<_>>();
+        let range = |start, end, every, holidays: &[i32]| {
+            let out = business_date_range("date", start, end, every, week_mask, holidays).unwrap();
+            assert_eq!(out.is_sorted_flag(), IsSorted::Ascending);
+            out.into_no_null_iter().collect::<Vec<_>>()
+        };
+        // Monday 2023-01-02 to Sunday 2023-01-15, with a holiday on Wednesday 2023-01-04
+        let (start, end) = (date(2023, 1, 2), date(2023, 1, 15));
+        let holidays = days(&[4]);
+        assert_eq!(
+            range(start, end, 1, &holidays),
+            days(&[2, 3, 5, 6, 9, 10, 11, 12, 13])
+        );
+        assert_eq!(range(start, end, 5, &holidays), days(&[2, 10]));
+        // holidays on `start` and `end` exclude them
+        assert_eq!(
+            range(start, date(2023, 1, 13), 1, &days(&[13, 4, 2])),
+            days(&[3, 5, 6, 9, 10, 11, 12])
+        );
+        // a weekend has no business days
+        assert!(range(date(2023, 1, 7), date(2023, 1, 8), 1, &[]).is_empty());
+
+        assert!(business_date_range("date", start, end, 0, week_mask, &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_week_mask() {
+        let week_mask = [true, true, true, true, true, false, false];
+        assert_eq!(validate_week_mask(&week_mask).unwrap(), week_mask);
+
+        let err = validate_week_mask(&[true, true, true, true, true, false]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected a week mask with 7 entries (Monday to Sunday), got 6"));
+
+        let err = validate_week_mask(&[false; 7]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("a week mask must contain at least one business day"));
+    }
+}
@@ -0,0 +1,65 @@
+use polars_arrow::export::arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+use polars_arrow::time_zone::Tz;
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+#[cfg(feature = "dtype-duration")]
+use crate::truncate::fixed_duration_in_unit;
+
+pub trait PolarsCeil {
+    /// Round every value up to the next multiple of `every`. Values on a boundary stay put.
+    fn ceil(&self, every: Duration, tz: Option<&Tz>) -> PolarsResult<Self>
+    where
+        Self: Sized;
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl PolarsCeil for DatetimeChunked {
+    fn ceil(&self, every: Duration, tz: Option<&Tz>) -> PolarsResult<Self> {
+        validate_every(&every)?;
+        let w = Window::new(every, every, Duration::parse("0ns"));
+
+        let func = match self.time_unit() {
+            TimeUnit::Nanoseconds => Window::ceil_ns,
+            TimeUnit::Microseconds => Window::ceil_us,
+            TimeUnit::Milliseconds => Window::ceil_ms,
+        };
+        Ok(self
+            .try_apply(|t| func(&w, t, tz))?
+            .into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl PolarsCeil for DateChunked {
+    fn ceil(&self, every: Duration, _tz: Option<&Tz>) -> PolarsResult<Self> {
+        validate_every(&every)?;
+        let w = Window::new(every, every, Duration::parse("0ns"));
+        Ok(self
+            .try_apply(|t| {
+                const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
+                Ok((w.ceil_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32)
+            })?
+            .into_date())
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl PolarsCeil for DurationChunked {
+    fn ceil(&self, every: Duration, _tz: Option<&Tz>) -> PolarsResult<Self> {
+        validate_every(&every)?;
+        let tu = self.time_unit();
+        let every_v = fixed_duration_in_unit(&every, tu, "every")?;
+        polars_ensure!(
+            every_v > 0,
+            ComputeError: "cannot ceil a Duration by an 'every' that is finer than its \
+            time unit '{}'", tu.to_ascii()
+        );
+        Ok(self
+            .apply(|t| match t.rem_euclid(every_v) {
+                0 => t,
+                rem => t - rem + every_v,
+            })
+            .into_duration(tu))
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// Compute the covariance between every window of `a` and the window of `b` at the same
+/// positions, with `ddof` delta degrees of freedom. Both are cast to `Float64`.
+///
+/// Pairs with a null value on either side are skipped and `min_periods` counts the remaining
+/// pairs. Windows with no more pairs than `ddof` are null.
+pub fn rolling_cov(
+    a: &Series,
+    b: &Series,
+    ddof: u8,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series> {
+    rolling_cov_corr(
+        a,
+        b,
+        "rolling_cov",
+        options,
+        &|x, y, window_size, min_periods, center| match (x.null_count(), y.null_count()) {
+            (0, 0) => rolling::no_nulls::rolling_cov(
+                x.values().as_slice(),
+                y.values().as_slice(),
+                window_size,
+                min_periods,
+                center,
+                ddof,
+            ),
+            _ => rolling::nulls::rolling_cov(x, y, window_size, min_periods, center, ddof),
+        },
+    )
+}
+
+/// Compute the Pearson correlation between every window of `a` and the window of `b` at the
+/// same positions. Both are cast to `Float64`.
+///
+/// Pairs with a null value on either side are skipped and `min_periods` counts the remaining
+/// pairs. Windows with fewer than two pairs or without variance on either side are null.
+pub fn rolling_corr(a: &Series, b: &Series, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    rolling_cov_corr(
+        a,
+        b,
+        "rolling_corr",
+        options,
+        &|x, y, window_size, min_periods, center| match (x.null_count(), y.null_count()) {
+            (0, 0) => rolling::no_nulls::rolling_corr(
+                x.values().as_slice(),
+                y.values().as_slice(),
+                window_size,
+                min_periods,
+                center,
+            ),
+            _ => rolling::nulls::rolling_corr(x, y, window_size, min_periods, center),
+        },
+    )
+}
+
+#[allow(clippy::type_complexity)]
+fn rolling_cov_corr(
+    a: &Series,
+    b: &Series,
+    agg: &str,
+    options: RollingOptionsImpl,
+    f: &dyn Fn(&PrimitiveArray<f64>, &PrimitiveArray<f64>, usize, usize, bool) -> ArrayRef,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for '{}'", agg
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for '{}'", agg
+    );
+    polars_ensure!(
+        a.len() == b.len(),
+        ComputeError: "the inputs of '{}' should have the same length, got {} and {}",
+        agg, a.len(), b.len()
+    );
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    if a.is_empty() {
+        return Ok(Series::new_empty(a.name(), &DataType::Float64));
+    }
+    let a = a.cast(&DataType::Float64)?.rechunk();
+    let b = b.cast(&DataType::Float64)?.rechunk();
+    let x = a.f64()?.downcast_iter().next().unwrap();
+    let y = b.f64()?.downcast_iter().next().unwrap();
+
+    let mut out = f(
+        x,
+        y,
+        options.window_size,
+        options.min_periods,
+        options.center,
+    );
+    if respect_current_null {
+        out = mask_current_nulls(mask_current_nulls(out, x), y);
+    }
+    Series::try_from((a.name(), out))
+}
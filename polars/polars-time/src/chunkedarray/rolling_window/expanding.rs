@@ -0,0 +1,125 @@
+use num::{Float, NumCast, One, Zero};
+use polars_core::export::num;
+
+use super::*;
+
+/// Aggregate every value together with all the values before it in a single pass, carrying
+/// the `state` of the aggregation from one value to the next (and from one chunk to the next).
+///
+/// The output is null until at least `min_periods` values, and at least one, are valid.
+fn expanding_fold<T, O, S>(
+    ca: &ChunkedArray<T>,
+    min_periods: usize,
+    mut state: S,
+    update: impl Fn(&mut S, T::Native),
+    finish: impl Fn(&S, usize) -> Option<O::Native>,
+) -> ChunkedArray<O>
+where
+    T: PolarsNumericType,
+    O: PolarsNumericType,
+{
+    let min_periods = min_periods.max(1);
+    let mut count = 0;
+    let mut out: ChunkedArray<O> = ca
+        .into_iter()
+        .map(|v| {
+            if let Some(v) = v {
+                update(&mut state, v);
+                count += 1;
+            }
+            if count >= min_periods {
+                finish(&state, count)
+            } else {
+                None
+            }
+        })
+        .collect();
+    out.rename(ca.name());
+    out
+}
+
+pub(super) fn expanding_sum<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    min_periods: usize,
+) -> ChunkedArray<T> {
+    expanding_fold(
+        ca,
+        min_periods,
+        T::Native::zero(),
+        |sum, v| *sum += v,
+        |sum, _| Some(*sum),
+    )
+}
+
+pub(super) fn expanding_min<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    min_periods: usize,
+) -> ChunkedArray<T> {
+    expanding_fold(
+        ca,
+        min_periods,
+        None,
+        |min: &mut Option<T::Native>, v| match *min {
+            Some(m) if m <= v => {}
+            _ => *min = Some(v),
+        },
+        |min, _| *min,
+    )
+}
+
+pub(super) fn expanding_max<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    min_periods: usize,
+) -> ChunkedArray<T> {
+    expanding_fold(
+        ca,
+        min_periods,
+        None,
+        |max: &mut Option<T::Native>, v| match *max {
+            Some(m) if m >= v => {}
+            _ => *max = Some(v),
+        },
+        |max, _| *max,
+    )
+}
+
+pub(super) fn expanding_mean<T>(ca: &ChunkedArray<T>, min_periods: usize) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    expanding_fold(
+        ca,
+        min_periods,
+        T::Native::zero(),
+        |sum, v| *sum += v,
+        |sum, count| Some(*sum / NumCast::from(count).unwrap()),
+    )
+}
+
+/// The standard deviation with `ddof` delta degrees of freedom, updated with Welford's
+/// algorithm. It is null as long as there are no more than `ddof` valid values.
+pub(super) fn expanding_std<T>(
+    ca: &ChunkedArray<T>,
+    ddof: u8,
+    min_periods: usize,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let ddof_native: T::Native = NumCast::from(ddof).unwrap();
+    // the number of values, their mean and the sum of their squared deviations from it
+    expanding_fold(
+        ca,
+        min_periods,
+        (T::Native::zero(), T::Native::zero(), T::Native::zero()),
+        |(n, mean, m2): &mut (T::Native, T::Native, T::Native), v| {
+            *n += T::Native::one();
+            let delta = v - *mean;
+            *mean += delta / *n;
+            *m2 += delta * (v - *mean);
+        },
+        |(n, _, m2), count| (count > ddof as usize).then(|| (*m2 / (*n - ddof_native)).sqrt()),
+    )
+}
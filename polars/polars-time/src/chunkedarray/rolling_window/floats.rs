@@ -31,6 +31,7 @@ where
             &rolling::no_nulls::rolling_mean,
             &rolling::nulls::rolling_mean,
             Some(&super::rolling_kernels::no_nulls::rolling_mean),
+            Some(&super::rolling_kernels::nulls::rolling_mean),
         )
     }
 
@@ -45,6 +46,7 @@ where
             &rolling::no_nulls::rolling_sum,
             &rolling::nulls::rolling_sum,
             Some(&super::rolling_kernels::no_nulls::rolling_sum),
+            Some(&super::rolling_kernels::nulls::rolling_sum),
         )
     }
 
@@ -59,6 +61,7 @@ where
             &rolling::no_nulls::rolling_min,
             &rolling::nulls::rolling_min,
             Some(&super::rolling_kernels::no_nulls::rolling_min),
+            Some(&super::rolling_kernels::nulls::rolling_min),
         )
     }
 
@@ -73,6 +76,7 @@ where
             &rolling::no_nulls::rolling_max,
             &rolling::nulls::rolling_max,
             Some(&super::rolling_kernels::no_nulls::rolling_max),
+            Some(&super::rolling_kernels::nulls::rolling_max),
         )
     }
 
@@ -80,8 +84,9 @@ where
     /// A window of length `window_size` will traverse the array. The values that fill this window
     /// will (optionally) be weighted according to the `weights` vector.
     fn rolling_median(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        // the median is the linearly interpolated 0.5 quantile
         if options.by.is_some() {
-            panic!("'rolling by' not yet supported for 'rolling_median', consider using 'groupby_rolling'")
+            return self.rolling_quantile(0.5, QuantileInterpolOptions::Linear, options);
         }
         rolling_agg(
             &self.0,
@@ -89,6 +94,7 @@ where
             &rolling::no_nulls::rolling_median,
             &rolling::nulls::rolling_median,
             None,
+            None,
         )
     }
 
@@ -101,53 +107,119 @@ where
         interpolation: QuantileInterpolOptions,
         options: RollingOptionsImpl,
     ) -> PolarsResult<Series> {
-        if options.by.is_some() {
-            panic!("'rolling by' not yet supported for 'rolling_quantile', consider using 'groupby_rolling'")
-        }
-
-        let options: RollingOptionsFixedWindow = options.into();
-        check_input(options.window_size, options.min_periods)?;
-        let ca = self.0.rechunk();
-
-        let arr = ca.downcast_iter().next().unwrap();
-        let arr = match self.0.has_validity() {
-            false => rolling::no_nulls::rolling_quantile(
-                arr.values(),
-                quantile,
-                interpolation,
-                options.window_size,
-                options.min_periods,
-                options.center,
-                options.weights.as_deref(),
-            ),
-            _ => rolling::nulls::rolling_quantile(
-                arr,
-                quantile,
-                interpolation,
-                options.window_size,
-                options.min_periods,
-                options.center,
-                options.weights.as_deref(),
+        rolling_agg(
+            &self.0,
+            options,
+            &|values, window_size, min_periods, center, weights| {
+                rolling::no_nulls::rolling_quantile(
+                    values,
+                    quantile,
+                    interpolation,
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                )
+            },
+            &|arr, window_size, min_periods, center, weights| {
+                rolling::nulls::rolling_quantile(
+                    arr,
+                    quantile,
+                    interpolation,
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                )
+            },
+            Some(&|values, period, offset, time, closed_window, tu, tz| {
+                super::rolling_kernels::no_nulls::rolling_quantile(
+                    values,
+                    quantile,
+                    interpolation,
+                    period,
+                    offset,
+                    time,
+                    closed_window,
+                    tu,
+                    tz,
+                )
+            }),
+            Some(
+                &|arr, min_periods, period, offset, time, closed_window, tu, tz| {
+                    super::rolling_kernels::nulls::rolling_quantile(
+                        arr,
+                        quantile,
+                        interpolation,
+                        min_periods,
+                        period,
+                        offset,
+                        time,
+                        closed_window,
+                        tu,
+                        tz,
+                    )
+                },
             ),
-        };
-        Series::try_from((self.0.name(), arr))
+        )
     }
 
-    fn rolling_var(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    fn rolling_var(&self, mut options: RollingOptionsImpl) -> PolarsResult<Series> {
+        let transform = std::mem::take(&mut options.var_transform);
+        let ddof = options.ddof;
         rolling_agg(
             &self.0,
             options,
-            &rolling::no_nulls::rolling_var,
-            &rolling::nulls::rolling_var,
-            Some(&super::rolling_kernels::no_nulls::rolling_var),
+            &|values, window_size, min_periods, center, weights| {
+                rolling::no_nulls::rolling_var(
+                    values,
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                    ddof,
+                )
+            },
+            &|arr, window_size, min_periods, center, weights| {
+                rolling::nulls::rolling_var(arr, window_size, min_periods, center, weights, ddof)
+            },
+            Some(&|values, period, offset, time, closed_window, tu, tz| {
+                super::rolling_kernels::no_nulls::rolling_var(
+                    values,
+                    ddof,
+                    period,
+                    offset,
+                    time,
+                    closed_window,
+                    tu,
+                    tz,
+                )
+            }),
+            Some(
+                &|arr, min_periods, period, offset, time, closed_window, tu, tz| {
+                    super::rolling_kernels::nulls::rolling_var(
+                        arr,
+                        ddof,
+                        min_periods,
+                        period,
+                        offset,
+                        time,
+                        closed_window,
+                        tu,
+                        tz,
+                    )
+                },
+            ),
         )
+        .map(|s| transform_var(s, transform))
     }
 
     /// Apply a rolling std (moving std) over the values in this array.
     /// A window of length `window_size` will traverse the array. The values that fill this window
     /// will (optionally) be multiplied with the weights given by the `weights` vector. The resulting
     /// values will be aggregated to their std.
-    fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    fn rolling_std(&self, mut options: RollingOptionsImpl) -> PolarsResult<Series> {
+        let transform = std::mem::take(&mut options.var_transform);
         if options.window_size.parsed_int {
             let options_fixed: RollingOptionsFixedWindow = options.clone().into();
             check_input(options_fixed.window_size, options.min_periods)?;
@@ -172,16 +244,209 @@ where
                         }
                         _ => unreachable!(),
                     }
-                    s
+                    transform_var(s, transform)
                 });
         }
 
+        let ddof = options.ddof;
+        rolling_agg(
+            &self.0,
+            options,
+            &|values, window_size, min_periods, center, weights| {
+                rolling::no_nulls::rolling_std(
+                    values,
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                    ddof,
+                )
+            },
+            &|arr, window_size, min_periods, center, weights| {
+                rolling::nulls::rolling_std(arr, window_size, min_periods, center, weights, ddof)
+            },
+            Some(&|values, period, offset, time, closed_window, tu, tz| {
+                super::rolling_kernels::no_nulls::rolling_std(
+                    values,
+                    ddof,
+                    period,
+                    offset,
+                    time,
+                    closed_window,
+                    tu,
+                    tz,
+                )
+            }),
+            Some(
+                &|arr, min_periods, period, offset, time, closed_window, tu, tz| {
+                    super::rolling_kernels::nulls::rolling_std(
+                        arr,
+                        ddof,
+                        min_periods,
+                        period,
+                        offset,
+                        time,
+                        closed_window,
+                        tu,
+                        tz,
+                    )
+                },
+            ),
+        )
+        .map(|s| transform_var(s, transform))
+    }
+
+    fn rolling_zero_crossings(
+        &self,
+        policy: ZeroCrossingPolicy,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_zero_crossings(&self.0, policy, options)
+    }
+
+    fn rolling_sum_with_reset(
+        &self,
+        reset: &dyn Fn(f64) -> bool,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_sum_with_reset(&self.0.cast(&DataType::Float64)?, reset, options)
+    }
+
+    fn rolling_cv(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_cv(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_mad(&self, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_mad(&self.0.cast(&DataType::Float64)?, scale, options)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn rolling_gain_loss(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_gain_loss(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_autocorr(&self, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_autocorr(&self.0.cast(&DataType::Float64)?, lag, options)
+    }
+
+    fn rolling_range(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            ComputeError: "weights are not supported for 'rolling_range'"
+        );
+        rolling_agg(
+            &self.0,
+            options,
+            &|values, window_size, min_periods, center, _| {
+                rolling::no_nulls::rolling_range(values, window_size, min_periods, center)
+            },
+            &|arr, window_size, min_periods, center, _| {
+                rolling::nulls::rolling_range(arr, window_size, min_periods, center)
+            },
+            None,
+            None,
+        )
+    }
+
+    fn rolling_null_fraction(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_null_fraction(&self.0, options)
+    }
+
+    fn rolling_dot(&self, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_dot(&self.0.cast(&DataType::Float64)?, reference, options)
+    }
+
+    fn rolling_rs(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_rs(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_kurtosis(
+        &self,
+        params: RollingKurtosisParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_kurtosis(&self.0.cast(&DataType::Float64)?, params, options)
+    }
+
+    fn rolling_first(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            ComputeError: "weights are not supported for 'rolling_first'"
+        );
+        rolling_agg(
+            &self.0,
+            options,
+            &rolling::no_nulls::rolling_first,
+            &rolling::nulls::rolling_first,
+            Some(&super::rolling_kernels::no_nulls::rolling_first),
+            Some(&super::rolling_kernels::nulls::rolling_first),
+        )
+    }
+
+    fn rolling_last(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            ComputeError: "weights are not supported for 'rolling_last'"
+        );
         rolling_agg(
             &self.0,
             options,
-            &rolling::no_nulls::rolling_std,
-            &rolling::nulls::rolling_std,
-            Some(&super::rolling_kernels::no_nulls::rolling_std),
+            &rolling::no_nulls::rolling_last,
+            &rolling::nulls::rolling_last,
+            Some(&super::rolling_kernels::no_nulls::rolling_last),
+            Some(&super::rolling_kernels::nulls::rolling_last),
         )
     }
+
+    fn rolling_mode(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_mode(&self.0, options)
+    }
+
+    fn rolling_rank(
+        &self,
+        params: RollingRankParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_rank(&self.0, params, options)
+    }
+
+    fn rolling_apply(
+        &self,
+        f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_apply_any(&self.0, f, options)
+    }
+
+    fn rolling_argmax(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_arg_min_max(&self.0, true, options)
+    }
+
+    fn rolling_argmin(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_arg_min_max(&self.0, false, options)
+    }
+
+    fn rolling_n_unique(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_n_unique(&self.0, options)
+    }
+
+    fn expanding_sum(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_sum(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_min(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_min(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_max(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_max(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_mean(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_mean(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_std(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_std(&self.0, 1, min_periods).into_series())
+    }
 }
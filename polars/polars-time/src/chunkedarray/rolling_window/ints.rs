@@ -19,12 +19,21 @@ where
         if options.weights.is_some() {
             return self.0.cast(&DataType::Float64)?.rolling_sum(options);
         }
+        // accumulate the smaller unsigned integers in u64 so that the sum
+        // doesn't overflow and the output stays unsigned
+        if matches!(
+            self.0.dtype(),
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32
+        ) {
+            return self.0.cast(&DataType::UInt64)?.rolling_sum(options);
+        }
         rolling_agg(
             &self.0,
             options,
             &rolling::no_nulls::rolling_sum,
             &rolling::nulls::rolling_sum,
             Some(&super::rolling_kernels::no_nulls::rolling_sum),
+            Some(&super::rolling_kernels::nulls::rolling_sum),
         )
     }
 
@@ -53,6 +62,7 @@ where
             &rolling::no_nulls::rolling_min,
             &rolling::nulls::rolling_min,
             Some(&super::rolling_kernels::no_nulls::rolling_min),
+            Some(&super::rolling_kernels::nulls::rolling_min),
         )
     }
 
@@ -66,6 +76,7 @@ where
             &rolling::no_nulls::rolling_max,
             &rolling::nulls::rolling_max,
             Some(&super::rolling_kernels::no_nulls::rolling_max),
+            Some(&super::rolling_kernels::nulls::rolling_max),
         )
     }
 
@@ -80,4 +91,150 @@ where
     fn rolling_mean(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         self.0.cast(&DataType::Float64)?.rolling_mean(options)
     }
+
+    fn rolling_zero_crossings(
+        &self,
+        policy: ZeroCrossingPolicy,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_zero_crossings(&self.0, policy, options)
+    }
+
+    fn rolling_sum_with_reset(
+        &self,
+        reset: &dyn Fn(f64) -> bool,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_sum_with_reset(&self.0.cast(&DataType::Float64)?, reset, options)
+    }
+
+    fn rolling_cv(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_cv(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_mad(&self, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_mad(&self.0.cast(&DataType::Float64)?, scale, options)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn rolling_gain_loss(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_gain_loss(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_autocorr(&self, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_autocorr(&self.0.cast(&DataType::Float64)?, lag, options)
+    }
+
+    fn rolling_range(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.0.cast(&DataType::Float64)?.rolling_range(options)
+    }
+
+    fn rolling_null_fraction(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_null_fraction(&self.0, options)
+    }
+
+    fn rolling_dot(&self, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_dot(&self.0.cast(&DataType::Float64)?, reference, options)
+    }
+
+    fn rolling_rs(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_rs(&self.0.cast(&DataType::Float64)?, options)
+    }
+
+    fn rolling_kurtosis(
+        &self,
+        params: RollingKurtosisParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_kurtosis(&self.0.cast(&DataType::Float64)?, params, options)
+    }
+
+    fn rolling_first(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            ComputeError: "weights are not supported for 'rolling_first'"
+        );
+        rolling_agg(
+            &self.0,
+            options,
+            &rolling::no_nulls::rolling_first,
+            &rolling::nulls::rolling_first,
+            Some(&super::rolling_kernels::no_nulls::rolling_first),
+            Some(&super::rolling_kernels::nulls::rolling_first),
+        )
+    }
+
+    fn rolling_last(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            ComputeError: "weights are not supported for 'rolling_last'"
+        );
+        rolling_agg(
+            &self.0,
+            options,
+            &rolling::no_nulls::rolling_last,
+            &rolling::nulls::rolling_last,
+            Some(&super::rolling_kernels::no_nulls::rolling_last),
+            Some(&super::rolling_kernels::nulls::rolling_last),
+        )
+    }
+
+    fn rolling_mode(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_mode(&self.0, options)
+    }
+
+    fn rolling_rank(
+        &self,
+        params: RollingRankParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_rank(&self.0, params, options)
+    }
+
+    fn rolling_apply(
+        &self,
+        f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        rolling_apply_any(&self.0, f, options)
+    }
+
+    fn rolling_argmax(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_arg_min_max(&self.0, true, options)
+    }
+
+    fn rolling_argmin(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_arg_min_max(&self.0, false, options)
+    }
+
+    fn rolling_n_unique(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        rolling_n_unique(&self.0, options)
+    }
+
+    fn expanding_sum(&self, min_periods: usize) -> PolarsResult<Series> {
+        // like `rolling_sum`, accumulate the smaller unsigned integers in u64
+        if matches!(
+            self.0.dtype(),
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32
+        ) {
+            return self.0.cast(&DataType::UInt64)?.expanding_sum(min_periods);
+        }
+        Ok(expanding::expanding_sum(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_min(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_min(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_max(&self, min_periods: usize) -> PolarsResult<Series> {
+        Ok(expanding::expanding_max(&self.0, min_periods).into_series())
+    }
+
+    fn expanding_mean(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.0.cast(&DataType::Float64)?.expanding_mean(min_periods)
+    }
+
+    fn expanding_std(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.0.cast(&DataType::Float64)?.expanding_std(min_periods)
+    }
 }
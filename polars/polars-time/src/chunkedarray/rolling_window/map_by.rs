@@ -0,0 +1,73 @@
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+
+use super::*;
+
+/// Apply a custom function to every window of `values` determined by the time column `by`.
+///
+/// `f` receives the values of the window and their timestamps, in the time unit of `by`,
+/// and should return a Series with a single value. Windows look back `window_size` from
+/// every timestamp and are closed according to `closed_window`, as in the 'rolling by'
+/// aggregations. Empty windows are passed to `f` as well.
+///
+/// This materializes every window, so prefer the built-in aggregations where possible.
+pub fn rolling_map_by(
+    values: &Series,
+    by: &Series,
+    f: &dyn Fn(&Series, &[i64]) -> Series,
+    window_size: Duration,
+    closed_window: ClosedWindow,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == by.len(),
+        ComputeError: "'values' and 'by' should have the same length, got {} and {}",
+        values.len(), by.len()
+    );
+    let by = match by.dtype() {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+        DataType::Datetime(_, _) => by.rechunk(),
+        dt => polars_bail!(opq = rolling_map_by, got = dt, expected = "date/datetime"),
+    };
+    let by = by.datetime().unwrap();
+    let time = by.cont_slice().map_err(|_| {
+        polars_err!(ComputeError: "`by` column should not have null values in 'rolling_map_by'")
+    })?;
+    let tu = by.time_unit();
+    let mut offset = window_size;
+    offset.negative = true;
+
+    let offset_iter = match by.time_zone() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            window_size,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(window_size, offset, time, closed_window, tu, None),
+    };
+
+    let mut out: Option<Series> = None;
+    for window in offset_iter {
+        let (start, len) = window?;
+        let (start, end) = (start as usize, (start + len) as usize);
+        let value = f(&values.slice(start as i64, end - start), &time[start..end]);
+        polars_ensure!(
+            value.len() == 1,
+            ComputeError: "the function in 'rolling_map_by' should return a single value, got {}",
+            value.len()
+        );
+        match out.as_mut() {
+            Some(out) => {
+                out.append(&value)?;
+            }
+            None => out = Some(value),
+        }
+    }
+    let mut out = out.unwrap_or_else(|| Series::new_empty(values.name(), values.dtype()));
+    out.rename(values.name());
+    Ok(out)
+}
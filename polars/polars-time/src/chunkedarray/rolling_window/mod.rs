@@ -1,7 +1,17 @@
+#[cfg(feature = "rolling_window")]
+mod cov;
+#[cfg(feature = "rolling_window")]
+mod expanding;
 mod floats;
 mod ints;
 #[cfg(feature = "rolling_window")]
+mod map_by;
+#[cfg(feature = "rolling_window")]
 mod rolling_kernels;
+#[cfg(feature = "rolling_window")]
+mod rsi;
+#[cfg(feature = "rolling_window")]
+mod time_since_event;
 
 #[cfg(feature = "rolling_window")]
 use std::convert::TryFrom;
@@ -9,6 +19,10 @@ use std::ops::SubAssign;
 
 #[cfg(feature = "rolling_window")]
 use arrow::array::{Array, PrimitiveArray};
+#[cfg(feature = "rolling_window")]
+pub use cov::*;
+#[cfg(feature = "rolling_window")]
+pub use map_by::*;
 use polars_arrow::data_types::IsFloat;
 #[cfg(feature = "rolling_window")]
 use polars_arrow::export::arrow;
@@ -16,12 +30,44 @@ use polars_arrow::export::arrow;
 use polars_arrow::kernels::rolling;
 #[cfg(feature = "rolling_window")]
 use polars_arrow::prelude::QuantileInterpolOptions;
+#[cfg(feature = "rolling_window")]
+use polars_arrow::utils::combine_validities_and;
 use polars_core::prelude::*;
+#[cfg(feature = "rolling_window")]
+pub use rsi::*;
+#[cfg(feature = "rolling_window")]
+pub use time_since_event::*;
 
 #[cfg(feature = "rolling_window")]
 use crate::prelude::*;
 use crate::series::WrapFloat;
 
+/// Which sample of a window the first of the `weights` multiplies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg(feature = "rolling_window")]
+pub enum WeightsOrder {
+    /// `weights[0]` multiplies the oldest sample and the last weight the most recent one,
+    /// i.e. the weights are laid over the window as it appears in the data.
+    #[default]
+    OldestFirst,
+    /// `weights[0]` multiplies the most recent sample, as the taps `h[k]` of a causal FIR
+    /// filter `y[n] = sum(h[k] * x[n - k])`.
+    NewestFirst,
+}
+
+/// A transformation applied to the output of `rolling_var` and `rolling_std`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg(feature = "rolling_window")]
+pub enum RollingVarTransform {
+    /// Return the variance/standard deviation as is.
+    #[default]
+    None,
+    /// Return `ln(1 + x)`.
+    Log1p,
+    /// Return `sqrt(x)`.
+    Sqrt,
+}
+
 #[derive(Clone)]
 #[cfg(feature = "rolling_window")]
 pub struct RollingOptions {
@@ -31,13 +77,30 @@ pub struct RollingOptions {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    ///              By default the first weight multiplies the oldest value, see `weights_order`.
     pub weights: Option<Vec<f64>>,
+    /// Which sample of the window the first weight multiplies.
+    pub weights_order: WeightsOrder,
     /// Set the labels at the center of the window.
+    /// A window defined by a time column then spans half the duration on either side of its
+    /// timestamp `t`, e.g. `(t - window_size / 2, t + window_size / 2]` for a right closed window.
     pub center: bool,
     /// Compute the rolling aggregates with a window defined by a time column
     pub by: Option<String>,
     /// The closed window of that time window if given
     pub closed_window: Option<ClosedWindow>,
+    /// Set the output to null wherever the input value is null,
+    /// even if the window has enough valid values.
+    pub respect_current_null: bool,
+    /// Only compute a result for full windows, i.e. `min_periods` is raised to the window size.
+    /// An error is raised if it is set for windows defined by a time column.
+    pub require_full_window: bool,
+    /// A transformation of the output of `rolling_var` and `rolling_std`.
+    /// Ignored by the other aggregations.
+    pub var_transform: RollingVarTransform,
+    /// The delta degrees of freedom of `rolling_var`, `rolling_std` and `rolling_cv`, 1 by
+    /// default. Ignored by the other aggregations.
+    pub ddof: u8,
 }
 
 #[cfg(feature = "rolling_window")]
@@ -47,13 +110,38 @@ impl Default for RollingOptions {
             window_size: Duration::parse("3i"),
             min_periods: 1,
             weights: None,
+            weights_order: WeightsOrder::OldestFirst,
             center: false,
             by: None,
             closed_window: None,
+            respect_current_null: false,
+            require_full_window: false,
+            var_transform: RollingVarTransform::None,
+            ddof: 1,
         }
     }
 }
 
+#[cfg(feature = "rolling_window")]
+impl RollingOptions {
+    /// Only compute a result once the window is full, like pandas does by default.
+    /// This sets `require_full_window`, so `min_periods` resolves to the window size once that
+    /// is known as a number of elements. Windows defined by a time column don't have a fixed
+    /// number of elements, so an error is raised for them.
+    #[must_use]
+    pub fn with_min_periods_equal_window(mut self) -> Self {
+        self.require_full_window = true;
+        self
+    }
+
+    /// Set the delta degrees of freedom of `rolling_var`, `rolling_std` and `rolling_cv`.
+    #[must_use]
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.ddof = ddof;
+        self
+    }
+}
+
 #[derive(Clone)]
 #[cfg(feature = "rolling_window")]
 pub struct RollingOptionsImpl<'a> {
@@ -63,13 +151,40 @@ pub struct RollingOptionsImpl<'a> {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    ///              By default the first weight multiplies the oldest value, see `weights_order`.
     pub weights: Option<Vec<f64>>,
+    /// Which sample of the window the first weight multiplies.
+    pub weights_order: WeightsOrder,
     /// Set the labels at the center of the window.
+    /// A window defined by a time column then spans half the duration on either side of its
+    /// timestamp `t`, e.g. `(t - window_size / 2, t + window_size / 2]` for a right closed window.
     pub center: bool,
     pub by: Option<&'a [i64]>,
     pub tu: Option<TimeUnit>,
     pub tz: Option<&'a TimeZone>,
     pub closed_window: Option<ClosedWindow>,
+    /// Set the output to null wherever the input value is null,
+    /// even if the window has enough valid values.
+    pub respect_current_null: bool,
+    /// Only compute a result for full windows, i.e. `min_periods` is raised to the window size.
+    /// An error is raised if it is set for windows defined by a time column.
+    pub require_full_window: bool,
+    /// A transformation of the output of `rolling_var` and `rolling_std`.
+    /// Ignored by the other aggregations.
+    pub var_transform: RollingVarTransform,
+    /// The delta degrees of freedom of `rolling_var`, `rolling_std` and `rolling_cv`, 1 by
+    /// default. Ignored by the other aggregations.
+    pub ddof: u8,
+}
+
+#[cfg(feature = "rolling_window")]
+impl<'a> RollingOptionsImpl<'a> {
+    /// Set the delta degrees of freedom of `rolling_var`, `rolling_std` and `rolling_cv`.
+    #[must_use]
+    pub fn with_ddof(mut self, ddof: u8) -> Self {
+        self.ddof = ddof;
+        self
+    }
 }
 
 #[cfg(feature = "rolling_window")]
@@ -85,11 +200,16 @@ impl From<RollingOptions> for RollingOptionsImpl<'static> {
             window_size,
             min_periods: options.min_periods,
             weights: options.weights,
+            weights_order: options.weights_order,
             center: options.center,
             by: None,
             tu: None,
             tz: None,
             closed_window: None,
+            respect_current_null: options.respect_current_null,
+            require_full_window: options.require_full_window,
+            var_transform: options.var_transform,
+            ddof: options.ddof,
         }
     }
 }
@@ -103,10 +223,15 @@ impl From<RollingOptions> for RollingOptionsFixedWindow {
             "should be fixed integer window size at this point"
         );
 
+        let window_size = window_size.nanoseconds() as usize;
         RollingOptionsFixedWindow {
-            window_size: window_size.nanoseconds() as usize,
-            min_periods: options.min_periods,
-            weights: options.weights,
+            window_size,
+            min_periods: effective_min_periods(
+                options.min_periods,
+                window_size,
+                options.require_full_window,
+            ),
+            weights: oriented_weights(options.weights, options.weights_order),
             center: options.center,
         }
     }
@@ -119,11 +244,16 @@ impl Default for RollingOptionsImpl<'static> {
             window_size: Duration::parse("3i"),
             min_periods: 1,
             weights: None,
+            weights_order: WeightsOrder::OldestFirst,
             center: false,
             by: None,
             tu: None,
             tz: None,
             closed_window: None,
+            respect_current_null: false,
+            require_full_window: false,
+            var_transform: RollingVarTransform::None,
+            ddof: 1,
         }
     }
 }
@@ -137,15 +267,70 @@ impl<'a> From<RollingOptionsImpl<'a>> for RollingOptionsFixedWindow {
             "should be fixed integer window size at this point"
         );
 
+        let window_size = window_size.nanoseconds() as usize;
         RollingOptionsFixedWindow {
-            window_size: window_size.nanoseconds() as usize,
-            min_periods: options.min_periods,
-            weights: options.weights,
+            window_size,
+            min_periods: effective_min_periods(
+                options.min_periods,
+                window_size,
+                options.require_full_window,
+            ),
+            weights: oriented_weights(options.weights, options.weights_order),
             center: options.center,
         }
     }
 }
 
+/// Apply `transform` to the (float) output of a rolling variance or standard deviation.
+#[cfg(feature = "rolling_window")]
+fn transform_var(mut s: Series, transform: RollingVarTransform) -> Series {
+    macro_rules! apply {
+        ($f:expr) => {
+            match s.dtype().clone() {
+                DataType::Float32 => {
+                    let ca: &mut ChunkedArray<Float32Type> = s._get_inner_mut().as_mut();
+                    ca.apply_mut($f)
+                }
+                DataType::Float64 => {
+                    let ca: &mut ChunkedArray<Float64Type> = s._get_inner_mut().as_mut();
+                    ca.apply_mut($f)
+                }
+                _ => unreachable!(),
+            }
+        };
+    }
+    match transform {
+        RollingVarTransform::None => {}
+        RollingVarTransform::Log1p => apply!(|v| v.ln_1p()),
+        RollingVarTransform::Sqrt => apply!(|v| v.sqrt()),
+    }
+    s
+}
+
+/// The kernels multiply the first weight with the oldest sample of the window.
+#[cfg(feature = "rolling_window")]
+fn oriented_weights(weights: Option<Vec<f64>>, order: WeightsOrder) -> Option<Vec<f64>> {
+    weights.map(|mut weights| {
+        if order == WeightsOrder::NewestFirst {
+            weights.reverse();
+        }
+        weights
+    })
+}
+
+#[cfg(feature = "rolling_window")]
+fn effective_min_periods(
+    min_periods: usize,
+    window_size: usize,
+    require_full_window: bool,
+) -> usize {
+    if require_full_window {
+        std::cmp::max(min_periods, window_size)
+    } else {
+        min_periods
+    }
+}
+
 #[cfg(not(feature = "rolling_window"))]
 pub trait RollingAgg {}
 
@@ -202,6 +387,173 @@ pub trait RollingAgg {
     /// will (optionally) be multiplied with the weights given by the `weights` vector. The resulting
     /// values will be aggregated to their std.
     fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Count the number of sign changes (zero crossings) in every window.
+    /// How zeros are treated is determined by `policy`; null values are skipped.
+    fn rolling_zero_crossings(
+        &self,
+        policy: ZeroCrossingPolicy,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series>;
+
+    /// Apply a rolling sum that resets to zero whenever `reset` returns `true` for the running
+    /// sum of a window, e.g. to compute a CUSUM. The output is always `Float64`.
+    ///
+    /// This is considerably slower than [`RollingAgg::rolling_sum`] as every window is summed
+    /// from scratch.
+    fn rolling_sum_with_reset(
+        &self,
+        reset: &dyn Fn(f64) -> bool,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series>;
+
+    /// Apply a rolling coefficient of variation (`std / mean`) over the values in this array,
+    /// with `options.ddof` delta degrees of freedom. The output is always `Float64`.
+    ///
+    /// A window is null if it is under-filled or if its mean is zero.
+    fn rolling_cv(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Apply a rolling median absolute deviation (MAD) over the values in this array, i.e. the
+    /// median of the absolute deviations from the median of every window. If `scale` is set,
+    /// the MAD is multiplied by 1.4826 to estimate the standard deviation of normally
+    /// distributed data. The output is always `Float64`.
+    fn rolling_mad(&self, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the sum of the positive values (gains) and the absolute sum of the negative values
+    /// (losses) of every window in a single pass. Returns a struct with the `Float64` fields
+    /// `gains` and `losses`.
+    ///
+    /// Weights aren't supported, so an error is raised if they are set.
+    #[cfg(feature = "dtype-struct")]
+    fn rolling_gain_loss(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the autocorrelation at `lag` of every window, i.e. the Pearson correlation between
+    /// the window and its `lag`-shifted self. The output is always `Float64`.
+    ///
+    /// A window is null if it is under-filled, has fewer than `lag + 2` values or has no variance.
+    fn rolling_autocorr(&self, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Apply a rolling range (`max - min`) over the values in this array, tracking the min and the
+    /// max of every window in a single pass. Integers are cast to `Float64` so that the
+    /// subtraction can't overflow.
+    fn rolling_range(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the fraction of null values (between `0.0` and `1.0`) in every window. The
+    /// fraction is relative to the number of values in the window, which is clipped at the edges.
+    /// A window is null if it holds fewer than `min_periods` values, null or not.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_null_fraction(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the dot product of every window with a fixed `reference` window, e.g. to
+    /// match a template. The window size must be equal to the length of `reference`, whose
+    /// first value multiplies the oldest value of the window. The output is always `Float64`.
+    ///
+    /// Windows that are clipped at the edges are multiplied with the matching part of
+    /// `reference`, and are null if they hold fewer than `min_periods` non-null values.
+    fn rolling_dot(&self, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the rescaled range (R/S) statistic of every window, as used in Hurst exponent
+    /// estimation: the range of the cumulative deviations from the window's mean, divided by
+    /// the population standard deviation of the window. The output is always `Float64`.
+    ///
+    /// A window is null if it is under-filled, has fewer than two values or has no variance.
+    fn rolling_rs(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Compute the kurtosis of every window, i.e. the fourth central moment divided by the
+    /// square of the variance. `params` chooses between the excess (Fisher) and the raw
+    /// (Pearson) kurtosis, and whether to correct for bias. The output is always `Float64`.
+    ///
+    /// A window is null if it is under-filled or has no variance, or if the bias is corrected
+    /// and it has fewer than four values.
+    fn rolling_kurtosis(
+        &self,
+        params: RollingKurtosisParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series>;
+
+    /// Take the first (oldest) valid value of every window, keeping the data type. A window
+    /// is null if it holds fewer than `min_periods` valid values.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_first(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Take the last (newest) valid value of every window, keeping the data type. A window
+    /// is null if it holds fewer than `min_periods` valid values.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_last(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Take the most frequent value of every window, keeping the data type. Ties are broken
+    /// by taking the smallest of the most frequent values. A window is null if it holds fewer
+    /// than `min_periods` valid values.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_mode(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Rank the value of every row among the valid values of its window, starting at 1 for
+    /// the smallest value, or the largest if `params.descending` is set. Equal values are
+    /// ranked by `params.method`. The output is a `Float64` that is null where the value is
+    /// null or its window holds fewer than `min_periods` valid values.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_rank(
+        &self,
+        params: RollingRankParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series>;
+
+    /// Apply a custom reduction `f` to a Series view of every window. If weights are set, the
+    /// values of a window are multiplied with them before they are passed to `f`. Windows with
+    /// fewer than `min_periods` valid values are null and `f` isn't called for them.
+    ///
+    /// This calls `f` for every window and materializes the windows, so it is a lot slower
+    /// than the built-in aggregations. It isn't available in the streaming engine either.
+    /// On a Series, this is exposed as `rolling_map`, as `rolling_apply` is taken by the
+    /// fixed-window version of polars-core.
+    fn rolling_apply(
+        &self,
+        f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series>;
+
+    /// Find the offset of the largest value of every window from the first row of the
+    /// window, as a `UInt32`. On ties the first one is taken. A window is null if it holds
+    /// fewer than `min_periods` valid values.
+    ///
+    /// An error is raised if weights are set, as the max of weighted values is ambiguous.
+    fn rolling_argmax(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Find the offset of the smallest value of every window from the first row of the
+    /// window, as a `UInt32`. On ties the first one is taken. A window is null if it holds
+    /// fewer than `min_periods` valid values.
+    ///
+    /// An error is raised if weights are set, as the min of weighted values is ambiguous.
+    fn rolling_argmin(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Count the distinct valid values of every window, as a `UInt32`. Null values are not
+    /// counted. A window is null if it holds fewer than `min_periods` valid values.
+    ///
+    /// Weights make no sense here, so an error is raised if they are set.
+    fn rolling_n_unique(&self, options: RollingOptionsImpl) -> PolarsResult<Series>;
+
+    /// Apply an expanding sum, i.e. the sum of all values up to and including the current one.
+    /// The values are summed in a single pass, across chunks, rather than as rolling windows.
+    /// The output is null until `min_periods` values are valid.
+    fn expanding_sum(&self, min_periods: usize) -> PolarsResult<Series>;
+
+    /// Apply an expanding min in a single pass, see [`RollingAgg::expanding_sum`].
+    fn expanding_min(&self, min_periods: usize) -> PolarsResult<Series>;
+
+    /// Apply an expanding max in a single pass, see [`RollingAgg::expanding_sum`].
+    fn expanding_max(&self, min_periods: usize) -> PolarsResult<Series>;
+
+    /// Apply an expanding mean in a single pass, see [`RollingAgg::expanding_sum`].
+    fn expanding_mean(&self, min_periods: usize) -> PolarsResult<Series>;
+
+    /// Apply an expanding std (with one delta degree of freedom) in a single pass, see
+    /// [`RollingAgg::expanding_sum`]. The output is null while fewer than two values are valid.
+    fn expanding_std(&self, min_periods: usize) -> PolarsResult<Series>;
 }
 
 /// utility
@@ -214,6 +566,18 @@ fn check_input(window_size: usize, min_periods: usize) -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "rolling_window")]
+fn check_weights(window_size: usize, weights: Option<&[f64]>) -> PolarsResult<()> {
+    if let Some(weights) = weights {
+        polars_ensure!(
+            weights.len() == window_size,
+            ComputeError: "`weights` length ({}) must equal `window_size` ({})",
+            weights.len(), window_size
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "rolling_window")]
 #[allow(clippy::type_complexity)]
 fn rolling_agg<T>(
@@ -238,6 +602,18 @@ fn rolling_agg<T>(
             Option<&TimeZone>,
         ) -> PolarsResult<ArrayRef>,
     >,
+    rolling_agg_fn_dynamic_nulls: Option<
+        &dyn Fn(
+            &PrimitiveArray<T::Native>,
+            usize,
+            Duration,
+            Duration,
+            &[i64],
+            ClosedWindow,
+            TimeUnit,
+            Option<&TimeZone>,
+        ) -> PolarsResult<ArrayRef>,
+    >,
 ) -> PolarsResult<Series>
 where
     T: PolarsNumericType,
@@ -245,13 +621,21 @@ where
     if ca.is_empty() {
         return Ok(Series::new_empty(ca.name(), ca.dtype()));
     }
+    if options.window_size.parsed_int
+        && ca.chunks().len() > 1
+        && chunks_hold_windows(ca, options.window_size.nanoseconds() as usize)
+    {
+        return rolling_agg_chunked(ca, options, rolling_agg_fn, rolling_agg_fn_nulls);
+    }
     let ca = ca.rechunk();
+    let respect_current_null = options.respect_current_null;
 
     let arr = ca.downcast_iter().next().unwrap();
     // "5i" is a window size of 5, e.g. fixed
-    let arr = if options.window_size.parsed_int {
+    let out = if options.window_size.parsed_int {
         let options: RollingOptionsFixedWindow = options.into();
         check_input(options.window_size, options.min_periods)?;
+        check_weights(options.window_size, options.weights.as_deref())?;
 
         Ok(match ca.null_count() {
             0 => rolling_agg_fn(
@@ -270,21 +654,735 @@ where
             ),
         })
     } else {
-        if arr.null_count() > 0 {
-            panic!("'rolling by' not yet supported for series with null values, consider using 'groupby_rolling'")
-        }
-        let values = arr.values().as_slice();
+        polars_ensure!(
+            !options.require_full_window,
+            InvalidOperation: "'require_full_window' is not supported for 'rolling by' windows, \
+            as they don't have a fixed number of values"
+        );
         let duration = options.window_size;
         let tu = options.tu.unwrap();
         let by = options.by.unwrap();
         let closed_window = options.closed_window.expect("closed window  must be set");
-        let mut offset = duration;
-        offset.negative = true;
-        let func = rolling_agg_fn_dynamic.expect(
-            "'rolling by' not yet supported for this expression, consider using 'groupby_rolling'",
-        );
+        let offset = by_window_offset(duration, options.center)?;
 
-        func(values, duration, offset, by, closed_window, tu, options.tz)
+        if arr.null_count() > 0 {
+            // nulls are skipped and count as missing values for `min_periods`
+            let func = rolling_agg_fn_dynamic_nulls.ok_or_else(|| {
+                polars_err!(
+                    InvalidOperation: "'rolling by' is not yet supported for this expression on \
+                    series with null values, consider using 'groupby_rolling'"
+                )
+            })?;
+            func(
+                arr,
+                options.min_periods,
+                duration,
+                offset,
+                by,
+                closed_window,
+                tu,
+                options.tz,
+            )
+        } else {
+            let func = rolling_agg_fn_dynamic.ok_or_else(|| {
+                polars_err!(
+                    InvalidOperation: "'rolling by' is not yet supported for this expression, \
+                    consider using 'groupby_rolling'"
+                )
+            })?;
+            func(
+                arr.values().as_slice(),
+                duration,
+                offset,
+                by,
+                closed_window,
+                tu,
+                options.tz,
+            )
+        }
     }?;
-    Series::try_from((ca.name(), arr))
+    let out = if respect_current_null {
+        mask_current_nulls(out, arr)
+    } else {
+        out
+    };
+    Series::try_from((ca.name(), out))
+}
+
+/// The offset from every timestamp to the start of its window, for a window defined by a time
+/// column. The window either ends at the timestamp, or is centered on it with `center`.
+/// `closed_window` applies to both ends of a centered window just like it does otherwise, so a
+/// value exactly half the duration away is only part of the window if that end is closed.
+#[cfg(feature = "rolling_window")]
+fn by_window_offset(window_size: Duration, center: bool) -> PolarsResult<Duration> {
+    if center {
+        // a month doesn't have a fixed length that can be split in two
+        polars_ensure!(
+            window_size.months() == 0,
+            InvalidOperation: "'center' is not supported for 'rolling by' windows of months"
+        );
+        Ok(Duration::from_nsecs(-window_size.duration_ns() / 2))
+    } else {
+        let mut offset = window_size;
+        offset.negative = true;
+        Ok(offset)
+    }
+}
+
+/// Whether every chunk of `ca` is at least as long as a window, so that the overlap that
+/// [`rolling_agg_chunked`] copies along with a chunk is at most about twice the chunk itself.
+/// Otherwise, rechunking once is cheaper.
+#[cfg(feature = "rolling_window")]
+fn chunks_hold_windows<T: PolarsDataType>(ca: &ChunkedArray<T>, window_size: usize) -> bool {
+    ca.chunks()
+        .iter()
+        .all(|chunk| chunk.is_empty() || chunk.len() >= window_size)
+}
+
+/// Apply a fixed-window aggregation chunk by chunk, so that the chunks don't have to be
+/// rechunked into a single array first. Every chunk is copied together with the values of its
+/// neighbours that share a window with it and aggregated on its own. Every value is still
+/// copied (and the overlap more than once), but only one chunk at a time, which lowers the
+/// peak memory.
+///
+/// This should only be used if [`chunks_hold_windows`], as otherwise a chunk drags along many
+/// of its neighbours.
+#[cfg(feature = "rolling_window")]
+#[allow(clippy::type_complexity)]
+fn rolling_agg_chunked<T>(
+    ca: &ChunkedArray<T>,
+    options: RollingOptionsImpl,
+    rolling_agg_fn: &dyn Fn(&[T::Native], usize, usize, bool, Option<&[f64]>) -> ArrayRef,
+    rolling_agg_fn_nulls: &dyn Fn(
+        &PrimitiveArray<T::Native>,
+        usize,
+        usize,
+        bool,
+        Option<&[f64]>,
+    ) -> ArrayRef,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    check_weights(options.window_size, options.weights.as_deref())?;
+    // the number of values before (and after, if centered) a chunk
+    // that can be in the same window as one of its values
+    let before = options.window_size.saturating_sub(1);
+    let after = if options.center {
+        options.window_size
+    } else {
+        0
+    };
+
+    let len = ca.len();
+    let mut offset = 0;
+    let chunks = ca
+        .downcast_iter()
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| {
+            let start = offset.saturating_sub(before);
+            let end = std::cmp::min(len, offset + chunk.len() + after);
+            let part = ca.slice(start as i64, end - start).rechunk();
+            let arr = part.downcast_iter().next().unwrap();
+            let out = match arr.null_count() {
+                0 => rolling_agg_fn(
+                    arr.values().as_slice(),
+                    options.window_size,
+                    options.min_periods,
+                    options.center,
+                    options.weights.as_deref(),
+                ),
+                _ => rolling_agg_fn_nulls(
+                    arr,
+                    options.window_size,
+                    options.min_periods,
+                    options.center,
+                    options.weights.as_deref(),
+                ),
+            };
+            let out = out.sliced(offset - start, chunk.len());
+            offset += chunk.len();
+            if respect_current_null {
+                mask_current_nulls(out, chunk)
+            } else {
+                out
+            }
+        })
+        .collect::<Vec<_>>();
+    Series::try_from((ca.name(), chunks))
+}
+
+/// Set the output of a rolling aggregation to null wherever the input is null.
+#[cfg(feature = "rolling_window")]
+fn mask_current_nulls(out: ArrayRef, input: &dyn Array) -> ArrayRef {
+    let validity = combine_validities_and(out.validity(), input.validity());
+    out.with_validity(validity)
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_zero_crossings<T>(
+    ca: &ChunkedArray<T>,
+    policy: ZeroCrossingPolicy,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for 'rolling_zero_crossings', \
+        consider using 'groupby_rolling'"
+    );
+    if ca.is_empty() {
+        return Ok(Series::new_empty(ca.name(), &DataType::UInt32));
+    }
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    let ca = ca.rechunk();
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let out = match ca.null_count() {
+        0 => rolling::no_nulls::rolling_zero_crossings(
+            arr.values().as_slice(),
+            options.window_size,
+            options.min_periods,
+            options.center,
+            policy,
+        ),
+        _ => rolling::nulls::rolling_zero_crossings(
+            arr,
+            options.window_size,
+            options.min_periods,
+            options.center,
+            policy,
+        ),
+    };
+    let out = if respect_current_null {
+        mask_current_nulls(out, arr)
+    } else {
+        out
+    };
+    Series::try_from((ca.name(), out))
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_null_fraction<T>(
+    ca: &ChunkedArray<T>,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for 'rolling_null_fraction', \
+        consider using 'groupby_rolling'"
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_null_fraction'"
+    );
+    if ca.is_empty() {
+        return Ok(Series::new_empty(ca.name(), &DataType::Float64));
+    }
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    let ca = ca.rechunk();
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let out = rolling::nulls::rolling_null_fraction(
+        arr,
+        options.window_size,
+        options.min_periods,
+        options.center,
+    );
+    let out = if respect_current_null {
+        mask_current_nulls(out, arr)
+    } else {
+        out
+    };
+    Series::try_from((ca.name(), out))
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_mode<T>(ca: &ChunkedArray<T>, options: RollingOptionsImpl) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+    T::Native: IsFloat,
+{
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_mode'"
+    );
+    rolling_agg(
+        ca,
+        options,
+        &|values, window_size, min_periods, center, _| {
+            rolling::no_nulls::rolling_mode(values, window_size, min_periods, center)
+        },
+        &|arr, window_size, min_periods, center, _| {
+            rolling::nulls::rolling_mode(arr, window_size, min_periods, center)
+        },
+        None,
+        None,
+    )
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_rank<T>(
+    ca: &ChunkedArray<T>,
+    params: RollingRankParams,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+    T::Native: IsFloat,
+{
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_rank'"
+    );
+    let min_periods = options.min_periods;
+    rolling_agg(
+        ca,
+        options,
+        &|values, window_size, min_periods, center, _| {
+            rolling::no_nulls::rolling_rank(values, window_size, min_periods, center, params)
+        },
+        &|arr, window_size, min_periods, center, _| {
+            rolling::nulls::rolling_rank(arr, window_size, min_periods, center, params)
+        },
+        Some(&|values, period, offset, time, closed_window, tu, tz| {
+            rolling_kernels::no_nulls::rolling_rank(
+                values,
+                params,
+                min_periods,
+                period,
+                offset,
+                time,
+                closed_window,
+                tu,
+                tz,
+            )
+        }),
+        None,
+    )
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_apply_any<T>(
+    ca: &ChunkedArray<T>,
+    f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for 'rolling_apply'"
+    );
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    check_weights(options.window_size, options.weights.as_deref())?;
+    let s = Series::try_from((ca.name(), ca.chunks().clone()))?;
+    let (s, weights) = match &options.weights {
+        Some(weights) => (
+            s.cast(&DataType::Float64)?,
+            Some(Series::new("weights", weights)),
+        ),
+        None => (s, None),
+    };
+    // the closure isn't called for the windows masked by a null current value
+    let current_valid = respect_current_null.then(|| s.is_not_null().rechunk());
+
+    let len = s.len();
+    let window_size = options.window_size;
+    let values = (0..len)
+        .map(|idx| {
+            if let Some(current_valid) = &current_valid {
+                if current_valid.get(idx) != Some(true) {
+                    return Ok(AnyValue::Null);
+                }
+            }
+            let (start, end) = if options.center {
+                let right_window = (window_size + 1) / 2;
+                (
+                    idx.saturating_sub(window_size - right_window),
+                    std::cmp::min(len, idx + right_window),
+                )
+            } else {
+                (idx.saturating_sub(window_size - 1), idx + 1)
+            };
+            let window = s.slice(start as i64, end - start);
+            if window.len() - window.null_count() < options.min_periods {
+                return Ok(AnyValue::Null);
+            }
+            // like the weighted kernels, a window cut off at the edges takes the first weights
+            let window = match &weights {
+                Some(weights) => &window * &weights.slice(0, window.len()),
+                None => window,
+            };
+            f(&window)?.into_static()
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if values.iter().all(|av| matches!(av, AnyValue::Null)) {
+        Ok(Series::full_null(ca.name(), len, s.dtype()))
+    } else {
+        Series::from_any_values(ca.name(), &values, false)
+    }
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_arg_min_max<T>(
+    ca: &ChunkedArray<T>,
+    max: bool,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+    T::Native: IsFloat,
+{
+    let agg = if max {
+        "rolling_argmax"
+    } else {
+        "rolling_argmin"
+    };
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError:
+        "weights are not supported for '{}', as the extreme of weighted values is ambiguous",
+        agg
+    );
+    if ca.is_empty() {
+        return Ok(Series::new_empty(ca.name(), &DataType::UInt32));
+    }
+    let min_periods = options.min_periods;
+    rolling_agg(
+        ca,
+        options,
+        &|values, window_size, min_periods, center, _| {
+            if max {
+                rolling::no_nulls::rolling_argmax(values, window_size, min_periods, center)
+            } else {
+                rolling::no_nulls::rolling_argmin(values, window_size, min_periods, center)
+            }
+        },
+        &|arr, window_size, min_periods, center, _| {
+            if max {
+                rolling::nulls::rolling_argmax(arr, window_size, min_periods, center)
+            } else {
+                rolling::nulls::rolling_argmin(arr, window_size, min_periods, center)
+            }
+        },
+        Some(&|values, period, offset, time, closed_window, tu, tz| {
+            rolling_kernels::no_nulls::rolling_arg_min_max(
+                values,
+                max,
+                min_periods,
+                period,
+                offset,
+                time,
+                closed_window,
+                tu,
+                tz,
+            )
+        }),
+        None,
+    )
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_n_unique<T>(ca: &ChunkedArray<T>, options: RollingOptionsImpl) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+    T::Native: IsFloat,
+{
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_n_unique'"
+    );
+    if ca.is_empty() {
+        return Ok(Series::new_empty(ca.name(), &DataType::UInt32));
+    }
+    let min_periods = options.min_periods;
+    rolling_agg(
+        ca,
+        options,
+        &|values, window_size, min_periods, center, _| {
+            rolling::no_nulls::rolling_n_unique(values, window_size, min_periods, center)
+        },
+        &|arr, window_size, min_periods, center, _| {
+            rolling::nulls::rolling_n_unique(arr, window_size, min_periods, center)
+        },
+        Some(&|values, period, offset, time, closed_window, tu, tz| {
+            rolling_kernels::no_nulls::rolling_n_unique(
+                values,
+                min_periods,
+                period,
+                offset,
+                time,
+                closed_window,
+                tu,
+                tz,
+            )
+        }),
+        Some(
+            &|arr, min_periods, period, offset, time, closed_window, tu, tz| {
+                rolling_kernels::nulls::rolling_n_unique(
+                    arr,
+                    min_periods,
+                    period,
+                    offset,
+                    time,
+                    closed_window,
+                    tu,
+                    tz,
+                )
+            },
+        ),
+    )
+}
+
+/// Apply a fixed-window `Float64` kernel to `s`, which must be of type `Float64`.
+/// The kernels don't take weights, so an error is raised if they are set.
+/// `agg` is only used in the error messages.
+#[cfg(feature = "rolling_window")]
+fn rolling_agg_f64(
+    s: &Series,
+    agg: &str,
+    options: RollingOptionsImpl,
+    rolling_agg_fn: &dyn Fn(&[f64], usize, usize, bool) -> ArrayRef,
+    rolling_agg_fn_nulls: &dyn Fn(&PrimitiveArray<f64>, usize, usize, bool) -> ArrayRef,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for '{}', \
+        consider using 'groupby_rolling'", agg
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for '{}'", agg
+    );
+    if s.is_empty() {
+        return Ok(Series::new_empty(s.name(), &DataType::Float64));
+    }
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    let s = s.rechunk();
+    let ca = s.f64()?;
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let out = match ca.null_count() {
+        0 => rolling_agg_fn(
+            arr.values().as_slice(),
+            options.window_size,
+            options.min_periods,
+            options.center,
+        ),
+        _ => rolling_agg_fn_nulls(
+            arr,
+            options.window_size,
+            options.min_periods,
+            options.center,
+        ),
+    };
+    let out = if respect_current_null {
+        mask_current_nulls(out, arr)
+    } else {
+        out
+    };
+    Series::try_from((ca.name(), out))
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_sum_with_reset(
+    s: &Series,
+    reset: &dyn Fn(f64) -> bool,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series> {
+    rolling_agg_f64(
+        s,
+        "rolling_sum_with_reset",
+        options,
+        &|values, window_size, min_periods, center| {
+            rolling::no_nulls::rolling_sum_with_reset(
+                values,
+                window_size,
+                min_periods,
+                center,
+                reset,
+            )
+        },
+        &|arr, window_size, min_periods, center| {
+            rolling::nulls::rolling_sum_with_reset(arr, window_size, min_periods, center, reset)
+        },
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_cv(s: &Series, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    let ddof = options.ddof;
+    rolling_agg_f64(
+        s,
+        "rolling_cv",
+        options,
+        &|values, window_size, min_periods, center| {
+            rolling::no_nulls::rolling_cv(values, window_size, min_periods, center, ddof)
+        },
+        &|arr, window_size, min_periods, center| {
+            rolling::nulls::rolling_cv(arr, window_size, min_periods, center, ddof)
+        },
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_mad(s: &Series, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    rolling_agg_f64(
+        s,
+        "rolling_mad",
+        options,
+        &|values, window_size, min_periods, center| {
+            rolling::no_nulls::rolling_mad(values, window_size, min_periods, center, scale)
+        },
+        &|arr, window_size, min_periods, center| {
+            rolling::nulls::rolling_mad(arr, window_size, min_periods, center, scale)
+        },
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+fn rolling_gain_loss(s: &Series, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for 'rolling_gain_loss', \
+        consider using 'groupby_rolling'"
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_gain_loss'"
+    );
+    if s.is_empty() {
+        let fields = [
+            Series::new_empty("gains", &DataType::Float64),
+            Series::new_empty("losses", &DataType::Float64),
+        ];
+        return StructChunked::new(s.name(), &fields).map(|ca| ca.into_series());
+    }
+    let respect_current_null = options.respect_current_null;
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    let s = s.rechunk();
+    let ca = s.f64()?;
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let (gains, losses) = match ca.null_count() {
+        0 => rolling::no_nulls::rolling_gain_loss(
+            arr.values().as_slice(),
+            options.window_size,
+            options.min_periods,
+            options.center,
+        ),
+        _ => rolling::nulls::rolling_gain_loss(
+            arr,
+            options.window_size,
+            options.min_periods,
+            options.center,
+        ),
+    };
+    let (gains, losses) = if respect_current_null {
+        (
+            mask_current_nulls(gains, arr),
+            mask_current_nulls(losses, arr),
+        )
+    } else {
+        (gains, losses)
+    };
+    let fields = [
+        Series::try_from(("gains", gains))?,
+        Series::try_from(("losses", losses))?,
+    ];
+    StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_autocorr(s: &Series, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    rolling_agg_f64(
+        s,
+        "rolling_autocorr",
+        options,
+        &|values, window_size, min_periods, center| {
+            rolling::no_nulls::rolling_autocorr(values, window_size, min_periods, center, lag)
+        },
+        &|arr, window_size, min_periods, center| {
+            rolling::nulls::rolling_autocorr(arr, window_size, min_periods, center, lag)
+        },
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_dot(s: &Series, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series> {
+    let window_size = options.window_size;
+    polars_ensure!(
+        !window_size.parsed_int || window_size.nanoseconds() as usize == reference.len(),
+        ComputeError: "the length of 'reference' ({}) should be equal to the window size ({})",
+        reference.len(), window_size.nanoseconds()
+    );
+    rolling_agg_f64(
+        s,
+        "rolling_dot",
+        options,
+        &|values, _, min_periods, center| {
+            rolling::no_nulls::rolling_dot(values, min_periods, center, reference)
+        },
+        &|arr, _, min_periods, center| {
+            rolling::nulls::rolling_dot(arr, min_periods, center, reference)
+        },
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_rs(s: &Series, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    rolling_agg_f64(
+        s,
+        "rolling_rs",
+        options,
+        &rolling::no_nulls::rolling_rs,
+        &rolling::nulls::rolling_rs,
+    )
+}
+
+/// `s` must be of type `Float64`.
+#[cfg(feature = "rolling_window")]
+fn rolling_kurtosis(
+    s: &Series,
+    params: RollingKurtosisParams,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series> {
+    rolling_agg_f64(
+        s,
+        "rolling_kurtosis",
+        options,
+        &|values, window_size, min_periods, center| {
+            rolling::no_nulls::rolling_kurtosis(values, window_size, min_periods, center, params)
+        },
+        &|arr, window_size, min_periods, center| {
+            rolling::nulls::rolling_kurtosis(arr, window_size, min_periods, center, params)
+        },
+    )
 }
@@ -1,4 +1,5 @@
 pub(super) mod no_nulls;
+pub(super) mod nulls;
 use std::fmt::Debug;
 use std::ops::{AddAssign, Div, Mul, Sub, SubAssign};
 
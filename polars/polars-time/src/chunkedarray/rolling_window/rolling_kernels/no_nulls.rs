@@ -1,6 +1,7 @@
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 use polars_arrow::kernels::rolling::no_nulls::{self, RollingAggWindowNoNulls};
+use polars_arrow::kernels::rolling::{window_arg_min_max, window_rank, NUniqueWindow};
 use polars_core::export::num;
 
 use super::*;
@@ -15,6 +16,23 @@ where
     Agg: RollingAggWindowNoNulls<'a, T>,
     O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
     T: Debug + IsFloat + NativeType,
+{
+    rolling_apply_agg_window_with(values, offsets, Agg::new)
+}
+
+/// Like [`rolling_apply_agg_window`], but the window is created by `new_window`,
+/// e.g. to set the parameters of the aggregation.
+pub(crate) fn rolling_apply_agg_window_with<'a, Agg, T, O, N>(
+    values: &'a [T],
+    offsets: O,
+    new_window: N,
+) -> PolarsResult<ArrayRef>
+where
+    // items (offset, len) -> so offsets are offset, offset + len
+    Agg: RollingAggWindowNoNulls<'a, T>,
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    N: FnOnce(&'a [T], usize, usize) -> Agg,
+    T: Debug + IsFloat + NativeType,
 {
     if values.is_empty() {
         let out: Vec<T> = vec![];
@@ -25,7 +43,7 @@ where
         )));
     }
     // start with a dummy index, will be overwritten on first iteration.
-    let mut agg_window = Agg::new(values, 0, 0);
+    let mut agg_window = new_window(values, 0, 0);
 
     let out = offsets
         .map(|result| {
@@ -154,8 +172,10 @@ where
     rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _>(values, offset_iter)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn rolling_var<T>(
     values: &[T],
+    ddof: u8,
     period: Duration,
     offset: Duration,
     time: &[i64],
@@ -178,11 +198,15 @@ where
         ),
         _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
     };
-    rolling_apply_agg_window::<no_nulls::VarWindow<_>, _, _>(values, offset_iter)
+    rolling_apply_agg_window_with(values, offset_iter, |values, start, end| {
+        no_nulls::VarWindow::new(values, start, end).with_ddof(ddof)
+    })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn rolling_std<T>(
     values: &[T],
+    ddof: u8,
     period: Duration,
     offset: Duration,
     time: &[i64],
@@ -215,5 +239,232 @@ where
         ),
         _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
     };
-    rolling_apply_agg_window::<no_nulls::StdWindow<_>, _, _>(values, offset_iter)
+    rolling_apply_agg_window_with(values, offset_iter, |values, start, end| {
+        no_nulls::StdWindow::new(values, start, end).with_ddof(ddof)
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_quantile<T>(
+    values: &[T],
+    quantile: f64,
+    interpolation: QuantileInterpolOptions,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + std::iter::Sum<T>,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    // the windows are resolved first, as the quantile kernel doesn't take fallible offsets
+    let offsets = offset_iter.collect::<PolarsResult<Vec<_>>>()?;
+    Ok(no_nulls::rolling_quantile_by_iter(
+        values,
+        quantile,
+        interpolation,
+        offsets.iter().copied(),
+    ))
+}
+
+pub(crate) fn rolling_first<T>(
+    values: &[T],
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<no_nulls::FirstWindow<_>, _, _>(values, offset_iter)
+}
+
+pub(crate) fn rolling_last<T>(
+    values: &[T],
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<no_nulls::LastWindow<_>, _, _>(values, offset_iter)
+}
+
+/// Rank the value of every row among the values of its window. A row that isn't part of its
+/// own window, e.g. with a left-closed window, is ranked as if it were.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_rank<T>(
+    values: &[T],
+    params: RollingRankParams,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    let out = offset_iter
+        .enumerate()
+        .map(|(i, window)| {
+            let (start, len) = window?;
+            let (start, end) = (start as usize, (start + len) as usize);
+            if end - start < min_periods {
+                return Ok(None);
+            }
+            let window = &values[start..end];
+            let rank = if (start..end).contains(&i) {
+                window_rank(values[i], window.iter().copied(), params)
+            } else {
+                window_rank(
+                    values[i],
+                    window.iter().copied().chain(std::iter::once(values[i])),
+                    params,
+                )
+            };
+            Ok(Some(rank))
+        })
+        .collect::<PolarsResult<PrimitiveArray<f64>>>()?;
+    Ok(Box::new(out))
+}
+
+/// Find the offset from the first row of every window of its largest (`max`) or smallest
+/// value. On ties the first one is taken.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_arg_min_max<T>(
+    values: &[T],
+    max: bool,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    let out = offset_iter
+        .map(|window| {
+            let (start, len) = window?;
+            let (start, end) = (start as usize, (start + len) as usize);
+            if end - start < min_periods {
+                return Ok(None);
+            }
+            let window = values[start..end].iter().copied().enumerate();
+            Ok(window_arg_min_max(window, max).map(|offset| offset as u32))
+        })
+        .collect::<PolarsResult<PrimitiveArray<u32>>>()?;
+    Ok(Box::new(out))
+}
+
+/// Count the distinct values of every window.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_n_unique<T>(
+    values: &[T],
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    let mut window = NUniqueWindow::new(values, None);
+    let out = offset_iter
+        .map(|offsets| {
+            let (start, len) = offsets?;
+            window.update(start as usize, (start + len) as usize);
+            Ok((window.n_valid() >= min_periods).then(|| window.n_unique() as u32))
+        })
+        .collect::<PolarsResult<PrimitiveArray<u32>>>()?;
+    Ok(Box::new(out))
 }
@@ -0,0 +1,453 @@
+use std::ops::Add;
+
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+use polars_arrow::export::arrow::bitmap::Bitmap;
+use polars_arrow::kernels::rolling::nulls::{self, RollingAggWindowNulls};
+use polars_arrow::kernels::rolling::NUniqueWindow;
+use polars_core::export::num;
+
+use super::*;
+
+// Use an aggregation window that maintains the state. Null values are skipped and a
+// window with fewer than `min_periods` valid values is null.
+pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
+    values: &'a [T],
+    validity: &'a Bitmap,
+    min_periods: usize,
+    offsets: O,
+) -> PolarsResult<ArrayRef>
+where
+    // items (offset, len) -> so offsets are offset, offset + len
+    Agg: RollingAggWindowNulls<'a, T>,
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    T: Debug + IsFloat + NativeType,
+{
+    rolling_apply_agg_window_with(
+        values,
+        validity,
+        min_periods,
+        offsets,
+        // safety: an empty window is in bounds
+        |values, validity| unsafe { Agg::new(values, validity, 0, 0) },
+    )
+}
+
+/// Like [`rolling_apply_agg_window`], but the (empty) window is created by `new_window`,
+/// e.g. to set the parameters of the aggregation.
+pub(crate) fn rolling_apply_agg_window_with<'a, Agg, T, O, N>(
+    values: &'a [T],
+    validity: &'a Bitmap,
+    min_periods: usize,
+    offsets: O,
+    new_window: N,
+) -> PolarsResult<ArrayRef>
+where
+    // items (offset, len) -> so offsets are offset, offset + len
+    Agg: RollingAggWindowNulls<'a, T>,
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    N: FnOnce(&'a [T], &'a Bitmap) -> Agg,
+    T: Debug + IsFloat + NativeType,
+{
+    if values.is_empty() {
+        let out: Vec<T> = vec![];
+        return Ok(Box::new(PrimitiveArray::new(
+            T::PRIMITIVE.into(),
+            out.into(),
+            None,
+        )));
+    }
+    // start with a dummy index, will be overwritten on first iteration.
+    let mut agg_window = new_window(values, validity);
+
+    let out = offsets
+        .map(|result| {
+            result.map(|(start, len)| {
+                let end = start + len;
+
+                if start == end {
+                    None
+                } else {
+                    // safety:
+                    // we are in bounds
+                    let agg = unsafe { agg_window.update(start as usize, end as usize) };
+                    agg.filter(|_| agg_window.is_valid(min_periods))
+                }
+            })
+        })
+        .collect::<PolarsResult<PrimitiveArray<T>>>()?;
+
+    Ok(Box::new(out))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_min<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::MinWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_max<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::MaxWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_sum<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::SumWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_mean<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType
+        + IsFloat
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + NumCast
+        + Div<Output = T>,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::MeanWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_var<T>(
+    arr: &PrimitiveArray<T>,
+    ddof: u8,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window_with(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+        // safety: an empty window is in bounds
+        |values, validity| unsafe { nulls::VarWindow::new(values, validity, 0, 0).with_ddof(ddof) },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_std<T>(
+    arr: &PrimitiveArray<T>,
+    ddof: u8,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType
+        + Float
+        + IsFloat
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + One
+        + Sub<Output = T>
+        + num::pow::Pow<T, Output = T>,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window_with(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+        // safety: an empty window is in bounds
+        |values, validity| unsafe { nulls::StdWindow::new(values, validity, 0, 0).with_ddof(ddof) },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_quantile<T>(
+    arr: &PrimitiveArray<T>,
+    quantile: f64,
+    interpolation: QuantileInterpolOptions,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + std::iter::Sum<T> + AddAssign,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    // the windows are resolved first, as the quantile kernel doesn't take fallible offsets
+    let offsets = offset_iter.collect::<PolarsResult<Vec<_>>>()?;
+    Ok(nulls::rolling_quantile_by_iter(
+        arr.values(),
+        arr.validity().unwrap(),
+        quantile,
+        interpolation,
+        min_periods,
+        offsets.iter().copied(),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_first<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::FirstWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_last<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    rolling_apply_agg_window::<nulls::LastWindow<_>, _, _>(
+        arr.values(),
+        arr.validity().unwrap(),
+        min_periods,
+        offset_iter,
+    )
+}
+
+/// Count the distinct non-null values of every window.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_n_unique<T>(
+    arr: &PrimitiveArray<T>,
+    min_periods: usize,
+    period: Duration,
+    offset: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    let mut window = NUniqueWindow::new(arr.values(), arr.validity());
+    let out = offset_iter
+        .map(|offsets| {
+            let (start, len) = offsets?;
+            window.update(start as usize, (start + len) as usize);
+            Ok((window.n_valid() >= min_periods).then(|| window.n_unique() as u32))
+        })
+        .collect::<PolarsResult<PrimitiveArray<u32>>>()?;
+    Ok(Box::new(out))
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// The RSI given the (average) gains and losses of a window.
+fn rsi(gain: f64, loss: f64) -> Option<f64> {
+    match (gain == 0.0, loss == 0.0) {
+        // no price changes at all
+        (true, true) => None,
+        (_, true) => Some(100.0),
+        _ => Some(100.0 - 100.0 / (1.0 + gain / loss)),
+    }
+}
+
+fn rolling_rsi_wilder(changes: &Float64Chunked, window_size: usize) -> Float64Chunked {
+    let n = window_size as f64;
+    let mut count = 0;
+    let mut avg_gain = 0.0;
+    let mut avg_loss = 0.0;
+    changes
+        .into_iter()
+        .map(|change| {
+            let change = change?;
+            let gain = change.max(0.0);
+            let loss = (-change).max(0.0);
+            if count < window_size {
+                // seed with the simple average of the first window
+                count += 1;
+                avg_gain += gain / n;
+                avg_loss += loss / n;
+                if count < window_size {
+                    return None;
+                }
+            } else {
+                avg_gain = (avg_gain * (n - 1.0) + gain) / n;
+                avg_loss = (avg_loss * (n - 1.0) + loss) / n;
+            }
+            rsi(avg_gain, avg_loss)
+        })
+        .collect()
+}
+
+/// Compute the Relative Strength Index (RSI) of the values in `s`, e.g. prices, over windows of
+/// `options.window_size` consecutive changes. The output is `Float64`, between 0 and 100.
+///
+/// * `wilder` - smooth the average gains and losses with Wilder's method, i.e. a moving average
+///   with `alpha = 1 / window_size` that is seeded with the simple average of the first full
+///   window. `min_periods` and `center` are ignored and null changes are skipped. Otherwise,
+///   the gains and losses are summed over every window.
+///
+/// A window with only gains has an RSI of 100 and one with only losses an RSI of 0. Windows
+/// without any change and under-filled windows are null. Weights aren't supported.
+pub fn rolling_rsi(s: &Series, wilder: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.by.is_none(),
+        InvalidOperation: "'rolling by' is not yet supported for 'rolling_rsi', \
+        consider using 'groupby_rolling'"
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        ComputeError: "weights are not supported for 'rolling_rsi'"
+    );
+    let options: RollingOptionsFixedWindow = options.into();
+    check_input(options.window_size, options.min_periods)?;
+    if s.is_empty() {
+        return Ok(Series::new_empty(s.name(), &DataType::Float64));
+    }
+    let s = s.cast(&DataType::Float64)?;
+    // the first change is always null
+    let changes = (&s - &s.shift(1)).rechunk();
+    let changes = changes.f64()?;
+
+    let mut out = if wilder {
+        rolling_rsi_wilder(changes, options.window_size)
+    } else {
+        let arr = changes.downcast_iter().next().unwrap();
+        let (gains, losses) = rolling::nulls::rolling_gain_loss(
+            arr,
+            options.window_size,
+            options.min_periods,
+            options.center,
+        );
+        let gains = gains
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        let losses = losses
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        gains
+            .iter()
+            .zip(losses.iter())
+            .map(|(gain, loss)| match (gain, loss) {
+                (Some(gain), Some(loss)) => rsi(*gain, *loss),
+                _ => None,
+            })
+            .collect()
+    };
+    out.rename(s.name());
+    Ok(out.into_series())
+}
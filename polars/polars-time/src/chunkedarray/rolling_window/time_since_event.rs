@@ -0,0 +1,71 @@
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+
+use super::*;
+
+/// Compute, for every row, the time elapsed since the most recent `true` value of `events`
+/// within its window, e.g. as a recency feature. Null events count as `false`.
+///
+/// This is only supported for windows determined by a time column, so `options.by` must be set.
+/// The output is a `Duration` in the time unit of the `by` column, and is null for rows without
+/// any event in their window.
+pub fn rolling_time_since_event(
+    events: &BooleanChunked,
+    options: RollingOptionsImpl,
+) -> PolarsResult<Series> {
+    let (Some(time), Some(tu)) = (options.by, options.tu) else {
+        polars_bail!(
+            InvalidOperation: "'rolling_time_since_event' is only supported with a 'by' column"
+        )
+    };
+    polars_ensure!(
+        time.len() == events.len(),
+        ComputeError: "'events' and 'by' should have the same length, got {} and {}",
+        events.len(), time.len()
+    );
+    let closed_window = options.closed_window.expect("closed window  must be set");
+    let period = options.window_size;
+    let mut offset = period;
+    offset.negative = true;
+
+    // the index of the last event at or before every row
+    let mut last = None;
+    let last_event = events
+        .into_iter()
+        .enumerate()
+        .map(|(i, event)| {
+            if event.unwrap_or(false) {
+                last = Some(i);
+            }
+            last
+        })
+        .collect::<Vec<_>>();
+
+    let offset_iter = match options.tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => groupby_values_iter(
+            period,
+            offset,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => groupby_values_iter(period, offset, time, closed_window, tu, None),
+    };
+    let mut out = offset_iter
+        .enumerate()
+        .map(|(i, window)| {
+            let (start, len) = window?;
+            let (start, end) = (start as usize, (start + len) as usize);
+            if start == end {
+                return Ok(None);
+            }
+            Ok(last_event[end - 1]
+                .filter(|&j| j >= start)
+                .map(|j| time[i] - time[j]))
+        })
+        .collect::<PolarsResult<Int64Chunked>>()?;
+    out.rename(events.name());
+    Ok(out.into_duration(tu).into_series())
+}
@@ -466,14 +466,14 @@ pub(crate) fn to_datetime(
                 Pattern::DatetimeYMDZ => infer.coerce_utf8(ca).datetime().map(|ca| {
                     let mut ca = ca.clone();
                     ca.set_time_unit(tu);
-                    ca.replace_time_zone(Some("UTC"), None)
+                    ca.replace_time_zone(Some("UTC"), None, NonExistent::Raise)
                 })?,
                 _ => infer.coerce_utf8(ca).datetime().map(|ca| {
                     let mut ca = ca.clone();
                     ca.set_time_unit(tu);
                     match tz {
                         #[cfg(feature = "timezones")]
-                        Some(tz) => ca.replace_time_zone(Some(tz), None),
+                        Some(tz) => ca.replace_time_zone(Some(tz), None, NonExistent::Raise),
                         _ => Ok(ca),
                     }
                 })?,
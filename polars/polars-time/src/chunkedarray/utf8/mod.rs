@@ -297,7 +297,10 @@ pub trait Utf8Methods: AsUtf8 {
         ca.rename(utf8_ca.name());
         match tz {
             #[cfg(feature = "timezones")]
-            Some(tz) => ca.into_datetime(tu, None).replace_time_zone(Some(tz), None),
+            Some(tz) => {
+                ca.into_datetime(tu, None)
+                    .replace_time_zone(Some(tz), None, NonExistent::Raise)
+            }
             _ => Ok(ca.into_datetime(tu, None)),
         }
     }
@@ -509,7 +512,10 @@ pub trait Utf8Methods: AsUtf8 {
             ca.rename(utf8_ca.name());
             match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => ca.into_datetime(tu, None).replace_time_zone(Some(tz), None),
+                Some(tz) => {
+                    ca.into_datetime(tu, None)
+                        .replace_time_zone(Some(tz), None, NonExistent::Raise)
+                }
                 _ => Ok(ca.into_datetime(tu, None)),
             }
         }
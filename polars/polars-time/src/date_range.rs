@@ -1,4 +1,4 @@
-use chrono::{Datelike, NaiveDateTime, NaiveTime};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 use polars_core::chunked_array::temporal::time_to_time64ns;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -6,12 +6,29 @@ use polars_core::series::IsSorted;
 use crate::prelude::*;
 #[cfg(feature = "timezones")]
 use crate::utils::localize_timestamp;
+use crate::windows::calendar::{is_leap_year, last_day_of_month};
 
 pub fn in_nanoseconds_window(ndt: &NaiveDateTime) -> bool {
     // ~584 year around 1970
     !(ndt.year() > 2554 || ndt.year() < 1386)
 }
 
+/// A range goes up from `start` to `stop`, or down if the interval is negative.
+fn validate_range_bounds(start: i64, stop: i64, every: &Duration) -> PolarsResult<()> {
+    if every.negative {
+        polars_ensure!(
+            start >= stop,
+            ComputeError: "'interval' cannot be negative if 'start' is less than 'stop'"
+        );
+    } else {
+        polars_ensure!(
+            start <= stop,
+            ComputeError: "'start' cannot be greater than 'stop' if 'interval' is positive"
+        );
+    }
+    Ok(())
+}
+
 #[doc(hidden)]
 pub fn date_range_impl(
     name: &str,
@@ -22,38 +39,49 @@ pub fn date_range_impl(
     tu: TimeUnit,
     _tz: Option<&TimeZone>,
 ) -> PolarsResult<DatetimeChunked> {
-    if start > stop {
-        polars_bail!(ComputeError: "'start' cannot be greater than 'stop'")
-    }
-    if every.negative {
-        polars_bail!(ComputeError: "'interval' cannot be negative")
-    }
-    let mut out = match _tz {
+    validate_range_bounds(start, stop, &every)?;
+    let (values, tz) = match _tz {
         #[cfg(feature = "timezones")]
         Some(tz) => match tz.parse::<chrono_tz::Tz>() {
             Ok(tz) => {
                 let start = localize_timestamp(start, tu, tz);
                 let stop = localize_timestamp(stop, tu, tz);
-                Int64Chunked::new_vec(
-                    name,
+                (
                     temporal_range_vec(start?, stop?, every, closed, tu, Some(&tz))?,
+                    _tz.cloned(),
                 )
-                .into_datetime(tu, _tz.cloned())
             }
             Err(_) => polars_bail!(ComputeError: "unable to parse time zone: '{}'", tz),
         },
-        _ => Int64Chunked::new_vec(
-            name,
+        _ => (
             temporal_range_vec(start, stop, every, closed, tu, None)?,
-        )
-        .into_datetime(tu, None),
+            None,
+        ),
     };
 
-    out.set_sorted_flag(IsSorted::Ascending);
+    // the wall-clock time of a tz-aware range isn't monotonic around DST
+    // transitions, so the flag is based on the (UTC) instants
+    let sorted = instant_order(&values);
+    let mut out = Int64Chunked::new_vec(name, values).into_datetime(tu, tz);
+    out.set_sorted_flag(sorted);
     Ok(out)
 }
 
+fn instant_order(values: &[i64]) -> IsSorted {
+    if values.windows(2).all(|w| w[0] <= w[1]) {
+        IsSorted::Ascending
+    } else if values.windows(2).all(|w| w[0] >= w[1]) {
+        IsSorted::Descending
+    } else {
+        IsSorted::Not
+    }
+}
+
 /// Create a [`DatetimeChunked`] from a given `start` and `stop` date and a given `every` interval.
+///
+/// Calendar intervals, such as `"1mo"`, `"1q"` or `"1y"`, step from `start` on: a range of
+/// quarters only lands on the starts of quarters if `start` is one, and otherwise keeps the day
+/// of the month (and time) of `start`.
 pub fn date_range(
     name: &str,
     start: NaiveDateTime,
@@ -74,6 +102,71 @@ pub fn date_range(
     date_range_impl(name, start, stop, every, closed, tu, tz.as_ref())
 }
 
+/// Create a [`DatetimeChunked`] like [`date_range`], checking that it has exactly `periods`
+/// values, so that an inconsistent `stop`, `every` or `periods` is caught early.
+#[allow(clippy::too_many_arguments)]
+pub fn date_range_with_periods(
+    name: &str,
+    start: NaiveDateTime,
+    stop: NaiveDateTime,
+    every: Duration,
+    periods: usize,
+    closed: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<TimeZone>,
+) -> PolarsResult<DatetimeChunked> {
+    let out = date_range(name, start, stop, every, closed, tu, tz)?;
+    polars_ensure!(
+        out.len() == periods,
+        ComputeError: "the range from '{}' to '{}' has {} value(s) at the given interval, \
+        but {} periods were given",
+        start, stop, out.len(), periods
+    );
+    Ok(out)
+}
+
+/// The dates from `start` to `end`, both included, which `in_month` picks from every month
+/// in between.
+fn month_boundary_range<F>(name: &str, start: NaiveDate, end: NaiveDate, in_month: F) -> DateChunked
+where
+    F: Fn(i32, u32) -> NaiveDate,
+{
+    let (mut year, mut month) = (start.year(), start.month());
+    let mut dates = vec![];
+    while (year, month) <= (end.year(), end.month()) {
+        let date = in_month(year, month);
+        if start <= date && date <= end {
+            dates.push(date);
+        }
+        (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+    }
+    let mut out = DateChunked::from_naive_date(name, dates);
+    out.set_sorted_flag(IsSorted::Ascending);
+    out
+}
+
+/// Create a [`DateChunked`] of the first day of every month from `start` to `end`, both included.
+pub fn month_start_range(name: &str, start: NaiveDate, end: NaiveDate) -> DateChunked {
+    month_boundary_range(name, start, end, |year, month| {
+        NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+    })
+}
+
+/// Create a [`DateChunked`] of the last day of every month from `start` to `end`, both included.
+pub fn month_end_range(name: &str, start: NaiveDate, end: NaiveDate) -> DateChunked {
+    month_boundary_range(name, start, end, |year, month| {
+        let mut last_day = last_day_of_month(month as i32);
+        if month == 2 && is_leap_year(year) {
+            last_day += 1;
+        }
+        NaiveDate::from_ymd_opt(year, month, last_day).unwrap()
+    })
+}
+
 #[doc(hidden)]
 pub fn time_range_impl(
     name: &str,
@@ -82,23 +175,18 @@ pub fn time_range_impl(
     every: Duration,
     closed: ClosedWindow,
 ) -> PolarsResult<TimeChunked> {
-    if start > stop {
-        polars_bail!(ComputeError: "'start' cannot be greater than 'stop'")
-    }
-    if every.negative {
-        polars_bail!(ComputeError: "'interval' cannot be negative")
-    }
-    let mut out = Int64Chunked::new_vec(
-        name,
-        temporal_range_vec(start, stop, every, closed, TimeUnit::Nanoseconds, None)?,
-    )
-    .into_time();
-
-    out.set_sorted_flag(IsSorted::Ascending);
+    validate_range_bounds(start, stop, &every)?;
+    let values = temporal_range_vec(start, stop, every, closed, TimeUnit::Nanoseconds, None)?;
+    let sorted = instant_order(&values);
+    let mut out = Int64Chunked::new_vec(name, values).into_time();
+    out.set_sorted_flag(sorted);
     Ok(out)
 }
 
 /// Create a [`TimeChunked`] from a given `start` and `stop` date and a given `every` interval.
+///
+/// Like a date range, a zero-length range (`start == stop`) is the single point `start` unless
+/// `closed` is `None`, so that time ranges and date ranges agree on their bounds.
 pub fn time_range(
     name: &str,
     start: NaiveTime,
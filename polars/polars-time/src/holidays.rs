@@ -0,0 +1,227 @@
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, Weekday};
+use polars_core::prelude::*;
+
+/// How a holiday that falls on a weekend is observed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// The holiday is observed on the day itself.
+    #[default]
+    None,
+    /// A Saturday holiday is observed on the Friday before and a Sunday holiday on the
+    /// Monday after, e.g. US federal holidays.
+    NearestWeekday,
+    /// A weekend holiday is observed on the Monday after.
+    NextMonday,
+}
+
+/// A rule describing on which day a holiday falls in a given year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    /// The same date every year, e.g. Christmas on December 25.
+    Fixed {
+        month: u32,
+        day: u32,
+        observance: Observance,
+    },
+    /// The `n`-th `weekday` of a month, e.g. Thanksgiving on the fourth Thursday of November.
+    /// A negative `n` counts from the end of the month, so `-1` is the last one.
+    NthWeekday { month: u32, weekday: Weekday, n: i8 },
+}
+
+fn observe(date: NaiveDate, observance: Observance) -> NaiveDate {
+    let shift = match (observance, date.weekday()) {
+        (Observance::NearestWeekday, Weekday::Sat) => -1,
+        (Observance::NearestWeekday, Weekday::Sun) => 1,
+        (Observance::NextMonday, Weekday::Sat) => 2,
+        (Observance::NextMonday, Weekday::Sun) => 1,
+        _ => 0,
+    };
+    date + ChronoDuration::days(shift)
+}
+
+impl HolidayRule {
+    fn validate(&self) -> PolarsResult<()> {
+        match *self {
+            HolidayRule::Fixed { month, day, .. } => polars_ensure!(
+                (1..=12).contains(&month) && (1..=31).contains(&day),
+                ComputeError: "invalid fixed holiday: month {} and day {}", month, day
+            ),
+            HolidayRule::NthWeekday { month, n, .. } => polars_ensure!(
+                (1..=12).contains(&month) && n != 0 && (-5..=5).contains(&n),
+                ComputeError: "invalid nth weekday holiday: month {} and n {}", month, n
+            ),
+        }
+        Ok(())
+    }
+
+    /// The (observed) date of the holiday in `year`, if it exists in that year,
+    /// e.g. February 29 or the fifth Monday of a month might not.
+    pub fn date(&self, year: i32) -> PolarsResult<Option<NaiveDate>> {
+        self.validate()?;
+        Ok(match *self {
+            HolidayRule::Fixed {
+                month,
+                day,
+                observance,
+            } => NaiveDate::from_ymd_opt(year, month, day).map(|date| observe(date, observance)),
+            HolidayRule::NthWeekday { month, weekday, n } if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                let next_month = if month == 12 {
+                    NaiveDate::from_ymd_opt(year + 1, 1, 1)
+                } else {
+                    NaiveDate::from_ymd_opt(year, month + 1, 1)
+                };
+                let last_day = next_month.unwrap().pred_opt().unwrap();
+                let days_back = (7 + last_day.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                let date = last_day
+                    - ChronoDuration::days(days_back as i64)
+                    - ChronoDuration::weeks((-n - 1) as i64);
+                (date.month() == month).then_some(date)
+            }
+        })
+    }
+}
+
+/// Expand holiday `rules` for all `years` into a sorted list of unique dates, as days since the
+/// Unix epoch. This is the holiday list that the business day functions expect.
+pub fn holidays_from_rules(
+    rules: &[HolidayRule],
+    years: RangeInclusive<i32>,
+) -> PolarsResult<Vec<i32>> {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let mut holidays = Vec::with_capacity(rules.len() * years.clone().count());
+    for year in years {
+        for rule in rules {
+            if let Some(date) = rule.date(year)? {
+                holidays.push((date - epoch).num_days() as i32);
+            }
+        }
+    }
+    holidays.sort_unstable();
+    holidays.dedup();
+    Ok(holidays)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn us_federal_holidays() -> Vec<HolidayRule> {
+        use HolidayRule::*;
+        let observance = Observance::NearestWeekday;
+        vec![
+            // New Year's Day
+            Fixed {
+                month: 1,
+                day: 1,
+                observance,
+            },
+            // Martin Luther King Jr. Day
+            NthWeekday {
+                month: 1,
+                weekday: Weekday::Mon,
+                n: 3,
+            },
+            // Memorial Day
+            NthWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+                n: -1,
+            },
+            // Independence Day
+            Fixed {
+                month: 7,
+                day: 4,
+                observance,
+            },
+            // Labor Day
+            NthWeekday {
+                month: 9,
+                weekday: Weekday::Mon,
+                n: 1,
+            },
+            // Thanksgiving
+            NthWeekday {
+                month: 11,
+                weekday: Weekday::Thu,
+                n: 4,
+            },
+            // Christmas
+            Fixed {
+                month: 12,
+                day: 25,
+                observance,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_us_federal_holidays() {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let out = holidays_from_rules(&us_federal_holidays(), 2022..=2022)
+            .unwrap()
+            .into_iter()
+            .map(|days| epoch + ChronoDuration::days(days as i64))
+            .collect::<Vec<_>>();
+        let expected = [
+            // New Year's Day 2022 is a Saturday, observed on Friday 2021-12-31
+            (2021, 12, 31),
+            (2022, 1, 17),
+            (2022, 5, 30),
+            // Independence Day is a Monday
+            (2022, 7, 4),
+            (2022, 9, 5),
+            (2022, 11, 24),
+            // Christmas is a Sunday, observed on Monday
+            (2022, 12, 26),
+        ]
+        .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        // there is no February 29 in 2023 and no fifth Monday in February 2022
+        let rules = [
+            HolidayRule::Fixed {
+                month: 2,
+                day: 29,
+                observance: Observance::None,
+            },
+            HolidayRule::NthWeekday {
+                month: 2,
+                weekday: Weekday::Mon,
+                n: 5,
+            },
+        ];
+        assert_eq!(rules[0].date(2023).unwrap(), None);
+        assert_eq!(
+            rules[0].date(2024).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+        assert_eq!(rules[1].date(2022).unwrap(), None);
+        // the last Monday of February 2022
+        let rule = HolidayRule::NthWeekday {
+            month: 2,
+            weekday: Weekday::Mon,
+            n: -1,
+        };
+        assert_eq!(
+            rule.date(2022).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 2, 28)
+        );
+
+        let rule = HolidayRule::Fixed {
+            month: 13,
+            day: 1,
+            observance: Observance::None,
+        };
+        assert!(holidays_from_rules(&[rule], 2022..=2022).is_err());
+    }
+}
@@ -1,10 +1,16 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod business_day_count;
+mod ceil;
 pub mod chunkedarray;
 mod date_range;
 mod groupby;
+mod holidays;
 mod month_end;
 mod month_start;
+mod normalize;
+mod offset_by;
 pub mod prelude;
+mod resample;
 mod round;
 pub mod series;
 mod truncate;
@@ -12,15 +18,27 @@ mod upsample;
 mod utils;
 mod windows;
 
+pub use business_day_count::*;
+pub use ceil::*;
 pub use date_range::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use groupby::dynamic::*;
+pub use holidays::*;
 pub use month_end::*;
 pub use month_start::*;
+pub use normalize::*;
+pub use offset_by::*;
+pub use resample::*;
 pub use round::*;
 pub use truncate::*;
 pub use upsample::*;
+#[cfg(feature = "dtype-datetime")]
+pub use utils::parse_datetime_time_zone;
+#[cfg(feature = "timezones")]
+pub use utils::{list_timezones, parse_time_zone};
+pub use windows::calendar::diff_in_periods;
 pub use windows::calendar::temporal_range as temporal_range_vec;
+pub use windows::calendar::temporal_range_periods;
 pub use windows::duration::Duration;
 pub use windows::groupby::ClosedWindow;
 pub use windows::window::Window;
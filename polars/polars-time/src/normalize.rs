@@ -0,0 +1,85 @@
+#[cfg(feature = "timezones")]
+use chrono::{LocalResult, NaiveDateTime, Offset, TimeZone};
+use polars_arrow::time_zone::Tz;
+use polars_core::prelude::*;
+#[cfg(feature = "timezones")]
+use polars_core::utils::arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+
+use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::utils::unlocalize_datetime;
+
+// the first instant of the local day starting at `midnight`
+#[cfg(feature = "timezones")]
+fn localize_midnight(midnight: NaiveDateTime, tz: &Tz) -> NaiveDateTime {
+    match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(dt) => dt.naive_utc(),
+        LocalResult::Ambiguous(earliest, _) => earliest.naive_utc(),
+        // the clocks are set forward at midnight, so the day starts
+        // at the end of the gap, which is midnight in the offset before it
+        LocalResult::None => {
+            let before = midnight - chrono::Duration::days(1);
+            let offset = tz.offset_from_utc_datetime(&before).fix();
+            midnight - chrono::Duration::seconds(offset.local_minus_utc() as i64)
+        }
+    }
+}
+
+pub trait PolarsNormalize {
+    /// Floor every value to midnight of its day, keeping the time zone.
+    ///
+    /// In `tz`, this is the first instant of the local day: the earliest midnight
+    /// if midnight is ambiguous, and the end of the gap if midnight doesn't exist.
+    fn normalize(&self, tz: Option<&Tz>) -> PolarsResult<Self>
+    where
+        Self: Sized;
+}
+
+impl PolarsNormalize for DatetimeChunked {
+    fn normalize(&self, tz: Option<&Tz>) -> PolarsResult<Self> {
+        let every = Duration::parse("1d");
+        let w = Window::new(every, every, Duration::parse("0ns"));
+
+        let truncate = match self.time_unit() {
+            TimeUnit::Nanoseconds => Window::truncate_ns,
+            TimeUnit::Microseconds => Window::truncate_us,
+            TimeUnit::Milliseconds => Window::truncate_ms,
+        };
+
+        let out = match tz {
+            // truncate the wall-clock time, and only then go back to UTC,
+            // as local midnight may not exist or may be ambiguous
+            #[cfg(feature = "timezones")]
+            Some(tz) => {
+                let timestamp_to_datetime: fn(i64) -> NaiveDateTime;
+                let datetime_to_timestamp: fn(NaiveDateTime) -> i64;
+                match self.time_unit() {
+                    TimeUnit::Nanoseconds => {
+                        timestamp_to_datetime = timestamp_ns_to_datetime;
+                        datetime_to_timestamp = datetime_to_timestamp_ns;
+                    }
+                    TimeUnit::Microseconds => {
+                        timestamp_to_datetime = timestamp_us_to_datetime;
+                        datetime_to_timestamp = datetime_to_timestamp_us;
+                    }
+                    TimeUnit::Milliseconds => {
+                        timestamp_to_datetime = timestamp_ms_to_datetime;
+                        datetime_to_timestamp = datetime_to_timestamp_ms;
+                    }
+                };
+                self.0.try_apply(|t| {
+                    let local = unlocalize_datetime(timestamp_to_datetime(t), tz);
+                    let midnight = truncate(&w, datetime_to_timestamp(local), None)?;
+                    Ok(datetime_to_timestamp(localize_midnight(
+                        timestamp_to_datetime(midnight),
+                        tz,
+                    )))
+                })?
+            }
+            _ => self.0.try_apply(|t| truncate(&w, t, None))?,
+        };
+        Ok(out.into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
+}
@@ -0,0 +1,201 @@
+use polars_arrow::time_zone::Tz;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::windows::duration::broadcast_duration;
+
+/// What to do when a calendar offset lands on a day that doesn't exist
+/// in the target month, e.g. `2022-01-31` offset by `1mo`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Roll {
+    /// Raise an error (unless the offset string ends with `"_saturating"`).
+    #[default]
+    Raise,
+    /// Roll back to the last day of the target month, e.g. `2022-02-28`.
+    Backward,
+    /// Roll forward to the first day of the following month, e.g. `2022-03-01`.
+    Forward,
+}
+
+type AddFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+fn add_fn(tu: TimeUnit) -> AddFn {
+    match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    }
+}
+
+fn add_with_roll(
+    tu: TimeUnit,
+    offset: &Duration,
+    t: i64,
+    tz: Option<&Tz>,
+    roll: Roll,
+) -> PolarsResult<i64> {
+    let add = add_fn(tu);
+    // only a day that doesn't exist is rolled, other errors (such as a
+    // non-existent time in `tz`) are raised either way
+    if roll == Roll::Raise || offset.saturating || !offset.lands_past_month_end(t, tz, tu) {
+        return add(offset, t, tz);
+    }
+    let mut saturating = *offset;
+    saturating.saturating = true;
+    let t = add(&saturating, t, tz)?;
+    match roll {
+        Roll::Backward => Ok(t),
+        // the saturated result is the last day of the month, so the
+        // next day is the first day of the following month
+        _ => add(&Duration::from_days(1), t, tz),
+    }
+}
+
+#[cfg(feature = "timezones")]
+fn parse_offset_tz(tz: Option<&str>) -> PolarsResult<Option<Tz>> {
+    tz.map(parse_time_zone).transpose()
+}
+
+#[cfg(not(feature = "timezones"))]
+fn parse_offset_tz(tz: Option<&str>) -> PolarsResult<Option<Tz>> {
+    match tz {
+        Some(_) => polars_bail!(
+            ComputeError: "cannot offset a tz-aware datetime without the 'timezones' feature"
+        ),
+        None => Ok(None),
+    }
+}
+
+/// Offset every value of a Date/Datetime Series by the matching duration string in `offsets`.
+///
+/// `offsets` is either of length 1, in which case it is broadcast, or of the same length as `s`.
+/// See [`Duration::parse`] for the format of the duration strings.
+///
+/// * `offset_tz` - the time zone in which calendar arithmetic is done. Defaults to the time zone
+///   of `s`. The output keeps the time zone of `s`.
+/// * `roll` - what to do if a month offset lands on a day that doesn't exist.
+/// * `strict` - raise on invalid duration strings or results that don't exist. If `false`,
+///   these result in a null instead.
+pub fn offset_by(
+    s: &Series,
+    offsets: &Utf8Chunked,
+    offset_tz: Option<&str>,
+    roll: Roll,
+    strict: bool,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Date => {
+            polars_ensure!(
+                offset_tz.is_none(),
+                ComputeError: "'offset_tz' is not supported for Date, cast to Datetime first"
+            );
+            let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            offset_by(&s, offsets, None, roll, strict)?.cast(&DataType::Date)
+        }
+        DataType::Datetime(tu, tz) => {
+            let ca = s.datetime().unwrap();
+            let offset_tz = parse_offset_tz(offset_tz.or(tz.as_deref()))?;
+
+            polars_ensure!(
+                offsets.len() == 1 || offsets.len() == ca.len(),
+                ComputeError: "expected 'offsets' of length 1 or {}, got {}",
+                ca.len(), offsets.len()
+            );
+            let mut cache = DurationCache::new(ca.len());
+            let mut out =
+                ca.0.into_iter()
+                    .enumerate()
+                    .map(|(idx, t)| match (t, broadcast_duration(offsets, idx)) {
+                        (Some(t), Some(offset)) => {
+                            let out = cache.try_parse(offset).and_then(|offset| {
+                                add_with_roll(*tu, &offset, t, offset_tz.as_ref(), roll)
+                            });
+                            match out {
+                                Ok(v) => Ok(Some(v)),
+                                Err(err) if strict => Err(err),
+                                Err(_) => Ok(None),
+                            }
+                        }
+                        _ => Ok(None),
+                    })
+                    .collect::<PolarsResult<Int64Chunked>>()?;
+            out.rename(ca.name());
+            Ok(out.into_datetime(*tu, tz.clone()).into_series())
+        }
+        dt => polars_bail!(opq = offset_by, got = dt, expected = "date/datetime"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn datetimes(values: &[(i32, u32, u32, u32)], tz: Option<&str>) -> Series {
+        let values = values
+            .iter()
+            .map(|(y, m, d, h)| {
+                NaiveDate::from_ymd_opt(*y, *m, *d)
+                    .unwrap()
+                    .and_hms_opt(*h, 0, 0)
+                    .unwrap()
+                    .timestamp_millis()
+            })
+            .collect::<Vec<_>>();
+        Int64Chunked::new("dt", &values)
+            .into_datetime(TimeUnit::Milliseconds, tz.map(|tz| tz.to_string()))
+            .into_series()
+    }
+
+    #[test]
+    fn test_offset_by_per_row_and_roll() {
+        let s = datetimes(&[(2022, 1, 31, 0), (2022, 1, 15, 0)], None);
+        let offsets = Utf8Chunked::new("offsets", &["1mo", "1d"]);
+
+        let out = offset_by(&s, &offsets, None, Roll::Forward, true).unwrap();
+        let expected = datetimes(&[(2022, 3, 1, 0), (2022, 1, 16, 0)], None);
+        assert!(out.series_equal_missing(&expected));
+
+        let out = offset_by(&s, &offsets, None, Roll::Backward, true).unwrap();
+        let expected = datetimes(&[(2022, 2, 28, 0), (2022, 1, 16, 0)], None);
+        assert!(out.series_equal_missing(&expected));
+
+        assert!(offset_by(&s, &offsets, None, Roll::Raise, true).is_err());
+        let out = offset_by(&s, &offsets, None, Roll::Raise, false).unwrap();
+        assert_eq!(out.null_count(), 1);
+        assert!(out.is_null().get(0).unwrap());
+
+        // invalid duration strings
+        let offsets = Utf8Chunked::new("offsets", &["1d", "foo"]);
+        assert!(offset_by(&s, &offsets, None, Roll::Raise, true).is_err());
+        let out = offset_by(&s, &offsets, None, Roll::Raise, false).unwrap();
+        assert!(out.is_null().get(1).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_offset_by_tz_override() {
+        // Europe/London switches to BST on 2022-03-27
+        let s = datetimes(&[(2022, 1, 31, 12), (2022, 3, 26, 12)], Some("UTC"));
+        let offsets = Utf8Chunked::new("offsets", &["1mo", "1d"]);
+
+        let out = offset_by(&s, &offsets, None, Roll::Forward, true).unwrap();
+        let expected = datetimes(&[(2022, 3, 1, 12), (2022, 3, 27, 12)], Some("UTC"));
+        assert!(out.series_equal_missing(&expected));
+
+        let out = offset_by(&s, &offsets, Some("Europe/London"), Roll::Forward, true).unwrap();
+        let expected = datetimes(&[(2022, 3, 1, 12), (2022, 3, 27, 11)], Some("UTC"));
+        assert!(out.series_equal_missing(&expected));
+
+        // 01:00 doesn't exist in London on 2022-03-27, which isn't rolled
+        // like a day that doesn't exist
+        let s = datetimes(&[(2022, 2, 27, 1)], Some("Europe/London"));
+        let offsets = Utf8Chunked::new("offsets", &["1mo"]);
+        let err = offset_by(&s, &offsets, None, Roll::Forward, true).unwrap_err();
+        assert!(err.to_string().contains("non-existent"));
+    }
+}
@@ -0,0 +1,145 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How to fill the points of a grid that don't coincide with a point of the index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridFill {
+    /// Take the value of the last index point before the grid point.
+    Forward,
+    /// Take the value of the first index point after the grid point.
+    Backward,
+    /// Leave the grid point null.
+    #[default]
+    Null,
+}
+
+fn sorted_timestamps<'a>(s: &'a Series, name: &str) -> PolarsResult<&'a [i64]> {
+    polars_ensure!(
+        s.null_count() == 0,
+        ComputeError: "'{}' of 'resample_to_grid' cannot contain nulls", name
+    );
+    let ts = s.i64()?.cont_slice()?;
+    polars_ensure!(
+        ts.windows(2).all(|w| w[0] <= w[1]),
+        ComputeError: "'{}' of 'resample_to_grid' should be sorted in ascending order", name
+    );
+    Ok(ts)
+}
+
+/// Align `values`, indexed by the times in `index`, onto the times in `grid`, e.g. a
+/// [`date_range`](crate::date_range).
+///
+/// Grid points that coincide with a point of `index` take its value (the last one, if the index
+/// has duplicates). The other grid points are filled according to `fill`. Both `index` and `grid`
+/// must be sorted in ascending order and have the same Date/Datetime data type.
+pub fn resample_to_grid(
+    index: &Series,
+    values: &Series,
+    grid: &Series,
+    fill: GridFill,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        index.len() == values.len(),
+        ComputeError: "'index' and 'values' should have the same length, got {} and {}",
+        index.len(), values.len()
+    );
+    match index.dtype() {
+        DataType::Date | DataType::Datetime(_, _) => {}
+        dt => polars_bail!(opq = resample_to_grid, got = dt, expected = "date/datetime"),
+    }
+    polars_ensure!(
+        index.dtype() == grid.dtype(),
+        ComputeError: "'index' and 'grid' should have the same data type, got {} and {}",
+        index.dtype(), grid.dtype()
+    );
+    let index = index.to_physical_repr().cast(&DataType::Int64)?.rechunk();
+    let grid = grid.to_physical_repr().cast(&DataType::Int64)?.rechunk();
+    let index = sorted_timestamps(&index, "index")?;
+    let grid = sorted_timestamps(&grid, "grid")?;
+
+    // both sides are sorted, so `i` only moves forward: it is the number
+    // of index points at or before the current grid point
+    let mut i = 0;
+    let idx: IdxCa = grid
+        .iter()
+        .map(|&g| {
+            while i < index.len() && index[i] <= g {
+                i += 1;
+            }
+            let last_before = i.checked_sub(1);
+            let exact = last_before.filter(|&j| index[j] == g);
+            let take = match fill {
+                _ if exact.is_some() => exact,
+                GridFill::Forward => last_before,
+                GridFill::Backward => (i < index.len()).then_some(i),
+                GridFill::Null => None,
+            };
+            take.map(|j| j as IdxSize)
+        })
+        .collect();
+    let mut out = values.take(&idx)?;
+    out.rename(values.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+    use crate::prelude::*;
+
+    fn datetime(d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 1, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_resample_to_grid() {
+        let index = [
+            datetime(1, 0),
+            datetime(2, 6),
+            datetime(4, 0),
+            datetime(4, 18),
+        ]
+        .into_iter()
+        .map(|dt| dt.timestamp_millis())
+        .collect::<Vec<_>>();
+        let index = Int64Chunked::new("time", &index)
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        let values = Int32Chunked::new("values", &[Some(0), Some(1), None, Some(3)]).into_series();
+        let grid = date_range(
+            "grid",
+            datetime(1, 0),
+            datetime(5, 0),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap()
+        .into_series();
+
+        for (fill, expected) in [
+            (
+                GridFill::Forward,
+                [Some(0), Some(0), Some(1), None, Some(3)],
+            ),
+            (GridFill::Backward, [Some(0), Some(1), None, None, None]),
+            (GridFill::Null, [Some(0), None, None, None, None]),
+        ] {
+            let out = resample_to_grid(&index, &values, &grid, fill).unwrap();
+            assert_eq!(out.name(), "values");
+            assert_eq!(Vec::from(out.i32().unwrap()), &expected, "fill: {fill:?}");
+        }
+
+        // the index should be sorted
+        let index = index.reverse();
+        assert!(resample_to_grid(&index, &values, &grid, GridFill::Forward).is_err());
+    }
+}
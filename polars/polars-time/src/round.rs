@@ -3,16 +3,78 @@ use polars_arrow::time_zone::Tz;
 use polars_core::prelude::*;
 
 use crate::prelude::*;
+#[cfg(feature = "dtype-duration")]
+use crate::truncate::fixed_duration_in_unit;
+#[cfg(feature = "dtype-time")]
+use crate::truncate::time_every_ns;
+#[cfg(feature = "dtype-time")]
+use crate::windows::calendar::NS_DAY;
+
+/// How [`PolarsRound::round`] breaks ties, i.e. rounds values exactly halfway between two
+/// multiples of `every`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round to the even multiple of `every`, counted from the Unix epoch (banker's rounding).
+    HalfToEven,
+    /// Round away from zero, i.e. from the Unix epoch for a Date or Datetime.
+    HalfAwayFromZero,
+    /// Round up, to the later multiple of `every`.
+    #[default]
+    HalfUp,
+    /// Round down, to the earlier multiple of `every`.
+    HalfDown,
+}
+
+impl RoundMode {
+    /// Pick `floor` or `ceil` for a value exactly halfway between them.
+    pub(crate) fn break_tie(self, floor: i64, ceil: i64, floor_is_even: bool) -> i64 {
+        match self {
+            RoundMode::HalfToEven if floor_is_even => floor,
+            RoundMode::HalfToEven => ceil,
+            // the value is negative if the midpoint of `floor` and `ceil` is
+            RoundMode::HalfAwayFromZero if floor + ceil < 0 => floor,
+            RoundMode::HalfAwayFromZero | RoundMode::HalfUp => ceil,
+            RoundMode::HalfDown => floor,
+        }
+    }
+}
+
+/// Round `t` to the nearest multiple of the fixed duration `every`, breaking ties by `mode`.
+#[cfg(any(feature = "dtype-duration", feature = "dtype-time"))]
+fn round_fixed(t: i64, every: i64, mode: RoundMode) -> i64 {
+    let floor = t - t.rem_euclid(every);
+    let ceil = floor + every;
+    match (2 * (t - floor)).cmp(&every) {
+        std::cmp::Ordering::Less => floor,
+        std::cmp::Ordering::Greater => ceil,
+        std::cmp::Ordering::Equal => mode.break_tie(floor, ceil, floor.div_euclid(every) % 2 == 0),
+    }
+}
 
 pub trait PolarsRound {
-    fn round(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self>
+    /// Round every value to the nearest multiple of `every`, shifted by `offset`, e.g. to the
+    /// nearest half past the hour with an `every` of `1h` and an `offset` of `30m`.
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        mode: RoundMode,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 #[cfg(feature = "dtype-datetime")]
 impl PolarsRound for DatetimeChunked {
-    fn round(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        mode: RoundMode,
+    ) -> PolarsResult<Self> {
+        validate_every(&every)?;
         let w = Window::new(every, every, offset);
 
         let func = match self.time_unit() {
@@ -21,20 +83,68 @@ impl PolarsRound for DatetimeChunked {
             TimeUnit::Milliseconds => Window::round_ms,
         };
         Ok(self
-            .try_apply(|t| func(&w, t, tz))?
+            .try_apply(|t| func(&w, t, tz, mode))?
             .into_datetime(self.time_unit(), self.time_zone().clone()))
     }
 }
 
 #[cfg(feature = "dtype-date")]
 impl PolarsRound for DateChunked {
-    fn round(&self, every: Duration, offset: Duration, _tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        mode: RoundMode,
+    ) -> PolarsResult<Self> {
+        validate_every(&every)?;
         let w = Window::new(every, every, offset);
         Ok(self
             .try_apply(|t| {
                 const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
-                Ok((w.round_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32)
+                Ok((w.round_ms(MSECS_IN_DAY * t as i64, None, mode)? / MSECS_IN_DAY) as i32)
             })?
             .into_date())
     }
 }
+
+#[cfg(feature = "dtype-duration")]
+impl PolarsRound for DurationChunked {
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        mode: RoundMode,
+    ) -> PolarsResult<Self> {
+        validate_every(&every)?;
+        let tu = self.time_unit();
+        let every_v = fixed_duration_in_unit(&every, tu, "every")?;
+        polars_ensure!(
+            every_v > 0,
+            ComputeError: "cannot round a Duration by an 'every' that is finer than its \
+            time unit '{}'", tu.to_ascii()
+        );
+        let offset = fixed_duration_in_unit(&offset, tu, "offset")?;
+        Ok(self
+            .apply(|t| round_fixed(t - offset, every_v, mode) + offset)
+            .into_duration(tu))
+    }
+}
+
+/// A time rounded up to midnight wraps around to 00:00, e.g. 23:58 rounded to the hour is 00:00.
+#[cfg(feature = "dtype-time")]
+impl PolarsRound for TimeChunked {
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        mode: RoundMode,
+    ) -> PolarsResult<Self> {
+        let every_ns = time_every_ns(&every, &offset, "round")?;
+        Ok(self
+            .apply(|t| round_fixed(t, every_ns, mode) % NS_DAY)
+            .into_time())
+    }
+}
@@ -64,6 +64,171 @@ pub trait SeriesOpsTime {
     fn rolling_std(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
         invalid_operation!(self)
     }
+
+    /// Count the zero crossings in every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_zero_crossings(
+        &self,
+        _policy: ZeroCrossingPolicy,
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a rolling sum that resets whenever `reset` returns `true` to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_sum_with_reset(
+        &self,
+        _reset: &dyn Fn(f64) -> bool,
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a rolling coefficient of variation to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_cv(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a rolling median absolute deviation to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mad(&self, _scale: bool, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Compute the rolling sums of the gains and losses of a Series.
+    #[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+    fn rolling_gain_loss(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Compute the rolling autocorrelation at `lag` of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_autocorr(&self, _lag: usize, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a rolling range (`max - min`) to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_range(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Compute the fraction of null values in every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_null_fraction(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Compute the dot product of every window with a fixed `reference` window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_dot(
+        &self,
+        _reference: &[f64],
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Compute the rolling rescaled range (R/S) statistic.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rs(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a rolling kurtosis to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_kurtosis(
+        &self,
+        _params: RollingKurtosisParams,
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Take the first (oldest) valid value of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_first(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Take the last (newest) valid value of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_last(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Take the most frequent value of every window, breaking ties by the smallest value.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mode(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Rank the value of every row among the values of its window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(
+        &self,
+        _params: RollingRankParams,
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply a custom reduction to every window of a Series, see [`RollingAgg::rolling_apply`].
+    #[cfg(feature = "rolling_window")]
+    fn rolling_map(
+        &self,
+        _f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        _options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Find the offset of the largest value of every window from the start of the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmax(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Find the offset of the smallest value of every window from the start of the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmin(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Count the distinct values of every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, _options: RollingOptionsImpl) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+
+    /// Apply an expanding mean to a Series, i.e. the mean of all values up to and including
+    /// the current one.
+    #[cfg(feature = "rolling_window")]
+    fn expanding_mean(&self, _min_periods: usize) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+    /// Apply an expanding sum to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn expanding_sum(&self, _min_periods: usize) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+    /// Apply an expanding min to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn expanding_min(&self, _min_periods: usize) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+    /// Apply an expanding max to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn expanding_max(&self, _min_periods: usize) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
+    /// Apply an expanding std_dev to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn expanding_std(&self, _min_periods: usize) -> PolarsResult<Series> {
+        invalid_operation!(self)
+    }
 }
 
 impl SeriesOpsTime for Series {
@@ -115,4 +280,159 @@ impl SeriesOpsTime for Series {
     fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         self.to_ops().rolling_std(options)
     }
+
+    /// Count the zero crossings in every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_zero_crossings(
+        &self,
+        policy: ZeroCrossingPolicy,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        self.to_ops().rolling_zero_crossings(policy, options)
+    }
+
+    /// Apply a rolling sum that resets whenever `reset` returns `true` to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_sum_with_reset(
+        &self,
+        reset: &dyn Fn(f64) -> bool,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        self.to_ops().rolling_sum_with_reset(reset, options)
+    }
+
+    /// Apply a rolling coefficient of variation to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_cv(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_cv(options)
+    }
+
+    /// Apply a rolling median absolute deviation to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mad(&self, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_mad(scale, options)
+    }
+
+    /// Compute the rolling sums of the gains and losses of a Series.
+    #[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+    fn rolling_gain_loss(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_gain_loss(options)
+    }
+
+    /// Compute the rolling autocorrelation at `lag` of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_autocorr(&self, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_autocorr(lag, options)
+    }
+
+    /// Apply a rolling range (`max - min`) to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_range(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_range(options)
+    }
+
+    /// Compute the fraction of null values in every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_null_fraction(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_null_fraction(options)
+    }
+
+    /// Compute the dot product of every window with a fixed `reference` window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_dot(&self, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_dot(reference, options)
+    }
+
+    /// Compute the rolling rescaled range (R/S) statistic.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rs(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_rs(options)
+    }
+
+    /// Apply a rolling kurtosis to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_kurtosis(
+        &self,
+        params: RollingKurtosisParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        self.to_ops().rolling_kurtosis(params, options)
+    }
+
+    /// Take the first (oldest) valid value of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_first(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_first(options)
+    }
+
+    /// Take the last (newest) valid value of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_last(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_last(options)
+    }
+
+    /// Take the most frequent value of every window, breaking ties by the smallest value.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mode(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_mode(options)
+    }
+
+    /// Rank the value of every row among the values of its window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(
+        &self,
+        params: RollingRankParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        self.to_ops().rolling_rank(params, options)
+    }
+
+    /// Apply a custom reduction to every window of a Series, see [`RollingAgg::rolling_apply`].
+    #[cfg(feature = "rolling_window")]
+    fn rolling_map(
+        &self,
+        f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        self.to_ops().rolling_map(f, options)
+    }
+
+    /// Find the offset of the largest value of every window from the start of the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmax(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_argmax(options)
+    }
+
+    /// Find the offset of the smallest value of every window from the start of the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmin(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_argmin(options)
+    }
+
+    /// Count the distinct values of every window of a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        self.to_ops().rolling_n_unique(options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_mean(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.to_ops().expanding_mean(min_periods)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn expanding_sum(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.to_ops().expanding_sum(min_periods)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn expanding_min(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.to_ops().expanding_min(min_periods)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn expanding_max(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.to_ops().expanding_max(min_periods)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn expanding_std(&self, min_periods: usize) -> PolarsResult<Series> {
+        self.to_ops().expanding_std(min_periods)
+    }
 }
@@ -52,4 +52,144 @@ where
     fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         RollingAgg::rolling_std(self, options)
     }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_zero_crossings(
+        &self,
+        policy: ZeroCrossingPolicy,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        RollingAgg::rolling_zero_crossings(self, policy, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_sum_with_reset(
+        &self,
+        reset: &dyn Fn(f64) -> bool,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        RollingAgg::rolling_sum_with_reset(self, reset, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_cv(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_cv(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mad(&self, scale: bool, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_mad(self, scale, options)
+    }
+
+    #[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+    fn rolling_gain_loss(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_gain_loss(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_autocorr(&self, lag: usize, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_autocorr(self, lag, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_range(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_range(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_null_fraction(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_null_fraction(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_dot(&self, reference: &[f64], options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_dot(self, reference, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rs(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_rs(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_kurtosis(
+        &self,
+        params: RollingKurtosisParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        RollingAgg::rolling_kurtosis(self, params, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_first(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_first(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_last(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_last(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mode(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_mode(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(
+        &self,
+        params: RollingRankParams,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        RollingAgg::rolling_rank(self, params, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_map(
+        &self,
+        f: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>>,
+        options: RollingOptionsImpl,
+    ) -> PolarsResult<Series> {
+        RollingAgg::rolling_apply(self, f, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmax(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_argmax(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_argmin(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_argmin(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_n_unique(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_mean(&self, min_periods: usize) -> PolarsResult<Series> {
+        RollingAgg::expanding_mean(self, min_periods)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_sum(&self, min_periods: usize) -> PolarsResult<Series> {
+        RollingAgg::expanding_sum(self, min_periods)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_min(&self, min_periods: usize) -> PolarsResult<Series> {
+        RollingAgg::expanding_min(self, min_periods)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_max(&self, min_periods: usize) -> PolarsResult<Series> {
+        RollingAgg::expanding_max(self, min_periods)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn expanding_std(&self, min_periods: usize) -> PolarsResult<Series> {
+        RollingAgg::expanding_std(self, min_periods)
+    }
 }
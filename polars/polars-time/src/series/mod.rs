@@ -3,12 +3,18 @@ mod implementations;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use polars_arrow::time_zone::Tz;
 use polars_core::prelude::*;
 use polars_core::utils::Wrap;
 pub use SeriesOpsTime;
 
 pub use self::_trait::*;
 use crate::chunkedarray::*;
+#[cfg(feature = "dtype-datetime")]
+use crate::parse_datetime_time_zone;
+#[cfg(feature = "timezones")]
+use crate::utils::unlocalize_timestamp;
+use crate::{Duration, PolarsRound, PolarsTruncate, RoundMode};
 
 type SeriesOpsRef = Arc<dyn SeriesOpsTime>;
 
@@ -340,6 +346,131 @@ pub trait TemporalMethods: AsSeries {
                 .map(|s| s.datetime().unwrap().deref().clone())
         }
     }
+
+    /// Truncate a Date, Datetime or Duration Series by `every`, dispatching to the
+    /// [`PolarsTruncate`] implementation of its dtype.
+    ///
+    /// `tz` is the time zone a Datetime is truncated in. If `None`, the time zone of the
+    /// Series is used, if any. It is ignored for Date and Duration.
+    fn truncate_temporal(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => Ok(s.date()?.truncate(every, offset, None, None)?.into_series()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => {
+                let ca = s.datetime()?;
+                let own_tz = match tz {
+                    Some(_) => None,
+                    None => parse_datetime_time_zone(ca)?,
+                };
+                Ok(ca
+                    .truncate(every, offset, tz.or(own_tz.as_ref()), None)?
+                    .into_series())
+            }
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => Ok(s
+                .duration()?
+                .truncate(every, offset, None, None)?
+                .into_series()),
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => Ok(s.time()?.truncate(every, offset, None, None)?.into_series()),
+            dt => polars_bail!(
+                opq = truncate,
+                got = dt,
+                expected = "date/datetime/duration/time"
+            ),
+        }
+    }
+
+    /// The wall-clock time of day of a Datetime or Time Series, as a Duration since midnight.
+    ///
+    /// A tz-aware Datetime uses its wall-clock time, so on the day clocks spring forward,
+    /// 03:30 is 3h30m after midnight even though only 2h30m elapsed.
+    #[cfg(feature = "dtype-duration")]
+    fn time_since_midnight(&self) -> PolarsResult<DurationChunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, _) => {
+                let ca = s.datetime()?;
+                let tu = *tu;
+                let units_per_day = match tu {
+                    TimeUnit::Nanoseconds => 86_400_000_000_000,
+                    TimeUnit::Microseconds => 86_400_000_000,
+                    TimeUnit::Milliseconds => 86_400_000,
+                };
+                let out = match parse_datetime_time_zone(ca)? {
+                    #[cfg(feature = "timezones")]
+                    Some(tz) => {
+                        ca.0.apply(|t| unlocalize_timestamp(t, tu, tz).rem_euclid(units_per_day))
+                    }
+                    _ => ca.0.apply(|t| t.rem_euclid(units_per_day)),
+                };
+                Ok(out.into_duration(tu))
+            }
+            // a Time is the number of nanoseconds since midnight
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => Ok(s.time()?.0.clone().into_duration(TimeUnit::Nanoseconds)),
+            dt => polars_bail!(
+                opq = time_since_midnight,
+                got = dt,
+                expected = "datetime/time"
+            ),
+        }
+    }
+
+    /// Round a Date, Datetime or Duration Series to `every`, dispatching to the
+    /// [`PolarsRound`] implementation of its dtype.
+    ///
+    /// `tz` is the time zone a Datetime is rounded in. If `None`, the time zone of the
+    /// Series is used, if any. It is ignored for Date and Duration.
+    fn round_temporal(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => Ok(s
+                .date()?
+                .round(every, offset, None, RoundMode::default())?
+                .into_series()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => {
+                let ca = s.datetime()?;
+                let own_tz = match tz {
+                    Some(_) => None,
+                    None => parse_datetime_time_zone(ca)?,
+                };
+                Ok(ca
+                    .round(every, offset, tz.or(own_tz.as_ref()), RoundMode::default())?
+                    .into_series())
+            }
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => Ok(s
+                .duration()?
+                .round(every, offset, None, RoundMode::default())?
+                .into_series()),
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => Ok(s
+                .time()?
+                .round(every, offset, None, RoundMode::default())?
+                .into_series()),
+            dt => polars_bail!(
+                opq = round,
+                got = dt,
+                expected = "date/datetime/duration/time"
+            ),
+        }
+    }
 }
 
 impl<T: ?Sized + AsSeries> TemporalMethods for T {}
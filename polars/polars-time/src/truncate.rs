@@ -4,39 +4,328 @@ use polars_arrow::time_zone::Tz;
 use polars_core::prelude::*;
 
 use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::utils::{localize_timestamp, unlocalize_timestamp};
+#[cfg(feature = "dtype-time")]
+use crate::windows::calendar::NS_DAY;
+#[cfg(feature = "dtype-datetime")]
+use crate::windows::duration::broadcast_duration;
+
+/// Check that the `every` of a truncation or rounding is a positive duration.
+pub fn validate_every(every: &Duration) -> PolarsResult<()> {
+    polars_ensure!(
+        !every.is_zero(),
+        ComputeError: "'every' should be a positive duration, got a zero duration"
+    );
+    polars_ensure!(
+        !every.negative,
+        ComputeError: "'every' should be a positive duration, got a negative duration"
+    );
+    Ok(())
+}
 
 pub trait PolarsTruncate {
-    fn truncate(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self>
+    /// Truncate every value to a multiple of `every` counted from the Unix epoch, or from `origin`
+    /// if given, and add `offset`.
+    ///
+    /// `origin` is in the physical unit of the values, and is only supported for a Date or
+    /// Datetime with an `every` that isn't a calendar duration (months, quarters or years).
+    fn truncate(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        origin: Option<i64>,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
+/// Shorthands to truncate a Date or Datetime to the start of its calendar week, month or year,
+/// without spelling out the `every` of [`PolarsTruncate::truncate`].
+///
+/// `tz` is the time zone to truncate a Datetime in, which is ignored for a Date.
+pub trait PolarsTruncateCalendar: PolarsTruncate + Sized {
+    /// Truncate every value to the Monday at midnight of its ISO week.
+    fn truncate_to_week(&self, tz: Option<&Tz>) -> PolarsResult<Self> {
+        self.truncate(Duration::from_weeks(1), Duration::from_nsecs(0), tz, None)
+    }
+
+    /// Truncate every value to the first day at midnight of its month.
+    fn truncate_to_month(&self, tz: Option<&Tz>) -> PolarsResult<Self> {
+        self.truncate(Duration::from_months(1), Duration::from_nsecs(0), tz, None)
+    }
+
+    /// Truncate every value to January 1st at midnight of its year.
+    fn truncate_to_year(&self, tz: Option<&Tz>) -> PolarsResult<Self> {
+        self.truncate(Duration::from_months(12), Duration::from_nsecs(0), tz, None)
+    }
+}
+
 #[cfg(feature = "dtype-datetime")]
-impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self> {
-        let w = Window::new(every, every, offset);
+impl PolarsTruncateCalendar for DatetimeChunked {}
+
+#[cfg(feature = "dtype-date")]
+impl PolarsTruncateCalendar for DateChunked {}
+
+/// Truncate `t` to `origin` plus a multiple of the fixed `every`, all in the time unit `tu`, and
+/// add `offset`. With a time zone, this happens in wall-clock time.
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+fn truncate_from_origin(
+    t: i64,
+    origin: i64,
+    every: &Duration,
+    offset: &Duration,
+    tz: Option<&Tz>,
+    tu: TimeUnit,
+) -> PolarsResult<i64> {
+    let (every, add): (i64, fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>) = match tu {
+        TimeUnit::Nanoseconds => (every.duration_ns(), Duration::add_ns),
+        TimeUnit::Microseconds => (every.duration_us(), Duration::add_us),
+        TimeUnit::Milliseconds => (every.duration_ms(), Duration::add_ms),
+    };
+    let floor = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            let t = unlocalize_timestamp(t, tu, *tz);
+            let origin = unlocalize_timestamp(origin, tu, *tz);
+            localize_timestamp(t - (t - origin).rem_euclid(every), tu, *tz)?
+        }
+        _ => t - (t - origin).rem_euclid(every),
+    };
+    add(offset, floor, tz)
+}
+
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+fn validate_origin_every(every: &Duration) -> PolarsResult<()> {
+    polars_ensure!(
+        every.months() == 0,
+        ComputeError: "cannot truncate from an 'origin' by a calendar duration (months, \
+        quarters or years), got {} month(s)", every.months()
+    );
+    Ok(())
+}
+
+#[cfg(any(feature = "dtype-duration", feature = "dtype-time"))]
+fn validate_no_origin(origin: Option<i64>, dtype: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        origin.is_none(),
+        ComputeError: "cannot truncate a {} from an 'origin'", dtype
+    );
+    Ok(())
+}
 
-        let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
+#[cfg(feature = "dtype-datetime")]
+impl PolarsTruncate for DatetimeChunked {
+    fn truncate(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        origin: Option<i64>,
+    ) -> PolarsResult<Self> {
+        validate_every(&every)?;
+        let tu = self.time_unit();
+        let unit_nsecs = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        // otherwise the window would be rounded to a multiple of the time unit
+        // (or even to zero), and silently truncate to something else
+        polars_ensure!(
+            every.nanoseconds() % unit_nsecs == 0,
+            ComputeError: "cannot truncate a Datetime with time unit '{}' by an 'every' of {} \
+            nanosecond(s): it must be a multiple of 1{}",
+            tu.to_ascii(), every.nanoseconds(), tu.to_ascii()
+        );
+        let out = match origin {
+            Some(origin) => {
+                validate_origin_every(&every)?;
+                self.try_apply(|t| truncate_from_origin(t, origin, &every, &offset, tz, tu))?
+            }
+            None => {
+                let w = Window::new(every, every, offset);
+                let func = match tu {
+                    TimeUnit::Nanoseconds => Window::truncate_ns,
+                    TimeUnit::Microseconds => Window::truncate_us,
+                    TimeUnit::Milliseconds => Window::truncate_ms,
+                };
+                self.try_apply(|t| func(&w, t, tz))?
+            }
         };
+        Ok(out.into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
+}
 
-        Ok(self
-            .try_apply(|t| func(&w, t, tz))?
-            .into_datetime(self.time_unit(), self.time_zone().clone()))
+/// Truncate every value of a Date/Datetime Series by the matching `every` and `offset`
+/// duration strings.
+///
+/// `every` and `offset` are each either of length 1, in which case they are broadcast, or of
+/// the same length as `s`. Rows where either of them is null are null. A tz-aware Datetime is
+/// truncated in its own time zone.
+pub fn truncate_by(s: &Series, every: &Utf8Chunked, offset: &Utf8Chunked) -> PolarsResult<Series> {
+    for (name, durations) in [("every", every), ("offset", offset)] {
+        polars_ensure!(
+            durations.len() == 1 || durations.len() == s.len(),
+            ComputeError: "expected '{}' of length 1 or {}, got {}",
+            name, s.len(), durations.len()
+        );
+    }
+    let mut cache = DurationCache::new(s.len());
+
+    match s.dtype() {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            truncate_by(&s, every, offset)?.cast(&DataType::Date)
+        }
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, _) => {
+            let ca = s.datetime().unwrap();
+            let tz = parse_datetime_time_zone(ca)?;
+            let func = match tu {
+                TimeUnit::Nanoseconds => Window::truncate_ns,
+                TimeUnit::Microseconds => Window::truncate_us,
+                TimeUnit::Milliseconds => Window::truncate_ms,
+            };
+            let mut out =
+                ca.0.into_iter()
+                    .enumerate()
+                    .map(|(idx, t)| {
+                        match (
+                            t,
+                            broadcast_duration(every, idx),
+                            broadcast_duration(offset, idx),
+                        ) {
+                            (Some(t), Some(every), Some(offset)) => {
+                                let every = cache.try_parse(every)?;
+                                validate_every(&every)?;
+                                let w = Window::new(every, every, cache.try_parse(offset)?);
+                                func(&w, t, tz.as_ref()).map(Some)
+                            }
+                            _ => Ok(None),
+                        }
+                    })
+                    .collect::<PolarsResult<Int64Chunked>>()?;
+            out.rename(ca.name());
+            Ok(out.into_datetime(*tu, ca.time_zone().clone()).into_series())
+        }
+        dt => polars_bail!(opq = truncate_by, got = dt, expected = "date/datetime"),
     }
 }
 
 #[cfg(feature = "dtype-date")]
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, every: Duration, offset: Duration, _tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        origin: Option<i64>,
+    ) -> PolarsResult<Self> {
+        const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
+        validate_every(&every)?;
         let w = Window::new(every, every, offset);
+        if origin.is_some() {
+            validate_origin_every(&every)?;
+        }
         Ok(self
             .try_apply(|t| {
-                const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
-                Ok((w.truncate_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32)
+                let t = MSECS_IN_DAY * t as i64;
+                let t = match origin {
+                    Some(origin) => truncate_from_origin(
+                        t,
+                        MSECS_IN_DAY * origin,
+                        &every,
+                        &offset,
+                        None,
+                        TimeUnit::Milliseconds,
+                    )?,
+                    None => w.truncate_ms(t, None)?,
+                };
+                Ok((t / MSECS_IN_DAY) as i32)
             })?
             .into_date())
     }
 }
+
+/// Number of `tu` units in a fixed (non-calendar) `duration`, with its sign.
+#[cfg(feature = "dtype-duration")]
+pub(crate) fn fixed_duration_in_unit(
+    duration: &Duration,
+    tu: TimeUnit,
+    name: &str,
+) -> PolarsResult<i64> {
+    polars_ensure!(
+        duration.months() == 0,
+        ComputeError: "'{}' cannot be a calendar duration (months, quarters or years) \
+        for a Duration, got {} month(s)", name, duration.months()
+    );
+    let v = match tu {
+        TimeUnit::Nanoseconds => duration.duration_ns(),
+        TimeUnit::Microseconds => duration.duration_us(),
+        TimeUnit::Milliseconds => duration.duration_ms(),
+    };
+    Ok(if duration.negative { -v } else { v })
+}
+
+/// The `every` of a Time truncation or rounding in nanoseconds, checking that there is no
+/// `offset`.
+#[cfg(feature = "dtype-time")]
+pub(crate) fn time_every_ns(every: &Duration, offset: &Duration, op: &str) -> PolarsResult<i64> {
+    validate_every(every)?;
+    polars_ensure!(
+        offset.is_zero(),
+        ComputeError: "cannot {} a Time with an 'offset'", op
+    );
+    // a Time is the number of nanoseconds since midnight, so the buckets have to tile a day
+    let every_ns = every.duration_ns();
+    polars_ensure!(
+        every.months() == 0 && every.weeks() == 0 && NS_DAY % every_ns == 0,
+        ComputeError: "'every' of a Time {} should divide a day, got {} month(s), \
+        {} week(s), {} day(s) and {} nanosecond(s)",
+        op, every.months(), every.weeks(), every.days(), every.nanoseconds()
+    );
+    Ok(every_ns)
+}
+
+#[cfg(feature = "dtype-time")]
+impl PolarsTruncate for TimeChunked {
+    fn truncate(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        origin: Option<i64>,
+    ) -> PolarsResult<Self> {
+        validate_no_origin(origin, "Time")?;
+        let every_ns = time_every_ns(&every, &offset, "truncate")?;
+        Ok(self.apply(|t| t - t % every_ns).into_time())
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl PolarsTruncate for DurationChunked {
+    fn truncate(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        origin: Option<i64>,
+    ) -> PolarsResult<Self> {
+        validate_no_origin(origin, "Duration")?;
+        validate_every(&every)?;
+        let tu = self.time_unit();
+        let every = fixed_duration_in_unit(&every, tu, "every")?;
+        let offset = fixed_duration_in_unit(&offset, tu, "offset")?;
+        polars_ensure!(
+            every > 0,
+            ComputeError: "cannot truncate a Duration by an 'every' that is finer than its \
+            time unit '{}'", tu.to_ascii()
+        );
+        Ok(self
+            .apply(|t| t - t.rem_euclid(every) + offset)
+            .into_duration(tu))
+    }
+}
@@ -6,10 +6,58 @@ use arrow::temporal_conversions::{
 use chrono::TimeZone;
 #[cfg(feature = "timezones")]
 use chrono::{LocalResult, NaiveDateTime};
-#[cfg(feature = "timezones")]
+#[cfg(any(feature = "timezones", feature = "dtype-datetime"))]
 use polars_arrow::time_zone::Tz;
+#[cfg(feature = "dtype-datetime")]
+use polars_core::prelude::DatetimeChunked;
+#[cfg(any(feature = "timezones", feature = "dtype-datetime"))]
+use polars_core::prelude::PolarsResult;
+#[cfg(feature = "timezones")]
+use polars_core::prelude::{
+    polars_bail, polars_err, IntoSeries, NewChunkedArray, Series, TimeUnit, Utf8Chunked,
+};
+#[cfg(feature = "timezones")]
+use polars_core::series::IsSorted;
+
+/// Parse a time zone string into a [`Tz`].
+///
+/// Parse a time zone once and pass the resulting `&Tz` to e.g. [`PolarsTruncate::truncate`] or
+/// [`PolarsRound::round`], which reuse it for every value instead of reparsing the string.
+///
+/// [`PolarsTruncate::truncate`]: crate::PolarsTruncate::truncate
+/// [`PolarsRound::round`]: crate::PolarsRound::round
+#[cfg(feature = "timezones")]
+pub fn parse_time_zone(tz: &str) -> PolarsResult<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| polars_err!(ComputeError: "unable to parse time zone: '{}'", tz))
+}
+
+/// List the names of all IANA time zones that can be parsed, sorted, e.g. to validate a column
+/// of time zones against.
 #[cfg(feature = "timezones")]
-use polars_core::prelude::{polars_bail, PolarsResult, TimeUnit};
+pub fn list_timezones() -> Series {
+    let mut names = chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| tz.name())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    let mut ca = Utf8Chunked::from_slice("timezones", &names);
+    ca.set_sorted_flag(IsSorted::Ascending);
+    ca.into_series()
+}
+
+/// Parse the time zone of a Datetime column, if it has one.
+///
+/// Without the `timezones` feature there is no [`Tz`] to parse into, and `None` is returned
+/// for every column, so that tz-aware datetimes are handled in UTC.
+#[cfg(feature = "dtype-datetime")]
+pub fn parse_datetime_time_zone(ca: &DatetimeChunked) -> PolarsResult<Option<Tz>> {
+    match ca.time_zone() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => parse_time_zone(tz).map(Some),
+        _ => Ok(None),
+    }
+}
 
 #[cfg(feature = "timezones")]
 pub(crate) fn localize_datetime(ndt: NaiveDateTime, tz: &Tz) -> PolarsResult<NaiveDateTime> {
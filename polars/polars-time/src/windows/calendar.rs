@@ -35,7 +35,24 @@ pub const NS_HOUR: i64 = 60 * NS_MINUTE;
 pub const NS_DAY: i64 = 24 * NS_HOUR;
 pub const NS_WEEK: i64 = 7 * NS_DAY;
 
+/// The shortest time that `every` can span in nanoseconds, so that a range of it can't have
+/// more than `(stop - start) / min_duration_ns + 1` values.
+fn min_duration_ns(every: &Duration, tz: Option<&Tz>) -> i64 {
+    // the shortest month is a February of 28 days
+    let calendar_ns =
+        every.months() * 28 * NS_DAY + every.weeks() * NS_WEEK + every.days() * NS_DAY;
+    // and in a time zone, a DST transition shortens a day by (at most) a couple of hours
+    let dst_ns = if tz.is_some() && calendar_ns > 0 {
+        2 * NS_HOUR
+    } else {
+        0
+    };
+    calendar_ns - dst_ns + every.nanoseconds()
+}
+
 /// vector of i64 representing temporal values
+///
+/// With a negative `every`, the values go down from `start` to `stop`.
 pub fn temporal_range(
     start: i64,
     stop: i64,
@@ -44,49 +61,64 @@ pub fn temporal_range(
     tu: TimeUnit,
     tz: Option<&Tz>,
 ) -> PolarsResult<Vec<i64>> {
-    let size: usize;
+    polars_ensure!(!every.is_zero(), ComputeError: "'interval' cannot be zero");
     let offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+    let unit_ns;
 
     match tu {
         TimeUnit::Nanoseconds => {
-            size = ((stop - start) / every.duration_ns() + 1) as usize;
+            unit_ns = 1;
             offset_fn = Duration::add_ns;
         }
         TimeUnit::Microseconds => {
-            size = ((stop - start) / every.duration_us() + 1) as usize;
+            unit_ns = NS_MICROSECOND;
             offset_fn = Duration::add_us;
         }
         TimeUnit::Milliseconds => {
-            size = ((stop - start) / every.duration_ms() + 1) as usize;
+            unit_ns = NS_MILLISECOND;
             offset_fn = Duration::add_ms;
         }
     }
+    let min_every = (min_duration_ns(&every, tz) / unit_ns).max(1);
+    let size = ((stop - start).abs() / min_every + 1) as usize;
+    // a zero-length range is the single point `start`, included if either
+    // of its (coinciding) bounds is closed
+    if start == stop {
+        return Ok(match closed {
+            ClosedWindow::None => vec![],
+            _ => vec![start],
+        });
+    }
     let mut ts = Vec::with_capacity(size);
+    // whether `t` is in the range up to `stop`, with or without `stop` itself
+    let descending = every.negative;
+    let up_to_stop = |t: i64| if descending { t >= stop } else { t <= stop };
+    let before_stop = |t: i64| if descending { t > stop } else { t < stop };
 
     let mut t = start;
     match closed {
         ClosedWindow::Both => {
-            while t <= stop {
+            while up_to_stop(t) {
                 ts.push(t);
                 t = offset_fn(&every, t, tz)?
             }
         }
         ClosedWindow::Left => {
-            while t < stop {
+            while before_stop(t) {
                 ts.push(t);
                 t = offset_fn(&every, t, tz)?
             }
         }
         ClosedWindow::Right => {
             t = offset_fn(&every, t, tz)?;
-            while t <= stop {
+            while up_to_stop(t) {
                 ts.push(t);
                 t = offset_fn(&every, t, tz)?
             }
         }
         ClosedWindow::None => {
             t = offset_fn(&every, t, tz)?;
-            while t < stop {
+            while before_stop(t) {
                 ts.push(t);
                 t = offset_fn(&every, t, tz)?
             }
@@ -95,3 +127,89 @@ pub fn temporal_range(
     debug_assert!(size >= ts.len());
     Ok(ts)
 }
+
+/// vector of `periods` i64 representing temporal values, starting from `start` by `every`
+///
+/// Without a `stop`, only the left side of `closed` matters: a range closed on the left (`Left`
+/// or `Both`) starts at `start`, and one open on the left (`Right` or `None`) starts one `every`
+/// after it. Either way, it has exactly `periods` values.
+pub fn temporal_range_periods(
+    start: i64,
+    periods: usize,
+    every: Duration,
+    closed: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<Vec<i64>> {
+    polars_ensure!(!every.is_zero(), ComputeError: "'interval' cannot be zero");
+    let offset_fn = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+    let mut t = match closed {
+        ClosedWindow::Left | ClosedWindow::Both => start,
+        ClosedWindow::Right | ClosedWindow::None => offset_fn(&every, start, tz)?,
+    };
+    let mut ts = Vec::with_capacity(periods);
+    for i in 0..periods {
+        ts.push(t);
+        // don't step past the last value, which could overflow needlessly
+        if i + 1 < periods {
+            t = offset_fn(&every, t, tz)?;
+        }
+    }
+    Ok(ts)
+}
+
+/// The number of `every` steps from `start` to `end`, i.e. the inverse of [`temporal_range`]:
+/// the number of values of a range closed on both sides, minus one.
+///
+/// Calendar intervals (months, or weeks and days in a time zone) are counted by stepping
+/// through the calendar as [`temporal_range`] does, rather than by dividing nanoseconds.
+/// If `end` is before `start`, the count is negative.
+pub fn diff_in_periods(
+    start: i64,
+    end: i64,
+    every: Duration,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<i64> {
+    polars_ensure!(
+        !every.is_zero() && !every.negative,
+        ComputeError: "'every' should be a positive duration"
+    );
+    if end < start {
+        return diff_in_periods(end, start, every, tu, tz).map(|n| -n);
+    }
+
+    let is_fixed =
+        every.months() == 0 && (tz.is_none() || (every.weeks() == 0 && every.days() == 0));
+    if is_fixed {
+        let every = match tu {
+            TimeUnit::Nanoseconds => every.duration_ns(),
+            TimeUnit::Microseconds => every.duration_us(),
+            TimeUnit::Milliseconds => every.duration_ms(),
+        };
+        polars_ensure!(
+            every > 0,
+            ComputeError: "'every' should not be finer than the time unit '{}'", tu.to_ascii()
+        );
+        return Ok((end - start) / every);
+    }
+
+    let offset_fn = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+    let mut n = 0;
+    let mut t = start;
+    loop {
+        t = offset_fn(&every, t, tz)?;
+        if t > end {
+            return Ok(n);
+        }
+        n += 1;
+    }
+}
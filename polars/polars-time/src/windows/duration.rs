@@ -10,7 +10,7 @@ use polars_arrow::time_zone::Tz;
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    polars_ensure, PlHashMap, PolarsResult, TimeUnit, Utf8Chunked,
 };
 use polars_core::utils::arrow::temporal_conversions::NANOSECONDS;
 #[cfg(feature = "serde")]
@@ -92,6 +92,7 @@ impl Duration {
     /// * `d`:  day
     /// * `w`:  week
     /// * `mo`: calendar month
+    /// * `q`:  calendar quarter
     /// * `y`:  calendar year
     /// * `i`:  index value (only for {Int32, Int64} dtypes)
     ///
@@ -102,13 +103,22 @@ impl Duration {
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
+        Self::try_parse(duration).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Parse a string into a `Duration`, see [`Duration::parse`].
+    ///
+    /// Returns an error instead of panicking if the given str is invalid.
+    pub fn try_parse(duration: &str) -> PolarsResult<Self> {
         let num_minus_signs = duration.matches('-').count();
-        if num_minus_signs > 1 {
-            panic!("a Duration string can only have a single minus sign")
-        }
-        if (num_minus_signs > 0) & !duration.starts_with('-') {
-            panic!("only a single minus sign is allowed, at the front of the string")
-        }
+        polars_ensure!(
+            num_minus_signs <= 1,
+            ComputeError: "a Duration string can only have a single minus sign"
+        );
+        polars_ensure!(
+            num_minus_signs == 0 || duration.starts_with('-'),
+            ComputeError: "only a single minus sign is allowed, at the front of the string"
+        );
 
         let mut nsecs = 0;
         let mut weeks = 0;
@@ -135,9 +145,9 @@ impl Duration {
         let mut unit = String::with_capacity(2);
         while let Some((i, mut ch)) = iter.next() {
             if !ch.is_ascii_digit() {
-                let n = duration[start..i]
-                    .parse::<i64>()
-                    .expect("expected an integer in the duration string");
+                let n = duration[start..i].parse::<i64>().map_err(
+                    |_| polars_err!(ComputeError: "expected an integer in the duration string"),
+                )?;
 
                 loop {
                     if ch.is_ascii_alphabetic() {
@@ -155,9 +165,10 @@ impl Duration {
                         }
                     }
                 }
-                if unit.is_empty() {
-                    panic!("expected a unit in the duration string")
-                }
+                polars_ensure!(
+                    !unit.is_empty(),
+                    ComputeError: "expected a unit in the duration string"
+                );
 
                 match &*unit {
                     "ns" => nsecs += n,
@@ -168,9 +179,7 @@ impl Duration {
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
                     "w" => weeks += n,
-                    "mo" => {
-                        months += n
-                    }
+                    "mo" => months += n,
                     "q" => months += n * 3,
                     "y" => months += n * 12,
                     // we will read indexes as nanoseconds
@@ -178,12 +187,14 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     }
-                    unit => panic!("unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'"),
+                    unit => polars_bail!(
+                        ComputeError: "unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'"
+                    ),
                 }
                 unit.clear();
             }
         }
-        Duration {
+        Ok(Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
             weeks: weeks.abs(),
@@ -191,7 +202,7 @@ impl Duration {
             negative,
             parsed_int,
             saturating,
-        }
+        })
     }
 
     fn to_positive(v: i64) -> (bool, i64) {
@@ -332,6 +343,11 @@ impl Duration {
         self.days
     }
 
+    /// `true` if the duration has no sub-day (nanosecond) component.
+    pub fn is_full_days(&self) -> bool {
+        self.nsecs == 0
+    }
+
     /// Returns the nanoseconds from the `Duration` without the weeks or months part.
     pub fn nanoseconds(&self) -> i64 {
         self.nsecs
@@ -420,6 +436,37 @@ impl Duration {
         )
     }
 
+    /// Whether adding the months of this duration to `t` lands on a day that doesn't exist in
+    /// the target month, e.g. `2022-01-31` by `1mo`, which is an error unless `saturating`.
+    pub(crate) fn lands_past_month_end(&self, t: i64, tz: Option<&Tz>, tu: TimeUnit) -> bool {
+        if self.months == 0 {
+            return false;
+        }
+        let ts = match tu {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime(t),
+            TimeUnit::Microseconds => timestamp_us_to_datetime(t),
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime(t),
+        };
+        let ts = match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => unlocalize_datetime(ts, tz),
+            _ => ts,
+        };
+        let months = if self.negative {
+            -self.months
+        } else {
+            self.months
+        };
+        let months = ts.year() as i64 * 12 + ts.month0() as i64 + months;
+        let year = months.div_euclid(12) as i32;
+        let month = months.rem_euclid(12) as i32 + 1;
+        let mut last_day_of_month = last_day_of_month(month);
+        if month == (chrono::Month::February.number_from_month() as i32) && is_leap_year(year) {
+            last_day_of_month += 1;
+        }
+        ts.day() > last_day_of_month
+    }
+
     #[inline]
     pub fn truncate_impl<F, G, J>(
         &self,
@@ -673,6 +720,32 @@ impl Duration {
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
         Ok(new_t? + nsecs / 1_000_000)
     }
+
+    /// Add this duration to a date, given as the number of days since the Unix epoch.
+    ///
+    /// Only whole days, weeks and months can be added to a date.
+    pub fn add_date(&self, t: i32) -> PolarsResult<i32> {
+        const MS_DAY: i64 = NS_DAY / NS_MILLISECOND;
+        polars_ensure!(
+            self.is_full_days(),
+            ComputeError: "cannot offset a Date by a duration with a sub-day part of {} \
+            nanosecond(s)", self.nsecs
+        );
+        let mut new_t = t as i64;
+        if self.months > 0 {
+            let ts = timestamp_ms_to_datetime(new_t * MS_DAY);
+            let dt = Self::add_month(ts, self.months, self.negative, self.saturating)?;
+            new_t = datetime_to_timestamp_ms(dt) / MS_DAY;
+        }
+        let days = self.weeks * 7 + self.days;
+        new_t += if self.negative { -days } else { days };
+        i32::try_from(new_t).map_err(|_| {
+            polars_err!(
+                ComputeError: "the offset date, {} days since the epoch, is out of the range of a Date",
+                new_t
+            )
+        })
+    }
 }
 
 impl Mul<i64> for Duration {
@@ -691,6 +764,46 @@ impl Mul<i64> for Duration {
     }
 }
 
+/// Parses duration strings, each distinct string only once as long as there are few of them.
+///
+/// Per-row durations typically repeat a few strings, e.g. when a single one is broadcast. The
+/// strings are borrowed from their array, and the cache is cleared whenever it is full, so that
+/// many distinct strings don't grow it without bound.
+pub struct DurationCache<'a> {
+    parsed: PlHashMap<&'a str, Duration>,
+    capacity: usize,
+}
+
+impl<'a> DurationCache<'a> {
+    /// Create a cache for the durations of `len` rows, which holds up to about `sqrt(len)`
+    /// distinct strings at a time.
+    pub fn new(len: usize) -> Self {
+        let capacity = ((len as f64).sqrt() as usize).max(1);
+        Self {
+            parsed: PlHashMap::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn try_parse(&mut self, duration: &'a str) -> PolarsResult<Duration> {
+        if let Some(parsed) = self.parsed.get(duration) {
+            return Ok(*parsed);
+        }
+        let parsed = Duration::try_parse(duration)?;
+        if self.parsed.len() >= self.capacity {
+            self.parsed.clear();
+        }
+        self.parsed.insert(duration, parsed);
+        Ok(parsed)
+    }
+}
+
+/// The duration string of row `idx`, where `durations` is either of length 1, in which case it
+/// is broadcast, or has a string for every row.
+pub(crate) fn broadcast_duration(durations: &Utf8Chunked, idx: usize) -> Option<&str> {
+    durations.get(if durations.len() == 1 { 0 } else { idx })
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -726,6 +839,38 @@ mod test {
         assert_eq!(out.weeks(), 5);
     }
 
+    #[test]
+    fn test_duration_cache() {
+        let mut cache = DurationCache::new(4);
+        for _ in 0..2 {
+            assert_eq!(cache.try_parse("1mo2d").unwrap(), Duration::parse("1mo2d"));
+            assert_eq!(cache.try_parse("-3h").unwrap(), Duration::parse("-3h"));
+        }
+        assert_eq!(cache.parsed.len(), 2);
+        assert!(cache.try_parse("1x").is_err());
+
+        // a full cache is cleared rather than grown
+        assert_eq!(cache.try_parse("5d").unwrap(), Duration::parse("5d"));
+        assert_eq!(cache.parsed.len(), 1);
+    }
+
+    #[test]
+    fn test_add_date() {
+        // 2023-01-31
+        let t = 19388;
+        assert_eq!(Duration::parse("10d").add_date(t).unwrap(), t + 10);
+        assert_eq!(Duration::parse("-2w").add_date(t).unwrap(), t - 14);
+        // 2023-02-28
+        assert_eq!(
+            Duration::parse("1mo_saturating").add_date(t).unwrap(),
+            t + 28
+        );
+        assert!(Duration::parse("1mo").add_date(t).is_err());
+        assert!(Duration::parse("1d12h").add_date(t).is_err());
+        // the number of days since the epoch doesn't fit in an i32
+        assert!(Duration::parse("1d").add_date(i32::MAX).is_err());
+    }
+
     #[test]
     fn test_add_ns() {
         let t = 1;
@@ -1,6 +1,7 @@
 use chrono::prelude::*;
 use polars_arrow::export::arrow::temporal_conversions::timestamp_ns_to_datetime;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 
 use crate::prelude::*;
 
@@ -65,6 +66,334 @@ fn test_feb_date_range() {
     assert_eq!(dates, expected);
 }
 
+#[test]
+fn test_date_range_start_equals_end() {
+    let t = NaiveDate::from_ymd_opt(2022, 2, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .timestamp_nanos();
+    for (closed, expected) in [
+        (ClosedWindow::Both, vec![t]),
+        (ClosedWindow::Left, vec![t]),
+        (ClosedWindow::Right, vec![t]),
+        (ClosedWindow::None, vec![]),
+    ] {
+        for every in ["1h", "1mo"] {
+            let dates = temporal_range_vec(
+                t,
+                t,
+                Duration::parse(every),
+                closed,
+                TimeUnit::Nanoseconds,
+                None,
+            )
+            .unwrap();
+            assert_eq!(dates, expected, "closed: {closed:?}, every: {every}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_date_range_sorted_across_dst() {
+    // Europe/London falls back from BST to GMT at 02:00 on 2022-10-30,
+    // so the wall-clock times 01:00 and 01:30 occur twice
+    let start = NaiveDate::from_ymd_opt(2022, 10, 30)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 10, 30)
+        .unwrap()
+        .and_hms_opt(3, 0, 0)
+        .unwrap();
+    let dates = date_range(
+        "dt",
+        start,
+        stop,
+        Duration::parse("30m"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        Some("Europe/London".to_string()),
+    )
+    .unwrap();
+    assert_eq!(dates.is_sorted_flag(), IsSorted::Ascending);
+
+    // 23:00 UTC up to and including 03:00 UTC
+    let instants = dates.cont_slice().unwrap();
+    assert_eq!(instants.len(), 9);
+    assert!(instants.windows(2).all(|w| w[1] - w[0] == 30 * 60 * 1000));
+}
+
+#[test]
+fn test_date_range_descending() {
+    let date = |d: u32| {
+        NaiveDate::from_ymd_opt(2022, 3, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let every = Duration::parse("-1d");
+    let range = |closed| {
+        date_range(
+            "dt",
+            date(5),
+            date(1),
+            every,
+            closed,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap()
+    };
+    let expected = |days: &[u32]| {
+        days.iter()
+            .map(|&d| date(d).timestamp_millis())
+            .collect::<Vec<_>>()
+    };
+
+    let dates = range(ClosedWindow::Both);
+    assert_eq!(dates.cont_slice().unwrap(), expected(&[5, 4, 3, 2, 1]));
+    assert_eq!(dates.is_sorted_flag(), IsSorted::Descending);
+    let dates = range(ClosedWindow::Left);
+    assert_eq!(dates.cont_slice().unwrap(), expected(&[5, 4, 3, 2]));
+    let dates = range(ClosedWindow::Right);
+    assert_eq!(dates.cont_slice().unwrap(), expected(&[4, 3, 2, 1]));
+    let dates = range(ClosedWindow::None);
+    assert_eq!(dates.cont_slice().unwrap(), expected(&[4, 3, 2]));
+
+    // a negative interval can't go up, nor a positive one down
+    let err = date_range(
+        "dt",
+        date(1),
+        date(5),
+        every,
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("'interval' cannot be negative"));
+    let err = date_range(
+        "dt",
+        date(5),
+        date(1),
+        Duration::parse("1d"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("'start' cannot be greater than 'stop'"));
+}
+
+#[test]
+fn test_monthly_date_range_across_leap_february() {
+    let date = |y: i32, m: u32, d: u32| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let dates = temporal_range_vec(
+        date(2023, 11, 29).timestamp_millis(),
+        date(2024, 3, 29).timestamp_millis(),
+        Duration::parse("1mo"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+    )
+    .unwrap();
+    let expected = [
+        date(2023, 11, 29),
+        date(2023, 12, 29),
+        date(2024, 1, 29),
+        date(2024, 2, 29),
+        date(2024, 3, 29),
+    ]
+    .iter()
+    .map(|d| d.timestamp_millis())
+    .collect::<Vec<_>>();
+    assert_eq!(dates, expected);
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_daily_date_range_across_dst() {
+    // Europe/London springs forward on 2022-03-27, so that day only has 23 hours and the
+    // range spans less than 4 times 24 hours
+    let date = |d: u32| {
+        NaiveDate::from_ymd_opt(2022, 3, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let dates = date_range(
+        "dt",
+        date(25),
+        date(29),
+        Duration::parse("1d"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        Some("Europe/London".to_string()),
+    )
+    .unwrap();
+    assert_eq!(dates.len(), 5);
+}
+
+#[test]
+fn test_quarterly_date_range() {
+    let date = |y: i32, m: u32, d: u32| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let range = |start, stop| {
+        date_range(
+            "dt",
+            start,
+            stop,
+            Duration::parse("1q"),
+            ClosedWindow::Left,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap()
+        .cont_slice()
+        .unwrap()
+        .to_vec()
+    };
+    let expected = |dates: &[NaiveDateTime]| {
+        dates
+            .iter()
+            .map(|d| d.timestamp_millis())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        range(date(2022, 1, 1), date(2023, 1, 1)),
+        expected(&[
+            date(2022, 1, 1),
+            date(2022, 4, 1),
+            date(2022, 7, 1),
+            date(2022, 10, 1)
+        ])
+    );
+    // starting mid-quarter, the day of the month is kept
+    assert_eq!(
+        range(date(2022, 2, 15), date(2023, 1, 1)),
+        expected(&[
+            date(2022, 2, 15),
+            date(2022, 5, 15),
+            date(2022, 8, 15),
+            date(2022, 11, 15)
+        ])
+    );
+}
+
+#[test]
+fn test_month_start_and_end_range() {
+    let date = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let dates = |ca: DateChunked| {
+        assert_eq!(ca.is_sorted_flag(), IsSorted::Ascending);
+        ca.as_date_iter().map(Option::unwrap).collect::<Vec<_>>()
+    };
+
+    for (year, last_of_february) in [(2024, 29), (2023, 28)] {
+        let (start, end) = (date(year, 1, 1), date(year, 3, 31));
+        assert_eq!(
+            dates(month_end_range("date", start, end)),
+            [
+                date(year, 1, 31),
+                date(year, 2, last_of_february),
+                date(year, 3, 31)
+            ]
+        );
+        assert_eq!(
+            dates(month_start_range("date", start, end)),
+            [date(year, 1, 1), date(year, 2, 1), date(year, 3, 1)]
+        );
+
+        // the boundaries of the months around the bounds are left out
+        let (start, end) = (date(year, 1, 15), date(year, 3, 15));
+        assert_eq!(
+            dates(month_end_range("date", start, end)),
+            [date(year, 1, 31), date(year, 2, last_of_february)]
+        );
+        assert_eq!(
+            dates(month_start_range("date", start, end)),
+            [date(year, 2, 1), date(year, 3, 1)]
+        );
+    }
+
+    // across a year boundary
+    assert_eq!(
+        dates(month_end_range(
+            "date",
+            date(2023, 11, 30),
+            date(2024, 1, 31)
+        )),
+        [date(2023, 11, 30), date(2023, 12, 31), date(2024, 1, 31)]
+    );
+    assert!(dates(month_start_range(
+        "date",
+        date(2023, 3, 2),
+        date(2023, 3, 31)
+    ))
+    .is_empty());
+}
+
+#[test]
+fn test_date_range_with_periods() {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 1, 2)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let range = |every, periods| {
+        date_range_with_periods(
+            "dt",
+            start,
+            stop,
+            Duration::parse(every),
+            periods,
+            ClosedWindow::Both,
+            TimeUnit::Milliseconds,
+            None,
+        )
+    };
+
+    let dates = range("6h", 5).unwrap();
+    assert_eq!(dates.len(), 5);
+    assert_eq!(
+        dates.cont_slice().unwrap(),
+        date_range(
+            "dt",
+            start,
+            stop,
+            Duration::parse("6h"),
+            ClosedWindow::Both,
+            TimeUnit::Milliseconds,
+            None
+        )
+        .unwrap()
+        .cont_slice()
+        .unwrap()
+    );
+
+    let err = range("8h", 5).unwrap_err();
+    assert!(err.to_string().contains(
+        "the range from '2022-01-01 00:00:00' to '2022-01-02 00:00:00' has 4 value(s) at the \
+        given interval, but 5 periods were given"
+    ));
+}
+
 fn print_ns(ts: &[i64]) {
     for ts in ts {
         println!("{}", timestamp_ns_to_datetime(*ts));
@@ -836,3 +1165,708 @@ fn test_groupby_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_time_range_full_day() {
+    let start = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+    let end = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+    let every = Duration::parse("1h");
+    let hours = |range: std::ops::Range<u32>| {
+        range
+            .map(|h| NaiveTime::from_hms_opt(h, 0, 0).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    for (closed, expected) in [
+        (ClosedWindow::Both, hours(0..24)),
+        (ClosedWindow::Left, hours(0..24)),
+        (ClosedWindow::Right, hours(1..24)),
+        (ClosedWindow::None, hours(1..24)),
+    ] {
+        let out = time_range("time", start, end, every, closed)
+            .unwrap()
+            .as_time_iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+fn test_time_range_last_nanosecond() {
+    let time = |ns| NaiveTime::from_hms_nano_opt(23, 59, 59, ns).unwrap();
+    let end = time(999_999_999);
+    let every = Duration::parse("1ns");
+
+    for (closed, expected) in [
+        (
+            ClosedWindow::Both,
+            vec![time(999_999_997), time(999_999_998), time(999_999_999)],
+        ),
+        (
+            ClosedWindow::Left,
+            vec![time(999_999_997), time(999_999_998)],
+        ),
+        (
+            ClosedWindow::Right,
+            vec![time(999_999_998), time(999_999_999)],
+        ),
+        (ClosedWindow::None, vec![time(999_999_998)]),
+    ] {
+        let out = time_range("time", time(999_999_997), end, every, closed)
+            .unwrap()
+            .as_time_iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    // start == end is a single point, included if either of its bounds is closed
+    for (closed, expected) in [
+        (ClosedWindow::Both, vec![end]),
+        (ClosedWindow::Left, vec![end]),
+        (ClosedWindow::Right, vec![end]),
+        (ClosedWindow::None, vec![]),
+    ] {
+        let out = time_range("time", end, end, every, closed)
+            .unwrap()
+            .as_time_iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    assert!(time_range("time", end, end, Duration::parse("0ns"), ClosedWindow::Both).is_err());
+}
+
+#[test]
+fn test_truncate_every_finer_than_time_unit() {
+    let ca = Int64Chunked::new("dt", &[1_500, 2_750]).into_datetime(TimeUnit::Milliseconds, None);
+
+    let err = ca
+        .truncate(Duration::parse("1ns"), Duration::parse("0ns"), None, None)
+        .unwrap_err();
+    assert!(err.to_string().contains(
+        "cannot truncate a Datetime with time unit 'ms' by an 'every' of 1 nanosecond(s)"
+    ));
+    assert!(ca
+        .truncate(
+            Duration::parse("1500us"),
+            Duration::parse("0ns"),
+            None,
+            None
+        )
+        .is_err());
+
+    let out = ca
+        .truncate(
+            Duration::parse("1000us"),
+            Duration::parse("0ns"),
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(Vec::from(&out.0), &[Some(1_000), Some(2_000)]);
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+fn test_truncate_round_temporal_dispatch() {
+    let every = || Duration::parse("1h");
+    let zero = || Duration::parse("0ns");
+
+    // Date
+    let days = |d: u32| {
+        (NaiveDate::from_ymd_opt(2022, 1, d).unwrap()
+            - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+        .num_days() as i32
+    };
+    let s = Int32Chunked::new("d", &[days(5)]).into_date().into_series();
+    let out = s
+        .truncate_temporal(Duration::parse("1mo"), zero(), None)
+        .unwrap();
+    assert_eq!(out.dtype(), &DataType::Date);
+    assert_eq!(out.to_physical_repr().i32().unwrap().get(0), Some(days(1)));
+
+    // naive Datetime
+    let t = NaiveDate::from_ymd_opt(2022, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 30, 0)
+        .unwrap()
+        .timestamp_millis();
+    let naive = Int64Chunked::new("dt", &[t]).into_datetime(TimeUnit::Milliseconds, None);
+    let out = naive
+        .clone()
+        .into_series()
+        .truncate_temporal(every(), zero(), None)
+        .unwrap();
+    let expected = naive
+        .truncate(every(), zero(), None, None)
+        .unwrap()
+        .into_series();
+    assert!(out.series_equal_missing(&expected));
+    let out = naive
+        .clone()
+        .into_series()
+        .round_temporal(every(), zero(), None)
+        .unwrap();
+    let expected = naive
+        .round(every(), zero(), None, RoundMode::default())
+        .unwrap()
+        .into_series();
+    assert!(out.series_equal_missing(&expected));
+
+    // tz-aware Datetime, truncated in its own time zone: 00:30 UTC is 06:15 in UTC+05:45
+    #[cfg(feature = "timezones")]
+    {
+        let aware = Int64Chunked::new("dt", &[t])
+            .into_datetime(TimeUnit::Milliseconds, Some("Asia/Kathmandu".into()))
+            .into_series();
+        let out = aware.truncate_temporal(every(), zero(), None).unwrap();
+        assert_eq!(out.dtype(), aware.dtype());
+        let out = out.to_physical_repr();
+        assert_eq!(out.i64().unwrap().get(0), Some(t - 15 * 60 * 1000));
+    }
+
+    // Duration
+    let s = Int64Chunked::new("d", &[1_500, -1_500, 2_750])
+        .into_duration(TimeUnit::Milliseconds)
+        .into_series();
+    let out = s
+        .truncate_temporal(Duration::parse("1s"), zero(), None)
+        .unwrap();
+    assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+    let out = out.to_physical_repr();
+    assert_eq!(
+        Vec::from(out.i64().unwrap()),
+        &[Some(1_000), Some(-2_000), Some(2_000)]
+    );
+    let out = s
+        .round_temporal(Duration::parse("1s"), zero(), None)
+        .unwrap();
+    let out = out.to_physical_repr();
+    assert_eq!(
+        Vec::from(out.i64().unwrap()),
+        &[Some(2_000), Some(-1_000), Some(3_000)]
+    );
+    assert!(s
+        .truncate_temporal(Duration::parse("1mo"), zero(), None)
+        .is_err());
+
+    // other dtypes
+    let s = Int64Chunked::new("i", &[1]).into_series();
+    assert!(s.truncate_temporal(every(), zero(), None).is_err());
+    assert!(s.round_temporal(every(), zero(), None).is_err());
+}
+
+#[test]
+fn test_diff_in_periods() {
+    let ts = |y: i32, m: u32, d: u32| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let diff = |start: i64, end: i64, every: &str| {
+        diff_in_periods(
+            start,
+            end,
+            Duration::parse(every),
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap()
+    };
+
+    // months across year boundaries
+    assert_eq!(diff(ts(2021, 11, 15), ts(2023, 2, 14), "1mo"), 14);
+    assert_eq!(diff(ts(2021, 11, 15), ts(2023, 2, 15), "1mo"), 15);
+    assert_eq!(diff(ts(2021, 11, 15), ts(2023, 2, 15), "1q"), 5);
+    assert_eq!(diff(ts(2021, 11, 15), ts(2023, 2, 15), "1y"), 1);
+    assert_eq!(diff(ts(2023, 2, 15), ts(2021, 11, 15), "1mo"), -15);
+    assert_eq!(diff(ts(2021, 12, 31), ts(2022, 1, 1), "1mo"), 0);
+
+    // the inverse of a range closed on both sides
+    let range = temporal_range_vec(
+        ts(2021, 11, 15),
+        ts(2023, 2, 15),
+        Duration::parse("1mo"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+    )
+    .unwrap();
+    assert_eq!(range.len() as i64 - 1, 15);
+
+    // fixed intervals
+    assert_eq!(diff(ts(2021, 12, 31), ts(2022, 1, 2), "1d"), 2);
+    assert_eq!(diff(ts(2021, 12, 31), ts(2022, 1, 2), "36h"), 1);
+
+    assert!(diff_in_periods(0, 1, Duration::parse("-1d"), TimeUnit::Milliseconds, None).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+fn test_validate_every_messages() {
+    let zero = || Duration::parse("0ns");
+    let date = Int32Chunked::new("d", &[0]).into_date().into_series();
+    let datetime = Int64Chunked::new("dt", &[0])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let duration = Int64Chunked::new("du", &[0])
+        .into_duration(TimeUnit::Milliseconds)
+        .into_series();
+
+    for every in ["0d", "-1d"] {
+        let expected = validate_every(&Duration::parse(every))
+            .unwrap_err()
+            .to_string();
+        for s in [&date, &datetime, &duration] {
+            let err = s
+                .truncate_temporal(Duration::parse(every), zero(), None)
+                .unwrap_err();
+            assert_eq!(err.to_string(), expected, "truncate {:?}", s.dtype());
+            let err = s
+                .round_temporal(Duration::parse(every), zero(), None)
+                .unwrap_err();
+            assert_eq!(err.to_string(), expected, "round {:?}", s.dtype());
+        }
+    }
+    assert!(validate_every(&Duration::parse("1d")).is_ok());
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+fn test_ceil() {
+    let every = Duration::parse("1h");
+    let t = |h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+
+    // Datetime: values on a boundary stay put
+    let ca = Int64Chunked::new("dt", &[t(0, 0), t(0, 1), t(0, 59), t(1, 0)])
+        .into_datetime(TimeUnit::Milliseconds, None);
+    let out = ca.ceil(every, None).unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[Some(t(0, 0)), Some(t(1, 0)), Some(t(1, 0)), Some(t(1, 0))]
+    );
+
+    // Date
+    let days = |m: u32, d: u32| {
+        (NaiveDate::from_ymd_opt(2022, m, d).unwrap()
+            - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+        .num_days() as i32
+    };
+    let ca = Int32Chunked::new("d", &[days(1, 1), days(1, 5), days(2, 1)]).into_date();
+    let out = ca.ceil(Duration::parse("1mo"), None).unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[Some(days(1, 1)), Some(days(2, 1)), Some(days(2, 1))]
+    );
+
+    // Duration
+    let ca = Int64Chunked::new("d", &[1_500, -1_500, 2_000]).into_duration(TimeUnit::Milliseconds);
+    let out = ca.ceil(Duration::parse("1s"), None).unwrap();
+    assert_eq!(Vec::from(&out.0), &[Some(2_000), Some(-1_000), Some(2_000)]);
+    assert!(ca.ceil(Duration::parse("1ns"), None).is_err());
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_ceil_across_dst() {
+    // Europe/London springs forward from 01:00 GMT to 02:00 BST on 2022-03-27
+    let tz: polars_arrow::time_zone::Tz = "Europe/London".parse().unwrap();
+    let utc = |h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2022, 3, 27)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let ca = Int64Chunked::new("dt", &[utc(0, 50), utc(1, 0), utc(1, 10)])
+        .into_datetime(TimeUnit::Milliseconds, Some("Europe/London".into()));
+    let out = ca.ceil(Duration::parse("30m"), Some(&tz)).unwrap();
+    // 00:50 GMT -> 02:00 BST, 02:00 BST stays put, 02:10 BST -> 02:30 BST
+    assert_eq!(
+        Vec::from(&out.0),
+        &[Some(utc(1, 0)), Some(utc(1, 0)), Some(utc(1, 30))]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+fn test_truncate_with_origin() {
+    let day = |d: u32| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+    let dates = [day(1), day(3), day(6), day(9), day(10), day(16)];
+    // a Wednesday
+    let origin = day(3);
+
+    let ca = DateChunked::from_naive_date("dates", dates);
+    let origin_days = (origin - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days();
+    let out = ca
+        .truncate(
+            Duration::parse("7d"),
+            Duration::parse("0ns"),
+            None,
+            Some(origin_days),
+        )
+        .unwrap();
+    let out = out.as_date_iter().map(Option::unwrap).collect::<Vec<_>>();
+    let expected = [
+        NaiveDate::from_ymd_opt(2023, 12, 27).unwrap(),
+        day(3),
+        day(3),
+        day(3),
+        day(10),
+        day(10),
+    ];
+    assert_eq!(out, expected);
+    assert!(out.iter().all(|d| d.weekday() == Weekday::Wed));
+
+    let ca = DatetimeChunked::from_naive_datetime(
+        "datetimes",
+        dates.iter().map(|d| d.and_hms_opt(12, 0, 0).unwrap()),
+        TimeUnit::Milliseconds,
+    );
+    let origin_ms = origin.and_hms_opt(0, 0, 0).unwrap().timestamp_millis();
+    let out = ca
+        .truncate(
+            Duration::parse("7d"),
+            Duration::parse("0ns"),
+            None,
+            Some(origin_ms),
+        )
+        .unwrap();
+    let out = out
+        .as_datetime_iter()
+        .map(Option::unwrap)
+        .collect::<Vec<_>>();
+    let expected = expected.map(|d| d.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(out, expected);
+
+    // calendar durations have no fixed length to count from an origin
+    assert!(ca
+        .truncate(
+            Duration::parse("1mo"),
+            Duration::parse("0ns"),
+            None,
+            Some(origin_ms)
+        )
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_truncate_time() {
+    let time = |h: u32, m: u32, s: u32| {
+        polars_core::chunked_array::temporal::time_to_time64ns(
+            &NaiveTime::from_hms_opt(h, m, s).unwrap(),
+        )
+    };
+    let ca = Int64Chunked::new(
+        "t",
+        &[
+            time(0, 0, 0),
+            time(9, 14, 59),
+            time(9, 15, 0),
+            time(23, 59, 59),
+        ],
+    )
+    .into_time();
+    let zero = Duration::parse("0ns");
+
+    let out = ca
+        .truncate(Duration::parse("15m"), zero, None, None)
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[
+            Some(time(0, 0, 0)),
+            Some(time(9, 0, 0)),
+            Some(time(9, 15, 0)),
+            Some(time(23, 45, 0))
+        ]
+    );
+    let out = ca
+        .truncate(Duration::parse("1h"), zero, None, None)
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[
+            Some(time(0, 0, 0)),
+            Some(time(9, 0, 0)),
+            Some(time(9, 0, 0)),
+            Some(time(23, 0, 0))
+        ]
+    );
+
+    // buckets that don't tile a day, calendar durations and offsets are rejected
+    assert!(ca
+        .truncate(Duration::parse("7h"), zero, None, None)
+        .is_err());
+    assert!(ca
+        .truncate(Duration::parse("1w"), zero, None, None)
+        .is_err());
+    assert!(ca
+        .truncate(Duration::parse("1mo"), zero, None, None)
+        .is_err());
+    assert!(ca
+        .truncate(Duration::parse("1h"), Duration::parse("5m"), None, None)
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_round_time() {
+    let time = |h: u32, m: u32, s: u32| {
+        polars_core::chunked_array::temporal::time_to_time64ns(
+            &NaiveTime::from_hms_opt(h, m, s).unwrap(),
+        )
+    };
+    let ca = Int64Chunked::new(
+        "t",
+        &[
+            time(9, 14, 59),
+            time(9, 15, 0),
+            time(9, 30, 0),
+            time(23, 58, 0),
+        ],
+    )
+    .into_time();
+    let zero = Duration::parse("0ns");
+
+    // ties are rounded up
+    let out = ca
+        .round(Duration::parse("30m"), zero, None, RoundMode::default())
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[
+            Some(time(9, 0, 0)),
+            Some(time(9, 30, 0)),
+            Some(time(9, 30, 0)),
+            Some(time(0, 0, 0))
+        ]
+    );
+    // and rounding up to midnight wraps around
+    let out = ca
+        .round(Duration::parse("1h"), zero, None, RoundMode::default())
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[
+            Some(time(9, 0, 0)),
+            Some(time(9, 0, 0)),
+            Some(time(10, 0, 0)),
+            Some(time(0, 0, 0))
+        ]
+    );
+
+    assert!(ca
+        .round(Duration::parse("7h"), zero, None, RoundMode::default())
+        .is_err());
+    assert!(ca
+        .round(Duration::parse("1w"), zero, None, RoundMode::default())
+        .is_err());
+    assert!(ca
+        .round(
+            Duration::parse("1h"),
+            Duration::parse("5m"),
+            None,
+            RoundMode::default()
+        )
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "dtype-duration")]
+fn test_round_mode() {
+    use RoundMode::*;
+    let zero = Duration::parse("0ns");
+
+    // Duration, rounded by the fixed path
+    let ca = Int64Chunked::new("d", &[1_500, 2_500, -1_500, 1_499])
+        .into_duration(TimeUnit::Milliseconds);
+    for (mode, expected) in [
+        (HalfToEven, [2_000, 2_000, -2_000, 1_000]),
+        (HalfAwayFromZero, [2_000, 3_000, -2_000, 1_000]),
+        (HalfUp, [2_000, 3_000, -1_000, 1_000]),
+        (HalfDown, [1_000, 2_000, -2_000, 1_000]),
+    ] {
+        let out = ca.round(Duration::parse("1s"), zero, None, mode).unwrap();
+        assert_eq!(Vec::from(&out.0), expected.map(Some), "{mode:?}");
+    }
+
+    // Datetime, rounded by the window
+    let t = |h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let ca = Int64Chunked::new("dt", &[t(0, 30), t(1, 30), t(1, 29)])
+        .into_datetime(TimeUnit::Milliseconds, None);
+    // 2022-01-01 00:00 is an even number of hours after the epoch
+    for (mode, expected) in [
+        (HalfToEven, [t(0, 0), t(2, 0), t(1, 0)]),
+        (HalfAwayFromZero, [t(1, 0), t(2, 0), t(1, 0)]),
+        (HalfUp, [t(1, 0), t(2, 0), t(1, 0)]),
+        (HalfDown, [t(0, 0), t(1, 0), t(1, 0)]),
+    ] {
+        let out = ca.round(Duration::parse("1h"), zero, None, mode).unwrap();
+        assert_eq!(Vec::from(&out.0), expected.map(Some), "{mode:?}");
+    }
+    assert_eq!(RoundMode::default(), HalfUp);
+}
+
+#[test]
+fn test_round_with_offset() {
+    let t = |h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let ca = Int64Chunked::new("dt", &[t(9, 59), t(10, 10), t(10, 50), t(11, 0), t(11, 10)])
+        .into_datetime(TimeUnit::Milliseconds, None);
+    let round = |offset| {
+        let out = ca
+            .round(
+                Duration::parse("1h"),
+                Duration::parse(offset),
+                None,
+                RoundMode::default(),
+            )
+            .unwrap();
+        Vec::from(&out.0)
+    };
+
+    assert_eq!(
+        round("0ns"),
+        [t(10, 0), t(10, 0), t(11, 0), t(11, 0), t(11, 0)].map(Some)
+    );
+    // the buckets are centered on half past the hour, so their edges are on the hour
+    assert_eq!(
+        round("30m"),
+        [t(9, 30), t(10, 30), t(10, 30), t(11, 30), t(11, 30)].map(Some)
+    );
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_list_timezones() {
+    let s = list_timezones();
+    let ca = s.utf8().unwrap();
+    assert!(!ca.is_empty());
+    let names = ca.into_no_null_iter().collect::<Vec<_>>();
+    assert!(names.windows(2).all(|w| w[0] <= w[1]));
+    assert!(names.contains(&"UTC"));
+    assert!(names.contains(&"America/New_York"));
+    // every name parses back into a time zone
+    assert!(names.iter().all(|tz| parse_time_zone(tz).is_ok()));
+}
+
+#[test]
+#[cfg(all(feature = "timezones", feature = "dtype-date"))]
+fn test_truncate_to_calendar() {
+    let tz: polars_arrow::time_zone::Tz = "Asia/Kathmandu".parse().unwrap();
+    // 2024-02-15 is a Thursday, 2024-02-12 the Monday of its week
+    let local = |m: u32, d: u32, h: u32| {
+        NaiveDate::from_ymd_opt(2024, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    };
+    // UTC+05:45, so that midnight in Kathmandu is 18:15 UTC on the day before
+    let utc =
+        |ndt: NaiveDateTime| (ndt - chrono::Duration::minutes(5 * 60 + 45)).timestamp_millis();
+    let ca = Int64Chunked::new("dt", &[Some(utc(local(2, 15, 3))), None])
+        .into_datetime(TimeUnit::Milliseconds, Some("Asia/Kathmandu".into()));
+
+    let out = ca.truncate_to_week(Some(&tz)).unwrap();
+    assert_eq!(Vec::from(&out.0), &[Some(utc(local(2, 12, 0))), None]);
+    assert_eq!(out.time_zone().as_deref(), Some("Asia/Kathmandu"));
+    let out = ca.truncate_to_month(Some(&tz)).unwrap();
+    assert_eq!(Vec::from(&out.0), &[Some(utc(local(2, 1, 0))), None]);
+    let out = ca.truncate_to_year(Some(&tz)).unwrap();
+    assert_eq!(Vec::from(&out.0), &[Some(utc(local(1, 1, 0))), None]);
+
+    // without a time zone, it's truncated in UTC, in which it's still the 14th
+    let out = ca.truncate_to_week(None).unwrap();
+    assert_eq!(
+        Vec::from(&out.0),
+        &[Some(local(2, 12, 0).timestamp_millis()), None]
+    );
+
+    let ca = DateChunked::from_naive_date("date", [NaiveDate::from_ymd_opt(2024, 2, 15).unwrap()]);
+    let out = ca.truncate_to_week(None).unwrap();
+    assert_eq!(out.get(0), ca.get(0).map(|d| d - 3));
+    let out = ca.truncate_to_month(None).unwrap();
+    assert_eq!(out.get(0), ca.get(0).map(|d| d - 14));
+}
+
+#[test]
+fn test_temporal_range_periods() {
+    let ts = |d: u32| {
+        NaiveDate::from_ymd_opt(2022, 1, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let range = |closed: ClosedWindow| {
+        temporal_range_periods(
+            ts(1),
+            3,
+            Duration::parse("1d"),
+            closed,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap()
+    };
+    // closed on the left, the range starts at `start`
+    assert_eq!(range(ClosedWindow::Left), [ts(1), ts(2), ts(3)]);
+    assert_eq!(range(ClosedWindow::Both), [ts(1), ts(2), ts(3)]);
+    // open on the left, it starts one `every` after `start`, with the same number of values
+    assert_eq!(range(ClosedWindow::Right), [ts(2), ts(3), ts(4)]);
+    assert_eq!(range(ClosedWindow::None), [ts(2), ts(3), ts(4)]);
+
+    // matching the range up to the last value, closed on the right
+    for (closed, stop) in [
+        (ClosedWindow::Left, ts(4)),
+        (ClosedWindow::Both, ts(3)),
+        (ClosedWindow::Right, ts(4)),
+        (ClosedWindow::None, ts(5)),
+    ] {
+        let expected = temporal_range_vec(
+            ts(1),
+            stop,
+            Duration::parse("1d"),
+            closed,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap();
+        assert_eq!(range(closed), expected, "closed={closed:?}");
+    }
+
+    let empty = temporal_range_periods(
+        ts(1),
+        0,
+        Duration::parse("1d"),
+        ClosedWindow::None,
+        TimeUnit::Milliseconds,
+        None,
+    )
+    .unwrap();
+    assert!(empty.is_empty());
+}
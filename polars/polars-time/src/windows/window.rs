@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use chrono::NaiveDateTime;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
@@ -5,10 +7,12 @@ use now::DateTimeNow;
 use polars_arrow::export::arrow::temporal_conversions::*;
 use polars_arrow::time_zone::Tz;
 use polars_core::prelude::*;
-use polars_core::utils::arrow::temporal_conversions::{timeunit_scale, SECONDS_IN_DAY};
+use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 
 use crate::prelude::*;
 
+type TimestampFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
 /// Represents a window in time
 #[derive(Copy, Clone)]
 pub struct Window {
@@ -60,24 +64,79 @@ impl Window {
         self.every.truncate_ms(t, tz)
     }
 
+    /// Round the given timestamp to the nearest window boundary, breaking ties by `mode`.
+    ///
+    /// The boundaries are the multiples of `every`, shifted by `offset`.
+    fn round_impl(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        mode: RoundMode,
+        tu: TimeUnit,
+    ) -> PolarsResult<i64> {
+        let (truncate, add): (TimestampFn, TimestampFn) = match tu {
+            TimeUnit::Nanoseconds => (Duration::truncate_ns, Duration::add_ns),
+            TimeUnit::Microseconds => (Duration::truncate_us, Duration::add_us),
+            TimeUnit::Milliseconds => (Duration::truncate_ms, Duration::add_ms),
+        };
+        let t = add(&(self.offset * -1), t, tz)?;
+        let floor = truncate(&self.every, t, tz)?;
+        let ceil = add(&self.every, floor, tz)?;
+        let rounded = match (t - floor).cmp(&(ceil - t)) {
+            Ordering::Less => floor,
+            Ordering::Greater => ceil,
+            Ordering::Equal => {
+                // only needed for ties, as counting calendar periods can be slow
+                let floor_is_even = diff_in_periods(0, floor, self.every, tu, tz)? % 2 == 0;
+                mode.break_tie(floor, ceil, floor_is_even)
+            }
+        };
+        add(&self.offset, rounded, tz)
+    }
+
     /// Round the given ns timestamp by the window boundary.
-    pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns() / 2_i64;
-        self.truncate_ns(t, tz)
+    pub fn round_ns(&self, t: i64, tz: Option<&Tz>, mode: RoundMode) -> PolarsResult<i64> {
+        self.round_impl(t, tz, mode, TimeUnit::Nanoseconds)
     }
 
     /// Round the given us timestamp by the window boundary.
-    pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns()
-            / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Microsecond) as i64);
-        self.truncate_us(t, tz)
+    pub fn round_us(&self, t: i64, tz: Option<&Tz>, mode: RoundMode) -> PolarsResult<i64> {
+        self.round_impl(t, tz, mode, TimeUnit::Microseconds)
     }
 
     /// Round the given ms timestamp by the window boundary.
-    pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let t = t + self.every.duration_ns()
-            / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Millisecond) as i64);
-        self.truncate_ms(t, tz)
+    pub fn round_ms(&self, t: i64, tz: Option<&Tz>, mode: RoundMode) -> PolarsResult<i64> {
+        self.round_impl(t, tz, mode, TimeUnit::Milliseconds)
+    }
+
+    /// Ceil the given ns timestamp by the window boundary.
+    pub fn ceil_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let floor = self.truncate_ns(t, tz)?;
+        if floor == t {
+            Ok(t)
+        } else {
+            self.every.add_ns(floor, tz)
+        }
+    }
+
+    /// Ceil the given us timestamp by the window boundary.
+    pub fn ceil_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let floor = self.truncate_us(t, tz)?;
+        if floor == t {
+            Ok(t)
+        } else {
+            self.every.add_us(floor, tz)
+        }
+    }
+
+    /// Ceil the given ms timestamp by the window boundary.
+    pub fn ceil_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let floor = self.truncate_ms(t, tz)?;
+        if floor == t {
+            Ok(t)
+        } else {
+            self.every.add_ms(floor, tz)
+        }
     }
 
     /// returns the bounds for the earliest window bounds
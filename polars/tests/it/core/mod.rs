@@ -6,6 +6,8 @@ mod ops;
 #[cfg(feature = "pivot")]
 mod pivot;
 #[cfg(feature = "rolling_window")]
+mod rolling_regression;
+#[cfg(feature = "rolling_window")]
 mod rolling_window;
 mod series;
 mod utils;
@@ -0,0 +1,144 @@
+//! Pins the outputs of the core rolling kernels against a naive implementation, and guards
+//! their complexity. The timing test only runs with the `bench` feature, run it with
+//! `cargo test --release --features bench -- rolling_kernels_scaling`.
+use super::*;
+
+type Agg = (
+    &'static str,
+    fn(&Series, RollingOptionsImpl) -> PolarsResult<Series>,
+    fn(&mut [f64]) -> f64,
+);
+
+/// Deterministic values with repeats and both signs.
+fn values(n: usize) -> Vec<f64> {
+    let mut state = 42u64;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 1000) as f64 / 10.0 - 50.0
+        })
+        .collect()
+}
+
+fn quantile(window: &mut [f64], quantile: f64) -> f64 {
+    window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let idx = (window.len() - 1) as f64 * quantile;
+    let (lower, upper) = (window[idx.floor() as usize], window[idx.ceil() as usize]);
+    lower + (upper - lower) * (idx - idx.floor())
+}
+
+fn aggs() -> [Agg; 7] {
+    [
+        ("sum", |s, o| s.rolling_sum(o), |w| w.iter().sum()),
+        (
+            "mean",
+            |s, o| s.rolling_mean(o),
+            |w| w.iter().sum::<f64>() / w.len() as f64,
+        ),
+        (
+            "min",
+            |s, o| s.rolling_min(o),
+            |w| w.iter().copied().fold(f64::INFINITY, f64::min),
+        ),
+        (
+            "max",
+            |s, o| s.rolling_max(o),
+            |w| w.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        ),
+        (
+            "var",
+            |s, o| s.rolling_var(o),
+            |w| {
+                let mean = w.iter().sum::<f64>() / w.len() as f64;
+                w.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (w.len() - 1) as f64
+            },
+        ),
+        ("median", |s, o| s.rolling_median(o), |w| quantile(w, 0.5)),
+        (
+            "quantile",
+            |s, o| s.rolling_quantile(0.25, QuantileInterpolOptions::Linear, o),
+            |w| quantile(w, 0.25),
+        ),
+    ]
+}
+
+fn options(window_size: usize, min_periods: usize) -> RollingOptionsImpl<'static> {
+    RollingOptionsImpl {
+        window_size: Duration::new(window_size as i64),
+        min_periods,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_rolling_kernels_match_naive() {
+    let values = values(2000);
+    let s = Series::new("values", &values);
+
+    for (name, rolling, naive) in aggs() {
+        for window_size in [2, 3, 50, 700] {
+            for min_periods in [2, window_size] {
+                let out = rolling(&s, options(window_size, min_periods)).unwrap();
+                let out = out.f64().unwrap();
+                for (i, out) in out.into_iter().enumerate() {
+                    let mut window = values[(i + 1).saturating_sub(window_size)..=i].to_vec();
+                    let expected = (window.len() >= min_periods).then(|| naive(&mut window));
+                    match (out, expected) {
+                        (Some(out), Some(expected)) => assert!(
+                            (out - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+                            "{name}: window {window_size}, index {i}: {out} != {expected}"
+                        ),
+                        (out, expected) => {
+                            assert_eq!(out, expected, "{name}: window {window_size}, index {i}")
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bench")]
+mod scaling {
+    use std::time::{Duration as StdDuration, Instant};
+
+    use super::*;
+
+    fn time(
+        rolling: fn(&Series, RollingOptionsImpl) -> PolarsResult<Series>,
+        s: &Series,
+        w: usize,
+    ) -> StdDuration {
+        let start = Instant::now();
+        rolling(s, options(w, w)).unwrap();
+        start.elapsed()
+    }
+
+    #[test]
+    fn rolling_kernels_scaling() {
+        let s = Series::new("values", &values(1_000_000));
+        let (small, large) = (10, 10_000);
+
+        for (name, rolling, _) in aggs() {
+            // warm up
+            time(rolling, &s, small);
+            let t_small = time(rolling, &s, small);
+            let t_large = time(rolling, &s, large);
+
+            // sum, mean, min, max and var update their state in (amortized) O(1) per step, so
+            // their run time shouldn't grow with the window size. median and quantile keep their
+            // window sorted, which moves O(window) values per step, but that is a cheap memmove
+            // rather than sorting every window again, which would be well over 1000x slower.
+            let max_ratio = match name {
+                "median" | "quantile" => 250,
+                _ => 10,
+            };
+            assert!(
+                t_large < t_small * max_ratio,
+                "rolling_{name} got slower with a larger window: {t_small:?} vs {t_large:?}"
+            );
+        }
+    }
+}
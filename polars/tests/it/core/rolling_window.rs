@@ -428,3 +428,1533 @@ fn test_median_quantile_types() {
     assert_eq!(*rol_quantile.dtype(), DataType::Float64);
     assert_eq!(*rol_quantile_weighted.dtype(), DataType::Float64);
 }
+
+#[test]
+fn test_rolling_zero_crossings() {
+    let s = Float64Chunked::new("foo", &[1.0, -1.0, 1.0, -1.0]).into_series();
+    let out = s
+        .rolling_zero_crossings(
+            ZeroCrossingPolicy::Skip,
+            RollingOptionsImpl {
+                window_size: Duration::new(4),
+                min_periods: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let out = out.u32().unwrap();
+    assert_eq!(Vec::from(out), &[None, Some(1), Some(2), Some(3)]);
+
+    // windows determined by a time column are not supported
+    let time = [0, 1, 2, 3];
+    let err = s
+        .rolling_zero_crossings(
+            ZeroCrossingPolicy::Skip,
+            RollingOptionsImpl {
+                window_size: Duration::parse("2ms"),
+                by: Some(time.as_slice()),
+                tu: Some(TimeUnit::Milliseconds),
+                closed_window: Some(ClosedWindow::Right),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+}
+
+#[test]
+fn test_rolling_respect_current_null() {
+    let s = Float64Chunked::new("foo", &[Some(1.0), None, Some(3.0), Some(4.0)]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 1,
+        ..Default::default()
+    };
+
+    let out = s.rolling_sum(options.clone()).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[Some(1.0), Some(1.0), Some(3.0), Some(7.0)]
+    );
+
+    let out = s
+        .rolling_sum(RollingOptionsImpl {
+            respect_current_null: true,
+            ..options
+        })
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(Vec::from(out), &[Some(1.0), None, Some(3.0), Some(7.0)]);
+}
+
+#[test]
+fn test_rolling_unsigned_near_max() {
+    let s = UInt32Chunked::new("foo", &[u32::MAX, u32::MAX - 1, 1]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 1,
+        ..Default::default()
+    };
+
+    let out = s.rolling_sum(options.clone()).unwrap();
+    let out = out.u64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[
+            Some(u32::MAX as u64),
+            Some(2 * u32::MAX as u64 - 1),
+            Some(u32::MAX as u64)
+        ]
+    );
+
+    let out = s.rolling_max(options.clone()).unwrap();
+    let out = out.u32().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[Some(u32::MAX), Some(u32::MAX), Some(u32::MAX - 1)]
+    );
+
+    let out = s.rolling_min(options).unwrap();
+    let out = out.u32().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[Some(u32::MAX), Some(u32::MAX - 1), Some(1)]
+    );
+}
+
+#[test]
+fn test_rolling_require_full_window() {
+    let s = Float64Chunked::new("foo", &[1.0, 2.0, 3.0, 4.0]).into_series();
+    let out = s
+        .rolling_sum(RollingOptionsImpl {
+            window_size: Duration::new(3),
+            min_periods: 1,
+            require_full_window: true,
+            ..Default::default()
+        })
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(Vec::from(out), &[None, None, Some(6.0), Some(9.0)]);
+
+    // windows defined by a time column don't have a fixed number of values
+    let time = [0i64, 1, 2, 3];
+    let err = s
+        .rolling_sum(RollingOptionsImpl {
+            window_size: Duration::parse("2ms"),
+            by: Some(time.as_slice()),
+            tu: Some(TimeUnit::Milliseconds),
+            closed_window: Some(ClosedWindow::Right),
+            require_full_window: true,
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+}
+
+#[test]
+fn test_rolling_min_periods_equal_window() {
+    let s = Int32Chunked::new("foo", &[Some(1), Some(2), Some(3), None, Some(5)]).into_series();
+    let options = RollingOptions {
+        window_size: Duration::parse("3i"),
+        ..Default::default()
+    }
+    .with_min_periods_equal_window();
+    // the leading values are null until the window is full, and so is every window with a null
+    let out = s.rolling_sum(options.clone().into()).unwrap();
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[None, None, Some(6), None, None]
+    );
+    let out = s.rolling_max(options.into()).unwrap();
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[None, None, Some(3), None, None]
+    );
+}
+
+#[test]
+fn test_rolling_sum_with_reset_cusum() {
+    // a CUSUM that resets once the running sum exceeds 3
+    let s = Int32Chunked::new("foo", &[1, 1, 2, 1, 1, 3]).into_series();
+    let out = s
+        .rolling_sum_with_reset(
+            &|sum| sum > 3.0,
+            RollingOptionsImpl {
+                window_size: Duration::new(6),
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(0.0),
+            Some(1.0),
+            Some(2.0),
+            Some(0.0)
+        ]
+    );
+
+    // windows determined by a time column and weights are not supported
+    let time = [0, 1, 2, 3, 4, 5];
+    let err = s
+        .rolling_sum_with_reset(
+            &|sum| sum > 3.0,
+            RollingOptionsImpl {
+                window_size: Duration::parse("2ms"),
+                by: Some(time.as_slice()),
+                tu: Some(TimeUnit::Milliseconds),
+                closed_window: Some(ClosedWindow::Right),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let err = s
+        .rolling_sum_with_reset(
+            &|sum| sum > 3.0,
+            RollingOptionsImpl {
+                window_size: Duration::new(2),
+                weights: Some(vec![1.0, 2.0]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("weights are not supported for 'rolling_sum_with_reset'"));
+}
+
+#[test]
+fn test_expanding() {
+    let values = [1.0, 3.0, 2.0, 5.0, 4.0];
+    let s = Float64Chunked::new("foo", &values).into_series();
+
+    let cumsum = values
+        .iter()
+        .scan(0.0, |acc, v| {
+            *acc += v;
+            Some(Some(*acc))
+        })
+        .collect::<Vec<_>>();
+    let out = s.expanding_sum(1).unwrap();
+    assert_eq!(Vec::from(out.f64().unwrap()), cumsum);
+
+    let out = s.expanding_sum(3).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(6.0), Some(11.0), Some(15.0)]
+    );
+
+    let out = s.expanding_max(1).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(3.0), Some(3.0), Some(5.0), Some(5.0)]
+    );
+    let out = s.expanding_min(1).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(1.0), Some(1.0), Some(1.0), Some(1.0)]
+    );
+    let out = s.expanding_mean(2).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(2.0), Some(2.0), Some(2.75), Some(3.0)]
+    );
+
+    // more min_periods than values
+    let out = s.expanding_sum(10).unwrap();
+    assert_eq!(out.null_count(), 5);
+
+    // the running aggregation is carried across chunks, and skips null values
+    let mut s = Float64Chunked::new("foo", &[Some(1.0), None]).into_series();
+    s.append(&Float64Chunked::new("foo", &[Some(3.0), Some(2.0)]).into_series())
+        .unwrap();
+    let out = s.expanding_sum(2).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(4.0), Some(6.0)]
+    );
+    let out = s.expanding_std(1).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(2.0f64.sqrt()), Some(1.0)]
+    );
+
+    // small unsigned integers are summed in u64
+    let s = UInt8Chunked::new("foo", &[200, 100]).into_series();
+    let out = s.expanding_sum(1).unwrap();
+    assert_eq!(Vec::from(out.u64().unwrap()), &[Some(200), Some(300)]);
+}
+
+#[test]
+fn test_rolling_cv() {
+    let s = Int32Chunked::new("foo", &[1, 3, -4, 5, 2, 8, 5]).into_series();
+    let options = || RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 3,
+        ..Default::default()
+    };
+    let out = s.rolling_cv(options()).unwrap();
+    assert_eq!(out.dtype(), &DataType::Float64);
+
+    let std = s.rolling_std(options()).unwrap();
+    let mean = s.rolling_mean(options()).unwrap();
+    let expected = &std / &mean;
+    let mean = mean.f64().unwrap();
+    let out = out.f64().unwrap();
+    for ((out, expected), mean) in out.into_iter().zip(expected.f64().unwrap()).zip(mean) {
+        match (out, expected, mean) {
+            // the window with a zero mean is null
+            (out, _, Some(mean)) if mean == 0.0 => assert_eq!(out, None),
+            (Some(out), Some(expected), _) => assert!((out - expected).abs() < 1e-12),
+            // under-filled windows are null
+            (out, expected, _) => assert_eq!(out, expected),
+        }
+    }
+    assert_eq!(out.null_count(), 3);
+    assert!(out.get(2).is_none());
+
+    // the delta degrees of freedom are taken from the options, like for `rolling_std`
+    let options = || options().with_ddof(0);
+    let out = s.rolling_cv(options()).unwrap();
+    let expected = &s.rolling_std(options()).unwrap() / &s.rolling_mean(options()).unwrap();
+    let out = out.f64().unwrap();
+    assert!((out.get(3).unwrap() - expected.f64().unwrap().get(3).unwrap()).abs() < 1e-12);
+}
+
+#[test]
+fn test_rolling_mad_outlier() {
+    let s = Float64Chunked::new("foo", &[1.0, 2.0, 3.0, 2.0, 100.0, 2.0, 3.0]).into_series();
+    let options = || RollingOptionsImpl {
+        window_size: Duration::new(5),
+        min_periods: 5,
+        ..Default::default()
+    };
+    let mad = s.rolling_mad(false, options()).unwrap();
+    let mad = mad.f64().unwrap();
+    assert_eq!(
+        Vec::from(mad),
+        &[None, None, None, None, Some(1.0), Some(0.0), Some(1.0)]
+    );
+
+    // a single outlier blows up the standard deviation, but not the MAD
+    let std = s.rolling_std(options()).unwrap();
+    assert!(std.f64().unwrap().get(4).unwrap() > 40.0);
+
+    let scaled = s.rolling_mad(true, options()).unwrap();
+    assert_eq!(scaled.f64().unwrap().get(4), Some(1.4826));
+
+    // integers are promoted to f64 and `min_periods` is respected
+    let s = Int32Chunked::new("foo", &[1, 2, 3, 2, 100]).into_series();
+    let out = s
+        .rolling_mad(
+            false,
+            RollingOptionsImpl {
+                window_size: Duration::new(5),
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(1.0), Some(0.5), Some(1.0)]
+    );
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_rolling_gain_loss() {
+    let s = Int32Chunked::new("foo", &[1, -2, 3, 0, -4]).into_series();
+    let out = s
+        .rolling_gain_loss(RollingOptionsImpl {
+            window_size: Duration::new(3),
+            min_periods: 2,
+            ..Default::default()
+        })
+        .unwrap();
+    let out = out.struct_().unwrap();
+    let gains = out.field_by_name("gains").unwrap();
+    let losses = out.field_by_name("losses").unwrap();
+    assert_eq!(
+        Vec::from(gains.f64().unwrap()),
+        &[None, Some(1.0), Some(4.0), Some(3.0), Some(3.0)]
+    );
+    assert_eq!(
+        Vec::from(losses.f64().unwrap()),
+        &[None, Some(2.0), Some(2.0), Some(2.0), Some(4.0)]
+    );
+
+    // windows determined by a time column and weights are not supported
+    let time = [0, 1, 2, 3, 4];
+    let err = s
+        .rolling_gain_loss(RollingOptionsImpl {
+            window_size: Duration::parse("2ms"),
+            by: Some(time.as_slice()),
+            tu: Some(TimeUnit::Milliseconds),
+            closed_window: Some(ClosedWindow::Right),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let err = s
+        .rolling_gain_loss(RollingOptionsImpl {
+            window_size: Duration::new(2),
+            weights: Some(vec![1.0, 2.0]),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("weights are not supported for 'rolling_gain_loss'"));
+}
+
+#[test]
+fn test_rolling_rsi() {
+    let assert_close = |out: Series, expected: &[Option<f64>]| {
+        let out = out.f64().unwrap();
+        assert_eq!(out.len(), expected.len());
+        for (out, expected) in out.into_iter().zip(expected) {
+            match (out, expected) {
+                (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-10),
+                (out, expected) => assert_eq!(out, *expected),
+            }
+        }
+    };
+    let options = || RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 3,
+        ..Default::default()
+    };
+    // changes: [null, 1, 1, -1, 2, -1]
+    let prices = Int32Chunked::new("price", &[10, 11, 12, 11, 13, 12]).into_series();
+
+    let out = rolling_rsi(&prices, false, options()).unwrap();
+    assert_close(
+        out,
+        &[None, None, None, Some(200.0 / 3.0), Some(75.0), Some(50.0)],
+    );
+
+    let out = rolling_rsi(&prices, true, options()).unwrap();
+    assert_close(
+        out,
+        &[
+            None,
+            None,
+            None,
+            Some(200.0 / 3.0),
+            Some(250.0 / 3.0),
+            Some(2000.0 / 33.0),
+        ],
+    );
+
+    // only gains, only losses and no changes at all
+    for wilder in [false, true] {
+        let s = Float64Chunked::new("price", &[1.0, 2.0, 3.0, 4.0]).into_series();
+        let out = rolling_rsi(&s, wilder, options()).unwrap();
+        assert_close(out, &[None, None, None, Some(100.0)]);
+
+        let s = Float64Chunked::new("price", &[4.0, 3.0, 2.0, 1.0]).into_series();
+        let out = rolling_rsi(&s, wilder, options()).unwrap();
+        assert_close(out, &[None, None, None, Some(0.0)]);
+
+        let s = Float64Chunked::new("price", &[1.0, 1.0, 1.0, 1.0]).into_series();
+        let out = rolling_rsi(&s, wilder, options()).unwrap();
+        assert_eq!(out.null_count(), 4);
+    }
+
+    // windows determined by a time column and weights are not supported
+    let time = [0, 1, 2, 3, 4, 5];
+    let by_time = RollingOptionsImpl {
+        window_size: Duration::parse("3ms"),
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+    let err = rolling_rsi(&prices, false, by_time).unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let mut weighted = options();
+    weighted.weights = Some(vec![1.0, 2.0, 3.0]);
+    assert!(rolling_rsi(&prices, false, weighted).is_err());
+}
+
+#[test]
+fn test_rolling_autocorr_sinusoid() {
+    // a sine with a period of 8
+    let values = (0..32)
+        .map(|t| (std::f64::consts::PI * t as f64 / 4.0).sin())
+        .collect::<Vec<_>>();
+    let s = Float64Chunked::new("foo", &values).into_series();
+    let options = || RollingOptionsImpl {
+        window_size: Duration::new(16),
+        min_periods: 16,
+        ..Default::default()
+    };
+
+    // shifted by half a period the sine is mirrored, by a full period it is itself
+    for (lag, expected) in [(4, -1.0), (8, 1.0)] {
+        let out = s.rolling_autocorr(lag, options()).unwrap();
+        let out = out.f64().unwrap();
+        assert_eq!(out.null_count(), 15);
+        for v in out.into_iter().flatten() {
+            assert!((v - expected).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_rolling_range() {
+    let s = Int32Chunked::new(
+        "foo",
+        &[
+            Some(3),
+            Some(-1),
+            None,
+            Some(7),
+            Some(2),
+            Some(2),
+            None,
+            None,
+            Some(-5),
+        ],
+    )
+    .into_series();
+
+    for center in [false, true] {
+        let options = || RollingOptionsImpl {
+            window_size: Duration::new(3),
+            min_periods: 1,
+            center,
+            ..Default::default()
+        };
+        let out = s.rolling_range(options()).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+
+        let expected = (&s.rolling_max(options()).unwrap() - &s.rolling_min(options()).unwrap())
+            .cast(&DataType::Float64)
+            .unwrap();
+        assert!(out.series_equal_missing(&expected));
+    }
+}
+
+#[test]
+fn test_rolling_null_fraction_burst() {
+    let s = Float64Chunked::new(
+        "foo",
+        &[
+            Some(1.0),
+            Some(2.0),
+            None,
+            None,
+            None,
+            Some(3.0),
+            Some(4.0),
+            Some(5.0),
+        ],
+    )
+    .into_series();
+    let out = s
+        .rolling_null_fraction(RollingOptionsImpl {
+            window_size: Duration::new(3),
+            min_periods: 1,
+            center: true,
+            ..Default::default()
+        })
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(out.null_count(), 0);
+    let expected = [
+        0.0,
+        1.0 / 3.0,
+        2.0 / 3.0,
+        1.0,
+        2.0 / 3.0,
+        1.0 / 3.0,
+        0.0,
+        0.0,
+    ];
+    for (v, e) in out.into_no_null_iter().zip(expected) {
+        assert!((v - e).abs() < 1e-12);
+    }
+    // the fraction peaks in the middle of the burst of nulls
+    assert_eq!(out.get(3), Some(1.0));
+
+    // windows determined by a time column are not supported
+    let time = [0, 1, 2, 3, 4, 5, 6, 7];
+    let err = s
+        .rolling_null_fraction(RollingOptionsImpl {
+            window_size: Duration::parse("3ms"),
+            by: Some(time.as_slice()),
+            tu: Some(TimeUnit::Milliseconds),
+            closed_window: Some(ClosedWindow::Right),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+}
+
+#[test]
+fn test_rolling_weights_order() {
+    let s = Float64Chunked::new("foo", &[1.0, 2.0, 3.0, 4.0, 5.0]).into_series();
+    let options = |weights_order| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 3,
+        weights: Some(vec![1.0, 0.0, 0.0]),
+        weights_order,
+        ..Default::default()
+    };
+
+    // by default the first weight multiplies the oldest value of the window
+    let out = s.rolling_sum(options(WeightsOrder::OldestFirst)).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[None, None, Some(1.0), Some(2.0), Some(3.0)]
+    );
+
+    // as the taps of a causal filter, the first weight multiplies the current value
+    let out = s.rolling_sum(options(WeightsOrder::NewestFirst)).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[None, None, Some(3.0), Some(4.0), Some(5.0)]
+    );
+}
+
+#[test]
+fn test_rolling_multiple_chunks() {
+    let values = (0..20)
+        .map(|i| if i % 7 == 3 { None } else { Some(i as f64) })
+        .collect::<Vec<_>>();
+    let single = Float64Chunked::new("foo", &values).into_series();
+    let chunked = |chunk_size: usize| {
+        let mut multi = Float64Chunked::new("foo", &values[..chunk_size]).into_series();
+        for part in values[chunk_size..].chunks(chunk_size) {
+            multi
+                .append(&Float64Chunked::new("foo", part).into_series())
+                .unwrap();
+        }
+        multi
+    };
+
+    // chunks of 5 hold a window of 4, and are aggregated one by one, rather than rechunked
+    // first. Windows shorter than the chunks of 3 span several of them, so those are rechunked.
+    for (chunk_size, n_chunks) in [(5, 4), (3, 1)] {
+        let multi = chunked(chunk_size);
+        assert!(multi.n_chunks() > 1);
+        for center in [false, true] {
+            let options = || RollingOptionsImpl {
+                window_size: Duration::new(4),
+                min_periods: 2,
+                center,
+                ..Default::default()
+            };
+            for (single, multi) in [
+                (
+                    single.rolling_sum(options()).unwrap(),
+                    multi.rolling_sum(options()).unwrap(),
+                ),
+                (
+                    single.rolling_min(options()).unwrap(),
+                    multi.rolling_min(options()).unwrap(),
+                ),
+                (
+                    single.rolling_max(options()).unwrap(),
+                    multi.rolling_max(options()).unwrap(),
+                ),
+                (
+                    single.rolling_mean(options()).unwrap(),
+                    multi.rolling_mean(options()).unwrap(),
+                ),
+            ] {
+                assert!(multi.series_equal_missing(&single));
+                assert_eq!(multi.n_chunks(), n_chunks);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rolling_std_var_transform() {
+    let s = Float64Chunked::new(
+        "foo",
+        &[Some(1.0), Some(4.0), None, Some(2.0), Some(8.0), Some(3.0)],
+    )
+    .into_series();
+    let options = |var_transform| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 2,
+        var_transform,
+        ..Default::default()
+    };
+
+    let std = s.rolling_std(options(RollingVarTransform::None)).unwrap();
+    let out = s.rolling_std(options(RollingVarTransform::Log1p)).unwrap();
+    assert_eq!(out.null_count(), std.null_count());
+    for (out, std) in out.f64().unwrap().into_iter().zip(std.f64().unwrap()) {
+        match (out, std) {
+            (Some(out), Some(std)) => assert!((out - (1.0 + std).ln()).abs() < 1e-12),
+            (out, std) => assert_eq!(out, std),
+        }
+    }
+
+    // the square root of the variance is the standard deviation
+    let out = s.rolling_var(options(RollingVarTransform::Sqrt)).unwrap();
+    for (out, std) in out.f64().unwrap().into_iter().zip(std.f64().unwrap()) {
+        match (out, std) {
+            (Some(out), Some(std)) => assert!((out - std).abs() < 1e-12),
+            (out, std) => assert_eq!(out, std),
+        }
+    }
+}
+
+#[test]
+fn test_rolling_dot_template() {
+    let s = Int32Chunked::new("foo", &[0, 1, 3, 1, 0, 0, 1, 3, 1, 0]).into_series();
+    let template = [1.0, 3.0, 1.0];
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 3,
+        ..Default::default()
+    };
+    let out = s.rolling_dot(&template, options.clone()).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(
+        Vec::from(out),
+        &[
+            None,
+            None,
+            Some(6.0),
+            Some(11.0),
+            Some(6.0),
+            Some(1.0),
+            Some(1.0),
+            Some(6.0),
+            Some(11.0),
+            Some(6.0)
+        ]
+    );
+    // the dot product peaks where the window ends on a copy of the template
+    let peaks = out
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, v)| (v == Some(11.0)).then_some(i))
+        .collect::<Vec<_>>();
+    assert_eq!(peaks, &[3, 8]);
+
+    assert!(s.rolling_dot(&[1.0, 3.0], options).is_err());
+}
+
+#[test]
+fn test_rolling_rs_ramp() {
+    let s = Int64Chunked::new("foo", &[1, 2, 3, 4, 5, 6]).into_series();
+    let out = s
+        .rolling_rs(RollingOptionsImpl {
+            window_size: Duration::new(4),
+            min_periods: 4,
+            ..Default::default()
+        })
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(out.null_count(), 3);
+    // a ramp of length 4 has cumulative deviations [-1.5, -2, -1.5, 0] and a std of sqrt(1.25)
+    let expected = 2.0 / 1.25f64.sqrt();
+    for v in out.into_iter().skip(3) {
+        assert!((v.unwrap() - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_rolling_time_since_event() {
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 5, 6, 9, 10].map(|h| h * hour);
+    let events = BooleanChunked::new(
+        "events",
+        &[
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+        ],
+    );
+    let out = polars_time::prelude::rolling_time_since_event(
+        &events,
+        RollingOptionsImpl {
+            window_size: Duration::parse("4h"),
+            by: Some(time.as_slice()),
+            tu: Some(TimeUnit::Milliseconds),
+            closed_window: Some(ClosedWindow::Right),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+    let out = out.duration().unwrap();
+    // the events at 1h and 6h drop out of the (t - 4h, t] windows at 5h and 10h
+    assert_eq!(
+        Vec::from(&out.0),
+        &[
+            None,
+            Some(0),
+            Some(hour),
+            None,
+            Some(0),
+            Some(3 * hour),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_rolling_kurtosis() {
+    let s = Float64Chunked::new(
+        "foo",
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            None,
+            Some(10.0),
+            Some(10.0),
+            Some(10.0),
+        ],
+    )
+    .into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(4),
+        min_periods: 3,
+        ..Default::default()
+    };
+    let out = s
+        .rolling_kurtosis(RollingKurtosisParams::default(), options.clone())
+        .unwrap();
+    let out = out.f64().unwrap();
+    // scipy.stats.kurtosis([1, 2, 3]) is -1.5 and scipy.stats.kurtosis([1, 2, 3, 4]) is -1.36
+    assert_eq!(out.get(0), None);
+    assert!((out.get(2).unwrap() + 1.5).abs() < 1e-12);
+    assert!((out.get(3).unwrap() + 1.36).abs() < 1e-12);
+    // the window of the null value still holds [2, 3, 4]
+    assert!((out.get(4).unwrap() + 1.5).abs() < 1e-12);
+    // a window without variance is null rather than NaN
+    assert_eq!(out.get(7), None);
+
+    // a bias-corrected kurtosis needs four values
+    let params = RollingKurtosisParams {
+        fisher: true,
+        bias: false,
+    };
+    let out = s.rolling_kurtosis(params, options).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(out.get(2), None);
+    assert!((out.get(3).unwrap() + 1.2).abs() < 1e-12);
+    assert_eq!(out.get(4), None);
+}
+
+#[test]
+fn test_rolling_first_last() {
+    let s = Int32Chunked::new("foo", &[None, Some(1), None, Some(3), Some(4), None]).into_series();
+    let options = |min_periods, center| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods,
+        center,
+        ..Default::default()
+    };
+
+    // the windows sliding off the start hold fewer than `min_periods` valid values
+    let out = s.rolling_first(options(2, false)).unwrap();
+    assert_eq!(out.dtype(), &DataType::Int32);
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[None, None, None, Some(1), Some(3), Some(3)]
+    );
+    let out = s.rolling_last(options(2, false)).unwrap();
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[None, None, None, Some(3), Some(4), Some(4)]
+    );
+
+    let out = s.rolling_first(options(1, true)).unwrap();
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[Some(1), Some(1), Some(1), Some(3), Some(3), Some(4)]
+    );
+    let out = s.rolling_last(options(1, true)).unwrap();
+    assert_eq!(
+        Vec::from(out.i32().unwrap()),
+        &[Some(1), Some(1), Some(3), Some(4), Some(4), Some(4)]
+    );
+
+    let mut with_weights = options(1, false);
+    with_weights.weights = Some(vec![1.0, 1.0, 1.0]);
+    assert!(s.rolling_first(with_weights).is_err());
+
+    // windows determined by a time column
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 5].map(|h| h * hour);
+    let s = Float64Chunked::new("foo", &[1.0, 2.0, 3.0, 4.0]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("2h"),
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+    let out = s.rolling_first(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(1.0), Some(2.0), Some(4.0)]
+    );
+    let out = s.rolling_last(options).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+    );
+}
+
+#[test]
+fn test_rolling_mode() {
+    let s = Int64Chunked::new(
+        "foo",
+        &[
+            Some(2),
+            Some(2),
+            Some(1),
+            Some(5),
+            Some(3),
+            None,
+            None,
+            None,
+        ],
+    )
+    .into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 1,
+        ..Default::default()
+    };
+    let out = s.rolling_mode(options.clone()).unwrap();
+    assert_eq!(out.dtype(), &DataType::Int64);
+    // [2, 2, 1] has a clear winner, [2, 1, 5] and [5, 3, null] are ties that go to the
+    // smallest value and the last window only holds nulls
+    assert_eq!(
+        Vec::from(out.i64().unwrap()),
+        &[
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(3),
+            Some(3),
+            None
+        ]
+    );
+
+    let mut with_weights = options;
+    with_weights.weights = Some(vec![1.0, 1.0, 1.0]);
+    assert!(s.rolling_mode(with_weights).is_err());
+}
+
+#[test]
+fn test_rolling_map_by_time_weighted_mean() {
+    let hour = 3_600_000;
+    let by = Int64Chunked::new("time", &[0, 1, 3, 4, 6].map(|h| h * hour))
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let values = Float64Chunked::new("values", &[1.0, 2.0, 3.0, 4.0, 5.0]).into_series();
+
+    // every value holds until the next timestamp of the window
+    let time_weighted_mean = |s: &Series, time: &[i64]| {
+        let values = s.f64().unwrap().cont_slice().unwrap();
+        let mean = if time.len() < 2 {
+            values.first().copied()
+        } else {
+            let weighted = time
+                .windows(2)
+                .zip(values)
+                .map(|(t, v)| v * (t[1] - t[0]) as f64)
+                .sum::<f64>();
+            Some(weighted / (time[time.len() - 1] - time[0]) as f64)
+        };
+        Series::new("", &[mean])
+    };
+    let out = polars_time::prelude::rolling_map_by(
+        &values,
+        &by,
+        &time_weighted_mean,
+        Duration::parse("4h"),
+        ClosedWindow::Right,
+    )
+    .unwrap();
+    assert_eq!(out.name(), "values");
+    let out = Vec::from(out.f64().unwrap());
+    let expected = [1.0, 1.0, 5.0 / 3.0, 7.0 / 3.0, 11.0 / 3.0];
+    assert_eq!(out.len(), expected.len());
+    for (out, expected) in out.into_iter().zip(expected) {
+        assert!((out.unwrap() - expected).abs() < 1e-12);
+    }
+
+    // the window of the first value is empty if the current row is excluded
+    let window_len = |s: &Series, time: &[i64]| {
+        assert_eq!(s.len(), time.len());
+        Series::new("", &[time.len() as u32])
+    };
+    let out = polars_time::prelude::rolling_map_by(
+        &values,
+        &by,
+        &window_len,
+        Duration::parse("4h"),
+        ClosedWindow::Left,
+    )
+    .unwrap();
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[Some(0), Some(1), Some(2), Some(3), Some(2)]
+    );
+}
+
+#[test]
+fn test_rolling_rank() {
+    let s = Int32Chunked::new("foo", &[1, 3, 3, 2, 3]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(4),
+        min_periods: 2,
+        ..Default::default()
+    };
+    let params = |method, descending| RollingRankParams { method, descending };
+    let out = s
+        .rolling_rank(params(RollingRankMethod::Max, false), options.clone())
+        .unwrap();
+    assert_eq!(out.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(2.0), Some(3.0), Some(2.0), Some(4.0)]
+    );
+
+    let mut with_weights = options;
+    with_weights.weights = Some(vec![1.0; 4]);
+    assert!(s
+        .rolling_rank(params(RollingRankMethod::Max, false), with_weights)
+        .is_err());
+
+    // windows determined by a time column
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 3].map(|h| h * hour);
+    let s = Float64Chunked::new("foo", &[3.0, 1.0, 3.0, 2.0]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("3h"),
+        min_periods: 2,
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+    let out = s
+        .rolling_rank(params(RollingRankMethod::Average, false), options.clone())
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(1.0), Some(2.5), Some(2.0)]
+    );
+    let out = s
+        .rolling_rank(params(RollingRankMethod::Dense, true), options)
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(2.0), Some(1.0), Some(2.0)]
+    );
+}
+
+#[test]
+fn test_rolling_corr_cov() {
+    let x = [1.0, 2.0, 4.0, 7.0, 11.0, 16.0];
+    let y = [2.0, 1.0, 5.0, 3.0, 8.0, 13.0];
+    let a = Series::new("a", &x);
+    let b = Series::new("b", &y);
+    let options = |min_periods| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods,
+        ..Default::default()
+    };
+
+    let pearson = |x: &[f64], y: &[f64]| {
+        let n = x.len() as f64;
+        let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+        let cov = x
+            .iter()
+            .zip(y)
+            .map(|(x, y)| (x - mx) * (y - my))
+            .sum::<f64>();
+        let vx = x.iter().map(|x| (x - mx).powi(2)).sum::<f64>();
+        let vy = y.iter().map(|y| (y - my).powi(2)).sum::<f64>();
+        (cov / (n - 1.0), cov / (vx * vy).sqrt())
+    };
+    let corr = polars_time::prelude::rolling_corr(&a, &b, options(3)).unwrap();
+    let cov = polars_time::prelude::rolling_cov(&a, &b, 1, options(3)).unwrap();
+    assert_eq!(corr.name(), "a");
+    let corr = Vec::from(corr.f64().unwrap());
+    let cov = Vec::from(cov.f64().unwrap());
+    assert_eq!(corr[..2], [None, None]);
+    assert_eq!(cov[..2], [None, None]);
+    for i in 2..x.len() {
+        let (expected_cov, expected_corr) = pearson(&x[i - 2..=i], &y[i - 2..=i]);
+        assert!((cov[i].unwrap() - expected_cov).abs() < 1e-12);
+        assert!((corr[i].unwrap() - expected_corr).abs() < 1e-12);
+    }
+
+    // the pair with a null is skipped, so the windows holding it only have two pairs left
+    let b = Series::new(
+        "b",
+        &[Some(2.0), Some(1.0), None, Some(3.0), Some(8.0), Some(13.0)],
+    );
+    let out = polars_time::prelude::rolling_corr(&a, &b, options(3)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(pearson(&x[3..], &y[3..]).1)
+        ]
+    );
+    let out = polars_time::prelude::rolling_corr(&a, &b, options(2)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap())[..5],
+        [None, Some(-1.0), Some(-1.0), Some(1.0), Some(1.0)]
+    );
+
+    assert!(polars_time::prelude::rolling_corr(&a, &b.slice(0, 5), options(3)).is_err());
+}
+
+#[test]
+fn test_rolling_map() {
+    let s = Series::new(
+        "foo",
+        &[
+            Some(1.0),
+            Some(4.0),
+            None,
+            Some(2.0),
+            Some(8.0),
+            Some(-3.0),
+            None,
+            None,
+        ],
+    );
+    let options = |min_periods, center, weights| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods,
+        center,
+        weights,
+        ..Default::default()
+    };
+
+    let mean: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>> =
+        Arc::new(|s| Ok(s.mean().map_or(AnyValue::Null, AnyValue::Float64)));
+    for (min_periods, center) in [(1, false), (2, false), (1, true), (3, true)] {
+        let out = s
+            .rolling_map(mean.clone(), options(min_periods, center, None))
+            .unwrap();
+        let expected = s.rolling_mean(options(min_periods, center, None)).unwrap();
+        assert!(out.series_equal_missing(&expected));
+    }
+
+    // the weights are applied before the closure sees the window
+    let sum: Arc<dyn Fn(&Series) -> PolarsResult<AnyValue>> =
+        Arc::new(|s| Ok(AnyValue::Float64(s.sum::<f64>().unwrap_or(0.0))));
+    let s = Series::new("foo", &[1.0, 4.0, 2.0, 8.0, -3.0]);
+    let weights = Some(vec![1.0, 2.0, 3.0]);
+    let out = s
+        .rolling_map(sum, options(3, false, weights.clone()))
+        .unwrap();
+    let expected = s.rolling_sum(options(3, false, weights)).unwrap();
+    assert!(out.series_equal_missing(&expected));
+
+    // a null current value masks the output, like for the built-in aggregations
+    let s = Series::new("foo", &[Some(1.0), None, Some(3.0), Some(4.0)]);
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 1,
+        respect_current_null: true,
+        ..Default::default()
+    };
+    let out = s.rolling_map(mean, options.clone()).unwrap();
+    let expected = s.rolling_mean(options).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), None, Some(3.0), Some(3.5)]
+    );
+    assert!(out.series_equal_missing(&expected));
+}
+
+#[test]
+fn test_rolling_arg_min_max() {
+    let s = Int64Chunked::new("foo", &[1, 2, 3, 4, 4, 2]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 3,
+        ..Default::default()
+    };
+    // [1, 2, 3] and [2, 3, 4] are monotonic, [3, 4, 4] and [4, 4, 2] have a tie at the end
+    // and the start of the window
+    let out = s.rolling_argmax(options.clone()).unwrap();
+    assert_eq!(out.dtype(), &DataType::UInt32);
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[None, None, Some(2), Some(2), Some(1), Some(0)]
+    );
+    let out = s.rolling_argmin(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[None, None, Some(0), Some(0), Some(0), Some(2)]
+    );
+
+    let mut with_weights = options;
+    with_weights.weights = Some(vec![1.0, 2.0, 3.0]);
+    assert!(s.rolling_argmax(with_weights).is_err());
+
+    // windows determined by a time column, the offsets are relative to the first row
+    // in the window
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 5, 6].map(|h| h * hour);
+    let s = Float64Chunked::new("foo", &[3.0, 1.0, 3.0, 2.0, 2.0]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("3h"),
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+    let out = s.rolling_argmax(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[Some(0), Some(0), Some(0), Some(0), Some(0)]
+    );
+    let out = s.rolling_argmin(options).unwrap();
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[Some(0), Some(1), Some(1), Some(0), Some(0)]
+    );
+}
+
+#[test]
+fn test_rolling_n_unique() {
+    let s = Int32Chunked::new(
+        "foo",
+        &[Some(1), Some(2), Some(1), Some(3), None, None, None],
+    )
+    .into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 1,
+        ..Default::default()
+    };
+    let out = s.rolling_n_unique(options).unwrap();
+    assert_eq!(out.dtype(), &DataType::UInt32);
+    // the window gains a duplicate 1 at 2 and loses it at 3, the last window only holds nulls
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[Some(1), Some(2), Some(2), Some(3), Some(2), Some(1), None]
+    );
+
+    // windows determined by a time column
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 5, 6].map(|h| h * hour);
+    let s = Float64Chunked::new("foo", &[1.0, 1.0, 2.0, 2.0, 3.0]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("2h"),
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+    let out = s.rolling_n_unique(options).unwrap();
+    assert_eq!(
+        Vec::from(out.u32().unwrap()),
+        &[Some(1), Some(1), Some(2), Some(1), Some(2)]
+    );
+}
+
+#[test]
+fn test_rolling_by_with_nulls() {
+    let hour = 3_600_000;
+    let time = [0, 1, 2, 3, 4].map(|h| h * hour);
+    let s =
+        Float64Chunked::new("foo", &[Some(1.0), None, Some(3.0), None, Some(5.0)]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("3h"),
+        min_periods: 1,
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+
+    // the nulls are skipped
+    let out = s.rolling_sum(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(1.0), Some(4.0), Some(3.0), Some(8.0)]
+    );
+    let out = s.rolling_max(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(1.0), Some(1.0), Some(3.0), Some(3.0), Some(5.0)]
+    );
+
+    // and count as missing for `min_periods`
+    let out = s
+        .rolling_mean(RollingOptionsImpl {
+            min_periods: 2,
+            ..options.clone()
+        })
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(2.0), None, Some(4.0)]
+    );
+
+    // kernels without null support return an error instead of panicking
+    let params = RollingRankParams::default();
+    assert!(s.rolling_rank(params, options).is_err());
+}
+
+#[test]
+fn test_rolling_by_center() {
+    // irregular time index, every value is a distinct power of two so that the sum of a window
+    // tells which values are in it
+    let hour = 3_600_000;
+    let time = [0, 1, 3, 4, 7, 8].map(|h| h * hour);
+    let s = Float64Chunked::new("foo", &[1.0, 2.0, 4.0, 8.0, 16.0, 32.0]).into_series();
+    let options = |closed_window| RollingOptionsImpl {
+        window_size: Duration::parse("4h"),
+        min_periods: 1,
+        center: true,
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(closed_window),
+        ..Default::default()
+    };
+
+    // (t - 2h, t + 2h]: the value at 1h is just out of the window of 3h
+    let out = s.rolling_sum(options(ClosedWindow::Right)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            Some(3.0),
+            Some(7.0),
+            Some(12.0),
+            Some(12.0),
+            Some(48.0),
+            Some(48.0)
+        ]
+    );
+    // [t - 2h, t + 2h]
+    let out = s.rolling_sum(options(ClosedWindow::Both)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            Some(3.0),
+            Some(7.0),
+            Some(14.0),
+            Some(12.0),
+            Some(48.0),
+            Some(48.0)
+        ]
+    );
+    // [t - 2h, t + 2h): the value at 3h is just out of the window of 1h
+    let out = s.rolling_sum(options(ClosedWindow::Left)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            Some(3.0),
+            Some(3.0),
+            Some(14.0),
+            Some(12.0),
+            Some(48.0),
+            Some(48.0)
+        ]
+    );
+
+    // a month can't be split in two
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("1mo"),
+        ..options(ClosedWindow::Right)
+    };
+    assert!(s.rolling_sum(options).is_err());
+}
+
+#[test]
+fn test_rolling_weights_length() {
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 1,
+        weights: Some(vec![1.0, 2.0]),
+        ..Default::default()
+    };
+    let no_nulls = Float64Chunked::new("foo", &[1.0, 2.0, 3.0, 4.0]).into_series();
+    let nulls = Float64Chunked::new("foo", &[Some(1.0), None, Some(3.0), Some(4.0)]).into_series();
+    for s in [no_nulls, nulls] {
+        let msg = s.rolling_mean(options.clone()).unwrap_err().to_string();
+        assert!(msg.contains("`weights` length (2) must equal `window_size` (3)"));
+        let msg = s
+            .rolling_quantile(0.5, QuantileInterpolOptions::Linear, options.clone())
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("`weights` length (2) must equal `window_size` (3)"));
+    }
+}
+
+#[test]
+fn test_rolling_var_std_ddof() {
+    let s = Float64Chunked::new("foo", &[Some(1.0), Some(5.0), Some(3.0), None, Some(4.0)])
+        .into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 2,
+        ..Default::default()
+    };
+
+    let out = s.rolling_var(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(8.0), Some(2.0), None, None]
+    );
+    let out = s.rolling_var(options.clone().with_ddof(0)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(4.0), Some(1.0), None, None]
+    );
+    let out = s.rolling_std(options.clone().with_ddof(0)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(2.0), Some(1.0), None, None]
+    );
+
+    // windows defined by a time column
+    let hour = 3_600_000;
+    let time = [0, 1, 2].map(|h| h * hour);
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("2h"),
+        min_periods: 1,
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..options
+    };
+    let s = s.slice(0, 3);
+    let out = s.rolling_var(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(0.0), Some(8.0), Some(2.0)]
+    );
+    let out = s.rolling_var(options.with_ddof(0)).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[Some(0.0), Some(4.0), Some(1.0)]
+    );
+}
+
+#[test]
+fn test_rolling_quantile_by() {
+    let hour = 3_600_000;
+    // an irregular time index
+    let time = [0, 1, 3, 4, 8, 9, 10].map(|h| h * hour);
+    let values = [
+        Some(5.0),
+        Some(1.0),
+        None,
+        Some(2.0),
+        Some(7.0),
+        Some(3.0),
+        Some(4.0),
+    ];
+    let s = Float64Chunked::new("foo", &values).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("3h"),
+        min_periods: 2,
+        by: Some(time.as_slice()),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+
+    // the quantile of the valid values in (t - 3h, t], if there are at least two of them
+    let expected = |quantile: f64, interpolation: QuantileInterpolOptions| {
+        time.iter()
+            .map(|&t| {
+                let window = time
+                    .iter()
+                    .zip(values)
+                    .filter(|&(&u, _)| t - 3 * hour < u && u <= t)
+                    .filter_map(|(_, v)| v)
+                    .collect::<Vec<_>>();
+                if window.len() < 2 {
+                    return None;
+                }
+                Float64Chunked::from_vec("", window)
+                    .quantile(quantile, interpolation)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for interpolation in [
+        QuantileInterpolOptions::Nearest,
+        QuantileInterpolOptions::Lower,
+        QuantileInterpolOptions::Higher,
+        QuantileInterpolOptions::Midpoint,
+        QuantileInterpolOptions::Linear,
+    ] {
+        for quantile in [0.0, 0.3, 0.5, 1.0] {
+            let out = s
+                .rolling_quantile(quantile, interpolation, options.clone())
+                .unwrap();
+            assert_eq!(
+                Vec::from(out.f64().unwrap()),
+                expected(quantile, interpolation),
+                "quantile {quantile} with {interpolation:?}"
+            );
+        }
+    }
+
+    let out = s.rolling_median(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        expected(0.5, QuantileInterpolOptions::Linear)
+    );
+    // the windows at 1h, 4h and 10h hold [5, 1], [2] and [7, 3, 4]
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, Some(3.0), None, None, None, Some(5.0), Some(4.0)]
+    );
+
+    // without nulls
+    let s = Float64Chunked::new("foo", &[5.0, 1.0, 6.0, 2.0, 7.0, 3.0, 4.0]).into_series();
+    let out = s.rolling_median(options).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            Some(5.0),
+            Some(3.0),
+            Some(3.5),
+            Some(4.0),
+            Some(7.0),
+            Some(5.0),
+            Some(4.0)
+        ]
+    );
+}
@@ -5,6 +5,8 @@ mod filter;
 #[cfg(feature = "is_in")]
 mod is_in;
 mod slice;
+#[cfg(feature = "temporal")]
+mod temporal;
 mod window;
 
 use super::*;
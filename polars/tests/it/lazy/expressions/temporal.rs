@@ -0,0 +1,1182 @@
+use polars::export::chrono::{NaiveDate, NaiveTime};
+
+use super::*;
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_month_and_day_name() -> PolarsResult<()> {
+    // 2023-01-04 is a Wednesday
+    let date = NaiveDate::from_ymd_opt(2023, 1, 4).unwrap();
+    let s = Series::new("date", &[Some(date), None]);
+    let df = DataFrame::new(vec![s])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("date").dt().month_name().alias("month_name"),
+            col("date").dt().day_name().alias("day_name"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "month_name" => [Some("January"), None],
+        "day_name" => [Some("Wednesday"), None],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_date_range_sub_day_interval_error() {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 1, 5).unwrap();
+    let err = df!["a" => [1]]
+        .unwrap()
+        .lazy()
+        .select([date_range(
+            lit(start),
+            lit(stop),
+            Duration::parse("1d12h"),
+            ClosedWindow::Both,
+            None,
+            true,
+        )])
+        .collect()
+        .unwrap_err();
+
+    let msg = err.to_string();
+    assert!(msg.contains("0 month(s), 0 week(s), 1 day(s) and 43200000000000 nanosecond(s)"));
+    assert!(msg.contains("the sub-day part of 43200000000000 nanosecond(s) is not allowed"));
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_date_range_invalid_input_error() -> PolarsResult<()> {
+    let empty = Series::new_empty("start", &DataType::Date);
+    let err = df!["a" => [1]]?
+        .lazy()
+        .select([date_range(
+            lit(empty.clone()),
+            lit(empty),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            None,
+            true,
+        )])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("'start' and 'stop' of a date range cannot be empty"));
+
+    let err = df!["a" => [1]]?
+        .lazy()
+        .select([date_range(
+            lit(1i64),
+            lit(5i64),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            None,
+            true,
+        )])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("should be of type Date, Datetime or Time, got i64"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_date_range_strict() -> PolarsResult<()> {
+    let date = |m: u32, d: u32| NaiveDate::from_ymd_opt(2022, m, d).unwrap();
+    let ms = |m: u32, d: u32| date(m, d).and_hms_opt(0, 0, 0).unwrap().timestamp_millis();
+    // i64::MAX milliseconds is far beyond the last Date, so the second `stop` can't be cast
+    let df = df![
+        "start" => [date(1, 1), date(1, 31)],
+        "stop" => Int64Chunked::new("stop", &[ms(3, 1), i64::MAX])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series(),
+    ]?;
+    let range = |every: &str, strict| {
+        df.clone()
+            .lazy()
+            // keep `stop` a Datetime, rather than casting both bounds to their supertype
+            .with_type_coercion(false)
+            .select([date_range(
+                col("start"),
+                col("stop"),
+                Duration::parse(every),
+                ClosedWindow::Both,
+                None,
+                strict,
+            )])
+            .collect()
+    };
+
+    assert!(range("1mo", true).is_err());
+
+    let out = range("1mo", false)?;
+    let out = out.column("start")?.list()?;
+    assert_eq!(out.null_count(), 1);
+    let first = out.get(0).unwrap();
+    let expected = Series::new("", [date(1, 1), date(2, 1), date(3, 1)]);
+    assert!(first.series_equal(&expected));
+    assert!(out.get(1).is_none());
+
+    // only the casts are lenient, a range that can't be created still raises
+    let err = range("0d", false).unwrap_err();
+    assert!(err.to_string().contains("'interval' cannot be zero"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-struct"))]
+fn test_date_range_with_ordinal() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 1, 5).unwrap();
+    let out = df!["a" => [1]]?
+        .lazy()
+        .select([date_range_with_ordinal(
+            lit(start),
+            lit(stop),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            None,
+        )
+        .alias("range")])
+        .unnest(["range"])
+        .collect()?;
+
+    let ordinal = out.column("ordinal")?;
+    assert_eq!(ordinal.dtype(), &IDX_DTYPE);
+    assert_eq!(
+        Vec::from(ordinal.idx()?),
+        (0..5).map(Some).collect::<Vec<_>>()
+    );
+    assert_eq!(out.column("range")?.len(), 5);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-time"))]
+fn test_combine_broadcast() -> PolarsResult<()> {
+    let date = NaiveDate::from_ymd_opt(2023, 1, 4).unwrap();
+    let times = [
+        NaiveTime::from_hms_opt(1, 0, 0).unwrap(),
+        NaiveTime::from_hms_opt(2, 30, 0).unwrap(),
+    ];
+    let expected = Series::new(
+        "date",
+        times.iter().map(|t| date.and_time(*t)).collect::<Vec<_>>(),
+    )
+    .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+
+    // a single date with many times
+    let df = df!["time" => times]?;
+    let out = df
+        .lazy()
+        .select([lit(date)
+            .alias("date")
+            .dt()
+            .combine(col("time"), TimeUnit::Microseconds, None)])
+        .collect()?;
+    assert!(out.column("date")?.series_equal(&expected));
+
+    // many dates with a single time
+    let date_b = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+    let df = df!["date" => [date, date_b]]?;
+    let out = df
+        .lazy()
+        .select([col("date").dt().combine(
+            lit(Series::new("time", &times[..1])),
+            TimeUnit::Microseconds,
+            None,
+        )])
+        .collect()?;
+    let expected = Series::new(
+        "date",
+        &[date.and_time(times[0]), date_b.and_time(times[0])],
+    )
+    .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+    assert!(out.column("date")?.series_equal(&expected));
+
+    // a genuine length mismatch
+    let df = df!["date" => [date, date_b, date]]?;
+    let err = df
+        .lazy()
+        .select([col("date").dt().combine(
+            lit(Series::new("time", &times)),
+            TimeUnit::Microseconds,
+            None,
+        )])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("cannot combine a date of length 3 with a time of length 2"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "timezones", feature = "dtype-time"))]
+fn test_combine_ambiguous() -> PolarsResult<()> {
+    // the clocks went back from 02:00 to 01:00 on 2021-10-31 in London
+    let date = NaiveDate::from_ymd_opt(2021, 10, 31).unwrap();
+    let df = df!["datetime" => [date.and_hms_opt(12, 0, 0).unwrap()]]?;
+    let combine = |use_earliest| {
+        df.clone()
+            .lazy()
+            .select([col("datetime")
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                .dt()
+                .replace_time_zone(Some("Europe/London".to_string()), None)
+                .dt()
+                .combine(
+                    lit(Series::new(
+                        "time",
+                        &[NaiveTime::from_hms_opt(1, 30, 0).unwrap()],
+                    )),
+                    TimeUnit::Milliseconds,
+                    use_earliest,
+                )])
+            .collect()
+    };
+
+    let err = combine(None).unwrap_err();
+    assert!(err.to_string().contains("ambiguous"));
+
+    let utc = |h| date.and_hms_opt(h, 30, 0).unwrap().timestamp_millis();
+    let earliest = combine(Some(true))?;
+    assert_eq!(
+        earliest.column("datetime")?.datetime()?.get(0),
+        Some(utc(0))
+    );
+    let latest = combine(Some(false))?;
+    assert_eq!(latest.column("datetime")?.datetime()?.get(0), Some(utc(1)));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-time"))]
+fn test_combine_datetime_keeps_date() -> PolarsResult<()> {
+    let date = NaiveDate::from_ymd_opt(2023, 7, 20).unwrap();
+    let datetimes = [
+        date.and_hms_milli_opt(15, 45, 30, 123).unwrap(),
+        date.and_hms_milli_opt(0, 0, 0, 1).unwrap(),
+    ];
+    let t = NaiveTime::from_hms_micro_opt(8, 0, 0, 456).unwrap();
+    let time = || lit(Series::new("time", &[t]));
+    let df = df!["datetime" => datetimes]?;
+    let datetime = || col("datetime").cast(DataType::Datetime(TimeUnit::Milliseconds, None));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([datetime()
+            .dt()
+            .combine(time(), TimeUnit::Microseconds, None)])
+        .collect()?;
+    let expected = Series::new("datetime", [date.and_time(t), date.and_time(t)])
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+    assert!(out.column("datetime")?.series_equal(&expected));
+
+    // the date is that of the wall-clock time: 02:00 in Kathmandu is still the 19th in UTC
+    #[cfg(feature = "timezones")]
+    {
+        let tz = || Some("Asia/Kathmandu".to_string());
+        let df = df!["datetime" => [date.and_hms_opt(2, 0, 0).unwrap()]]?;
+        let out = df
+            .lazy()
+            .select([
+                datetime()
+                    .dt()
+                    .replace_time_zone(tz(), None)
+                    .dt()
+                    .combine(time(), TimeUnit::Microseconds, None)
+                    .alias("combined"),
+                lit(date.and_time(t))
+                    .cast(DataType::Datetime(TimeUnit::Microseconds, None))
+                    .dt()
+                    .replace_time_zone(tz(), None)
+                    .alias("expected"),
+            ])
+            .collect()?;
+        assert!(out
+            .column("combined")?
+            .series_equal(out.column("expected")?));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "timezones", feature = "dtype-struct"))]
+fn test_dst_transition_flags() -> PolarsResult<()> {
+    let dt = |d: u32, m: u32, h: u32, min: u32| {
+        NaiveDate::from_ymd_opt(2022, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    };
+    // Europe/London skips 01:00-02:00 on 2022-03-27 and repeats it on 2022-10-30
+    let df = df![
+        "dt" => [dt(27, 3, 0, 30), dt(27, 3, 1, 30), dt(30, 10, 1, 30), dt(30, 10, 2, 30)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("dt")
+            .dt()
+            .dst_transition_flags(Some("Europe/London".into()))])
+        .unnest(["dt"])
+        .collect()?;
+    let expected = df![
+        "ambiguous" => [false, false, true, false],
+        "non_existent" => [false, true, false, false],
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    // tz-aware values are flagged by their wall-clock time in their own time zone
+    let out = df
+        .lazy()
+        .select([col("dt")
+            .dt()
+            .replace_time_zone(Some("UTC".into()), None)
+            .dt()
+            .convert_time_zone("Europe/London".into())
+            .dt()
+            .dst_transition_flags(None)])
+        .unnest(["dt"])
+        .collect()?;
+    // 2022-10-30 01:30 UTC is 01:30 GMT, a wall-clock time that also occurred in BST
+    let expected = df![
+        "ambiguous" => [false, false, true, false],
+        "non_existent" => [false, false, false, false],
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_truncate_with_preparsed_time_zone() -> PolarsResult<()> {
+    let dt = |d: u32, h: u32, min: u32| {
+        NaiveDate::from_ymd_opt(2022, 3, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    };
+    let df = df![
+        "dt" => [dt(26, 23, 50), dt(27, 0, 10), dt(27, 1, 40), dt(27, 23, 20)]
+    ]?
+    .lazy()
+    .select([col("dt")
+        .dt()
+        .replace_time_zone(Some("UTC".into()), None)
+        .dt()
+        .convert_time_zone("Asia/Kathmandu".into())])
+    .collect()?;
+    let s = df.column("dt")?;
+
+    // the time zone is parsed once and reused across calls
+    let ca = s.datetime()?;
+    let tz = polars_time::parse_datetime_time_zone(ca)?;
+    assert!(tz.is_some());
+
+    for every in ["1h", "1d"] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("dt").dt().truncate(every, "0ns")])
+            .collect()?;
+        let expected = ca
+            .truncate(
+                Duration::parse(every),
+                Duration::parse("0ns"),
+                tz.as_ref(),
+                None,
+            )?
+            .into_series();
+        assert!(out.column("dt")?.series_equal_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_normalize_across_dst() -> PolarsResult<()> {
+    let utc = |m: u32, d: u32, h: u32, min: u32| {
+        NaiveDate::from_ymd_opt(2022, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    };
+    let in_tz =
+        |values: Vec<polars::export::chrono::NaiveDateTime>, tz: &str| -> PolarsResult<Series> {
+            let out = df!["dt" => values]?
+                .lazy()
+                .select([col("dt")
+                    .dt()
+                    .replace_time_zone(Some("UTC".into()), None)
+                    .dt()
+                    .convert_time_zone(tz.into())])
+                .collect()?;
+            Ok(out.column("dt")?.clone())
+        };
+    let normalize = |s: Series| -> PolarsResult<Series> {
+        let out = df!["dt" => s]?
+            .lazy()
+            .select([col("dt").dt().normalize()])
+            .collect()?;
+        Ok(out.column("dt")?.clone())
+    };
+
+    // Europe/London springs forward at 01:00 GMT on 2022-03-27: midnight exists,
+    // but 02:30 BST is only 01:30 UTC
+    let s = in_tz(
+        vec![utc(3, 27, 0, 30), utc(3, 27, 1, 30), utc(3, 27, 22, 30)],
+        "Europe/London",
+    )?;
+    let expected = in_tz(vec![utc(3, 27, 0, 0); 3], "Europe/London")?;
+    let out = normalize(s)?;
+    assert_eq!(out.dtype(), expected.dtype());
+    assert!(out.series_equal_missing(&expected));
+
+    // America/Sao_Paulo sprang forward at midnight on 2018-11-04, so that day started at 01:00
+    let s = in_tz(
+        vec![NaiveDate::from_ymd_opt(2018, 11, 4)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap()],
+        "America/Sao_Paulo",
+    )?;
+    let expected = in_tz(
+        vec![NaiveDate::from_ymd_opt(2018, 11, 4)
+            .unwrap()
+            .and_hms_opt(3, 0, 0)
+            .unwrap()],
+        "America/Sao_Paulo",
+    )?;
+    assert!(normalize(s)?.series_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+fn test_time_since_midnight_across_dst() -> PolarsResult<()> {
+    let utc = |h: u32, min: u32| {
+        NaiveDate::from_ymd_opt(2022, 3, 27)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    };
+    let minutes = |s: &Series| -> PolarsResult<Vec<Option<i64>>> {
+        let ms = s.cast(&DataType::Duration(TimeUnit::Milliseconds))?;
+        Ok(ms
+            .duration()?
+            .into_iter()
+            .map(|v| v.map(|v| v / 60_000))
+            .collect())
+    };
+    let time_since_midnight = |s: Series| -> PolarsResult<Series> {
+        let out = df!["dt" => s]?
+            .lazy()
+            .select([col("dt").dt().time_since_midnight()])
+            .collect()?;
+        Ok(out.column("dt")?.clone())
+    };
+
+    // naive datetimes are taken as they are
+    let naive = Series::new("dt", &[utc(0, 30), utc(13, 45), utc(23, 59)]);
+    let out = time_since_midnight(naive)?;
+    assert!(matches!(out.dtype(), DataType::Duration(_)));
+    assert_eq!(
+        minutes(&out)?,
+        &[Some(30), Some(13 * 60 + 45), Some(23 * 60 + 59)]
+    );
+
+    // Europe/London springs forward at 01:00 GMT on 2022-03-27, so 01:30 UTC is 02:30 BST
+    let tz_aware = df!["dt" => [utc(0, 30), utc(1, 30), utc(22, 30)]]?
+        .lazy()
+        .select([col("dt")
+            .dt()
+            .replace_time_zone(Some("UTC".into()), None)
+            .dt()
+            .convert_time_zone("Europe/London".into())])
+        .collect()?
+        .column("dt")?
+        .clone();
+    let out = time_since_midnight(tz_aware)?;
+    assert_eq!(minutes(&out)?, &[Some(30), Some(150), Some(23 * 60 + 30)]);
+
+    // Time values map to the same wall-clock duration
+    let times = df!["dt" => [utc(0, 30), utc(18, 5)]]?
+        .lazy()
+        .select([col("dt").cast(DataType::Time)])
+        .collect()?
+        .column("dt")?
+        .clone();
+    let out = time_since_midnight(times)?;
+    assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Nanoseconds));
+    assert_eq!(minutes(&out)?, &[Some(30), Some(18 * 60 + 5)]);
+    Ok(())
+}
+
+#[test]
+fn test_truncate_by_per_row_every_and_offset() -> PolarsResult<()> {
+    let dt = |d: u32, h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2022, 1, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    };
+    let df = df![
+        "dt" => [dt(1, 10, 30), dt(1, 10, 30), dt(15, 10, 30), dt(1, 10, 30)],
+        "every" => ["1h", "1d", "1mo", "1h"],
+        "offset" => [Some("15m"), Some("2h"), Some("0ns"), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("dt").dt().truncate_by(col("every"), col("offset"))])
+        .collect()?;
+    let expected = df![
+        "dt" => [Some(dt(1, 10, 15)), Some(dt(1, 2, 0)), Some(dt(1, 0, 0)), None],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // a single offset is broadcast
+    let out = df
+        .lazy()
+        .select([col("dt").dt().truncate_by(col("every"), lit("0ns"))])
+        .collect()?;
+    let expected = df![
+        "dt" => [dt(1, 10, 0), dt(1, 0, 0), dt(1, 0, 0), dt(1, 10, 0)],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_business_day_count_week_mask() -> PolarsResult<()> {
+    // 2023-01-02 is a Monday
+    let start = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    let df = df![
+        "start" => [start, start],
+        "end" => [
+            NaiveDate::from_ymd_opt(2023, 1, 9).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 1, 5).unwrap(),
+        ],
+    ]?;
+
+    let six_day_week = [true, true, true, true, true, true, false];
+    let no_monday = [false, true, true, true, true, false, false];
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            business_day_count(col("start"), col("end"), six_day_week, vec![])
+                .alias("six_day_week"),
+            business_day_count(col("start"), col("end"), no_monday, vec![]).alias("no_monday"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "six_day_week" => [6, 3],
+        "no_monday" => [4, 2],
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    let err = df
+        .lazy()
+        .select([business_day_count(
+            col("start"),
+            col("end"),
+            [false; 7],
+            vec![],
+        )])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("a week mask must contain at least one business day"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_business_day_count_holidays() -> PolarsResult<()> {
+    let days =
+        |date: NaiveDate| (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
+    // Monday 2023-01-02 to Monday 2023-01-09
+    let start = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 1, 9).unwrap();
+    let df = df!["start" => [start], "end" => [end]]?;
+
+    let week_mask = [true, true, true, true, true, false, false];
+    // a Wednesday, a Saturday and a date after `end`
+    let holidays = vec![
+        days(NaiveDate::from_ymd_opt(2023, 1, 4).unwrap()),
+        days(NaiveDate::from_ymd_opt(2023, 1, 7).unwrap()),
+        days(NaiveDate::from_ymd_opt(2023, 1, 10).unwrap()),
+    ];
+    let out = df
+        .lazy()
+        .select([business_day_count(
+            col("start"),
+            col("end"),
+            week_mask,
+            holidays,
+        )])
+        .collect()?;
+
+    assert_eq!(out.column("start")?.i32()?.get(0), Some(4));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "date_offset")]
+fn test_offset_by_per_row_offsets() -> PolarsResult<()> {
+    let dt = |m: u32, d: u32, h: u32| {
+        NaiveDate::from_ymd_opt(2023, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    };
+    let df = df![
+        "dt" => [dt(1, 31, 0), dt(1, 15, 0), dt(1, 1, 12), dt(1, 1, 12)],
+        "by" => [Some("1d"), Some("1mo"), Some("-2h"), None],
+    ]?;
+
+    for tu in [TimeUnit::Milliseconds, TimeUnit::Nanoseconds] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("dt")
+                .cast(DataType::Datetime(tu, None))
+                .dt()
+                .offset_by_expr(col("by"))])
+            .collect()?;
+
+        let expected = df![
+            "dt" => [Some(dt(2, 1, 0)), Some(dt(2, 15, 0)), Some(dt(1, 1, 10)), None],
+        ]?
+        .lazy()
+        .select([col("dt").cast(DataType::Datetime(tu, None))])
+        .collect()?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "date_offset", feature = "timezones"))]
+fn test_offset_by_per_row_offsets_across_dst() -> PolarsResult<()> {
+    let dt = |d: u32, h: u32| {
+        NaiveDate::from_ymd_opt(2023, 3, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    };
+    // Europe/London springs forward on 2023-03-26, so that day only has 23 hours
+    let df = df![
+        "dt" => [dt(25, 12), dt(25, 12)],
+        "by" => ["1d", "24h"],
+    ]?;
+
+    for tu in [TimeUnit::Milliseconds, TimeUnit::Nanoseconds] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("dt")
+                .cast(DataType::Datetime(tu, None))
+                .dt()
+                .replace_time_zone(Some("Europe/London".into()), None)
+                .dt()
+                .offset_by_expr(col("by"))
+                .dt()
+                .replace_time_zone(None, None)])
+            .collect()?;
+
+        let expected = df!["dt" => [dt(26, 12), dt(26, 13)]]?
+            .lazy()
+            .select([col("dt").cast(DataType::Datetime(tu, None))])
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "date_offset")]
+fn test_offset_by_per_row_offsets_large() -> PolarsResult<()> {
+    let n = 100_000;
+    let offsets = ["1d", "-2h30m", "1mo", "3w", "1y"];
+    // milliseconds from 2000-01-01 onwards, an hour and a second apart
+    let start = 946_684_800_000i64;
+    let times: Vec<i64> = (0..n).map(|i| start + i * 3_601_000).collect();
+    let by: Vec<&str> = (0..n as usize)
+        .map(|i| offsets[i % offsets.len()])
+        .collect();
+
+    let expected: Vec<i64> = times
+        .iter()
+        .zip(&by)
+        .map(|(&t, by)| Duration::parse(by).add_ms(t, None).unwrap())
+        .collect();
+    let expected = Int64Chunked::from_vec("dt", expected)
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+
+    let dt = Int64Chunked::from_vec("dt", times)
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let out = df!["dt" => dt, "by" => by]?
+        .lazy()
+        .select([col("dt").dt().offset_by_expr(col("by"))])
+        .collect()?;
+    assert!(out.column("dt")?.series_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "date_offset")]
+fn test_offset_by_negative_keeps_sorted_flag() -> PolarsResult<()> {
+    let date = |m: u32, d: u32| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+    let mut s = Series::new("date", &[date(3, 1), date(3, 5), date(3, 20)]);
+    s.set_sorted_flag(IsSorted::Ascending);
+
+    let out = DataFrame::new(vec![s])?
+        .lazy()
+        .select([col("date").dt().offset_by(Duration::parse("-3d"))])
+        .collect()?;
+    let out = out.column("date")?;
+    let expected = Series::new("date", &[date(2, 26), date(3, 2), date(3, 17)]);
+    assert!(out.series_equal(&expected));
+    assert_eq!(out.is_sorted_flag(), IsSorted::Ascending);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "date_offset")]
+fn test_offset_by_date_matches_datetime() -> PolarsResult<()> {
+    let dates: Vec<NaiveDate> = (0..400)
+        .map(|i| {
+            NaiveDate::from_ymd_opt(1969, 12, 1).unwrap()
+                + polars::export::chrono::Duration::days(i)
+        })
+        // days past the 28th don't exist in every month, so "1mo" would fail on them
+        .filter(|date| polars::export::chrono::Datelike::day(date) <= 28)
+        .collect();
+    let df = df!["date" => dates]?;
+
+    for offset in ["1mo", "2w", "10d", "-1y1mo3d"] {
+        let offset = Duration::parse(offset);
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                col("date").dt().offset_by(offset).alias("date"),
+                col("date")
+                    .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                    .dt()
+                    .offset_by(offset)
+                    .cast(DataType::Date)
+                    .alias("casted"),
+            ])
+            .collect()?;
+        assert!(out.column("date")?.series_equal(out.column("casted")?));
+    }
+
+    let err = df
+        .lazy()
+        .select([col("date").dt().offset_by(Duration::parse("1d12h"))])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("cannot offset a Date by a duration with a sub-day part"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_strict() -> PolarsResult<()> {
+    // a month of 13 in the second row, and February 30 in the third
+    let df = df![
+        "year" => [2023, 2023, 2023],
+        "month" => [1, 13, 2],
+        "day" => [15, 1, 30],
+    ]?;
+    let args = || DatetimeArgs::new(col("year"), col("month"), col("day"));
+
+    let out = df.clone().lazy().select([datetime(args())]).collect()?;
+    let out = out.column("datetime")?;
+    assert_eq!(
+        out.is_null().into_iter().collect::<Vec<_>>(),
+        [Some(false), Some(true), Some(true)]
+    );
+
+    let err = df
+        .clone()
+        .lazy()
+        .select([datetime(args().with_strict(true))])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid datetime components in row 1: year=2023, month=13, day=1,"));
+
+    let err = df
+        .lazy()
+        .filter(col("month").neq(lit(13)))
+        .select([datetime(args().with_strict(true))])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid datetime components in row 1: year=2023, month=2, day=30"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_nanosecond() -> PolarsResult<()> {
+    let df = df![
+        "microsecond" => [123_456],
+        "nanosecond" => [789],
+    ]?;
+    let args = |tu| {
+        DatetimeArgs::new(lit(2023), lit(7), lit(20))
+            .with_hms(lit(12), lit(34), lit(56))
+            .with_microsecond(col("microsecond"))
+            .with_nanosecond(col("nanosecond"))
+            .with_time_unit(tu)
+    };
+    let out = df
+        .lazy()
+        .select([
+            datetime(args(TimeUnit::Nanoseconds)).alias("ns"),
+            datetime(args(TimeUnit::Microseconds)).alias("us"),
+            datetime(args(TimeUnit::Milliseconds)).alias("ms"),
+        ])
+        .collect()?;
+
+    let expected = NaiveDate::from_ymd_opt(2023, 7, 20)
+        .unwrap()
+        .and_hms_nano_opt(12, 34, 56, 123_456_789)
+        .unwrap();
+    let ns = out.column("ns")?;
+    assert_eq!(ns.dtype(), &DataType::Datetime(TimeUnit::Nanoseconds, None));
+    assert_eq!(ns.datetime()?.get(0), Some(expected.timestamp_nanos()));
+    // round-trips through its string representation
+    let parsed = ns
+        .datetime()?
+        .to_string("%Y-%m-%d %H:%M:%S%.9f")?
+        .as_datetime(
+            Some("%Y-%m-%d %H:%M:%S%.9f"),
+            TimeUnit::Nanoseconds,
+            false,
+            false,
+            None,
+        )?;
+    assert_eq!(parsed.get(0), Some(expected.timestamp_nanos()));
+
+    // the nanoseconds are truncated in a coarser time unit
+    assert_eq!(
+        out.column("us")?.datetime()?.get(0),
+        Some(expected.timestamp_micros())
+    );
+    assert_eq!(
+        out.column("ms")?.datetime()?.get(0),
+        Some(expected.timestamp_millis())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_out_of_range() -> PolarsResult<()> {
+    // the year 3000 doesn't fit in nanoseconds since the epoch, but does in microseconds
+    let df = df!["year" => [2023, 3000]]?;
+    let args = |tu| DatetimeArgs::new(col("year"), lit(1), lit(1)).with_time_unit(tu);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            datetime(args(TimeUnit::Nanoseconds)).alias("ns"),
+            datetime(args(TimeUnit::Microseconds)).alias("us"),
+        ])
+        .collect()?;
+    let expected = NaiveDate::from_ymd_opt(3000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    assert_eq!(
+        out.column("ns")?.is_null().into_iter().collect::<Vec<_>>(),
+        [Some(false), Some(true)]
+    );
+    assert_eq!(
+        out.column("us")?.datetime()?.get(1),
+        Some(expected.timestamp_micros())
+    );
+
+    let err = df
+        .lazy()
+        .select([datetime(args(TimeUnit::Nanoseconds).with_strict(true))])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid datetime components in row 1: year=3000, month=1, day=1,"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_broadcasts_scalar_components() -> PolarsResult<()> {
+    let n = 100_000;
+    // each year from 1900 to 2099, 500 times
+    let years = (0..n).map(|i| 1900 + (i % 200) as i32).collect::<Vec<_>>();
+    let df = df![
+        "year" => years,
+        "hour" => vec![12u32; n],
+        "minute" => vec![34u32; n],
+        "second" => vec![56u32; n],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            datetime(DatetimeArgs::new(col("year"), lit(2), lit(29)).with_hms(
+                lit(12),
+                lit(34),
+                lit(56),
+            ))
+            .alias("scalars"),
+            datetime(DatetimeArgs::new(col("year"), lit(2), lit(29)).with_hms(
+                col("hour"),
+                col("minute"),
+                col("second"),
+            ))
+            .alias("columns"),
+        ])
+        .collect()?;
+    let scalars = out.column("scalars")?;
+    assert_eq!(scalars.len(), n);
+    assert!(scalars.series_equal_missing(out.column("columns")?));
+    // February 29 only exists in the 49 leap years
+    assert_eq!(scalars.null_count(), (200 - 49) * 500);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-date"))]
+fn test_epoch_units() -> PolarsResult<()> {
+    let date = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    let datetimes = [
+        Some(date.and_hms_nano_opt(3, 4, 5, 123_456_789).unwrap()),
+        // half a second before the epoch, which rounds down
+        Some(
+            NaiveDate::from_ymd_opt(1969, 12, 31)
+                .unwrap()
+                .and_hms_milli_opt(23, 59, 59, 500)
+                .unwrap(),
+        ),
+        None,
+    ];
+    let df = DataFrame::new(vec![
+        Series::new("datetime", &datetimes),
+        Series::new("date", &[Some(date), None, None]),
+    ])?;
+
+    let units = ["s", "ms", "us", "ns", "d"];
+    let epochs = |name: &str| {
+        units
+            .iter()
+            .map(|unit| col(name).dt().epoch(unit).alias(unit))
+            .collect::<Vec<_>>()
+    };
+    let out = df.clone().lazy().select(epochs("datetime")).collect()?;
+    let expected: [[Option<i64>; 3]; 5] = [
+        [Some(1_672_628_645), Some(-1), None],
+        [Some(1_672_628_645_123), Some(-500), None],
+        [Some(1_672_628_645_123_456), Some(-500_000), None],
+        [Some(1_672_628_645_123_456_789), Some(-500_000_000), None],
+        [Some(19_359), Some(-1), None],
+    ];
+    for (unit, expected) in units.iter().zip(expected) {
+        let s = out.column(unit)?;
+        assert_eq!(s.dtype(), &DataType::Int64);
+        assert_eq!(Vec::from(s.i64()?), expected, "unit {unit}");
+    }
+
+    // the number of days of a Date is multiplied out
+    let out = df.lazy().select(epochs("date")).collect()?;
+    let expected: [i64; 5] = [
+        1_672_617_600,
+        1_672_617_600_000,
+        1_672_617_600_000_000,
+        1_672_617_600_000_000_000,
+        19_359,
+    ];
+    for (unit, expected) in units.iter().zip(expected) {
+        assert_eq!(
+            Vec::from(out.column(unit)?.i64()?),
+            &[Some(expected), None, None],
+            "unit {unit}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_epoch_invalid_unit() -> PolarsResult<()> {
+    let df = df!["date" => [NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()]]?;
+    let err = df
+        .lazy()
+        .select([col("date").dt().epoch("h")])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid epoch unit 'h'"));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-duration"))]
+fn test_duration_time_units() -> PolarsResult<()> {
+    let df = df![
+        "weeks" => [Some(1i64), None],
+        "days" => [2i64, 2],
+        "nanoseconds" => [8i64, 8],
+    ]?;
+    let args = DurationArgs {
+        weeks: col("weeks"),
+        days: col("days"),
+        hours: lit(3),
+        minutes: lit(4),
+        seconds: lit(5),
+        milliseconds: lit(6),
+        microseconds: lit(7),
+        nanoseconds: col("nanoseconds"),
+        ..Default::default()
+    };
+    for (tu, expected) in [
+        (TimeUnit::Nanoseconds, 788_645_006_007_008i64),
+        (TimeUnit::Microseconds, 788_645_006_007),
+        (TimeUnit::Milliseconds, 788_645_006),
+    ] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([duration(args.clone().with_time_unit(tu))])
+            .collect()?;
+        let s = out.column("duration")?;
+        assert_eq!(s.dtype(), &DataType::Duration(tu));
+        assert_eq!(
+            Vec::from(&s.duration()?.0),
+            &[Some(expected), None],
+            "time unit {tu}"
+        );
+    }
+
+    // the remainder of a finer component is rounded down
+    let out = df
+        .clone()
+        .lazy()
+        .select([duration(
+            DurationArgs::new()
+                .with_nanoseconds(lit(-1))
+                .with_time_unit(TimeUnit::Milliseconds),
+        )])
+        .collect()?;
+    assert_eq!(out.column("duration")?.duration()?.get(0), Some(-1));
+
+    // 20000 weeks fit in microseconds, but not in nanoseconds
+    let weeks = || DurationArgs::new().with_weeks(lit(20_000));
+    let out = df
+        .clone()
+        .lazy()
+        .select([duration(weeks().with_time_unit(TimeUnit::Microseconds))])
+        .collect()?;
+    assert_eq!(
+        out.column("duration")?.duration()?.get(0),
+        Some(20_000 * 7 * 86_400 * 1_000_000)
+    );
+    let err = df
+        .lazy()
+        .select([duration(weeks().with_time_unit(TimeUnit::Nanoseconds))])
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("overflows a Duration with time unit 'ns'"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_date_range_tz_aware_columns() -> PolarsResult<()> {
+    let tz = "Europe/Amsterdam";
+    let local = |m: u32, d: u32, h: u32| {
+        NaiveDate::from_ymd_opt(2023, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    };
+    // the first range crosses the spring-forward transition at 02:00
+    let bounds = [
+        (local(3, 26, 0), local(3, 26, 4)),
+        (local(6, 1, 10), local(6, 1, 12)),
+    ];
+    let zoned = |name: &str, values: Vec<_>| -> PolarsResult<Series> {
+        Ok(Series::new(name, values)
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+            .datetime()?
+            .replace_time_zone(Some(tz), None, NonExistent::Raise)?
+            .into_series())
+    };
+    let df = DataFrame::new(vec![
+        zoned("start", bounds.iter().map(|b| b.0).collect())?,
+        zoned("stop", bounds.iter().map(|b| b.1).collect())?,
+    ])?;
+
+    let out = df
+        .lazy()
+        .select([date_range(
+            col("start"),
+            col("stop"),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+            None,
+            true,
+        )])
+        .collect()?;
+    let out = out.column("start")?.list()?;
+    for (i, (start, stop)) in bounds.into_iter().enumerate() {
+        let expected = polars_time::date_range(
+            "",
+            start,
+            stop,
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+            TimeUnit::Milliseconds,
+            Some(tz.into()),
+        )?;
+        let rng = out.get(i).unwrap();
+        assert_eq!(
+            rng.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(tz.into()))
+        );
+        assert_eq!(Vec::from(&rng.datetime()?.0), Vec::from(&expected.0));
+    }
+    // 00:00 to 04:00 without 02:00, which doesn't exist
+    assert_eq!(out.get(0).unwrap().len(), 4);
+    Ok(())
+}
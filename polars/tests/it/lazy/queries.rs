@@ -273,3 +273,24 @@ fn test_groupby_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_strip_at_most_n() -> PolarsResult<()> {
+    let df = df!["a" => ["00042", "042", "42", "000"]]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").str().lstrip_n(Some("0".into()), 2).alias("lstrip"),
+            col("a").str().rstrip_n(Some("0".into()), 2).alias("rstrip"),
+            col("a").str().lstrip(Some("0".into())).alias("lstrip_all"),
+        ])
+        .collect()?;
+    let expected = df![
+        "lstrip" => ["042", "42", "42", "0"],
+        "rstrip" => ["00042", "042", "42", "0"],
+        "lstrip_all" => ["42", "42", "42", ""],
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
@@ -76,7 +76,7 @@ impl PyExpr {
         self.inner
             .clone()
             .dt()
-            .combine(time.inner, time_unit.0)
+            .combine(time.inner, time_unit.0, None)
             .into()
     }
 
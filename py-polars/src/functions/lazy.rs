@@ -182,7 +182,7 @@ pub fn date_range_lazy(
     let start = start.inner;
     let end = end.inner;
     let every = Duration::parse(every);
-    dsl::functions::date_range(start, end, every, closed.0, time_zone).into()
+    dsl::functions::date_range(start, end, every, closed.0, time_zone, true).into()
 }
 
 #[pyfunction]
@@ -209,6 +209,9 @@ pub fn datetime(
         minute,
         second,
         microsecond,
+        nanosecond: dsl::lit(0),
+        time_unit: TimeUnit::Microseconds,
+        strict: false,
     };
     dsl::datetime(args).into()
 }
@@ -265,6 +268,7 @@ pub fn duration(
         minutes,
         hours,
         weeks,
+        time_unit: TimeUnit::Nanoseconds,
     };
     dsl::duration(args).into()
 }
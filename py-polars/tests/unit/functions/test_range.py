@@ -439,6 +439,12 @@ def test_date_range_descending() -> None:
         )
 
 
+def test_date_range_descending_negative_interval() -> None:
+    result = pl.date_range(date(2000, 3, 5), date(2000, 3, 1), "-1d", eager=True)
+    assert result.to_list() == [date(2000, 3, day) for day in range(5, 0, -1)]
+    assert result.flags["SORTED_DESC"]
+
+
 def test_date_range_end_of_month_5441() -> None:
     start = date(2020, 1, 31)
     stop = date(2021, 1, 31)